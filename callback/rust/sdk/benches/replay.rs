@@ -0,0 +1,255 @@
+//! Replays a large log dump through the event parser.
+//!
+//! ```sh
+//! cargo bench --bench replay
+//! ```
+//!
+//! The `dispatch` group measures decoding of a realistic mix of events along with
+//! the bare discriminator lookup of a comparison chain. The lookup is a small fraction
+//! of decoding, and [`Event::try_from_bytes`] dispatches with a single integer match
+//! rather than a chain, so a decode cache would not pay off.
+//!
+//! The `format` group measures formatting of events into a reused buffer, and reports
+//! the number of allocations per event compared to encoding into a new string.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Write,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use anchor_client::solana_sdk::bs58;
+use anchor_lang::prelude::Pubkey;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use orao_solana_vrf_cb::{
+    events::{CallbackUpdated, Fulfilled, Requested, Responded, Transferred, Withdrawn},
+    sdk::{
+        encode_base58_into, parse_logs, Event, CALLBACK_UPDATED_DISCRIMINATOR,
+        CALLED_BACK_DISCRIMINATOR, FULFILLED_DISCRIMINATOR, REGISTERED_DISCRIMINATOR,
+        REQUESTED_ALT_DISCRIMINATOR, REQUESTED_DISCRIMINATOR, RESPONDED_DISCRIMINATOR,
+        TRANSFERRED_DISCRIMINATOR, WITHDRAWN_DISCRIMINATOR,
+    },
+};
+
+/// Counts allocations to compare the formatting paths.
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Number of transactions in the dump.
+const TRANSACTIONS: usize = 1_000;
+
+/// Events of a worst-case mix where most of them come late in the declaration order.
+fn events() -> Vec<Event> {
+    let client = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    vec![
+        Responded::new(Pubkey::new_unique(), client, [1; 32], [2; 64]).into(),
+        Fulfilled::new(client, [1; 32], [2; 64]).into(),
+        Withdrawn::new(owner, client, 42).into(),
+        Transferred::new(owner, client, Pubkey::new_unique()).into(),
+        CallbackUpdated::new(owner, client, true).into(),
+    ]
+}
+
+/// Logs of transactions where the VRF program is invoked by a client program.
+fn log_dump() -> Vec<String> {
+    let vrf = orao_solana_vrf_cb::id();
+    let client = Pubkey::new_unique();
+    let events = events();
+
+    let mut logs = Vec::new();
+    for _ in 0..TRANSACTIONS {
+        logs.push(format!("Program {client} invoke [1]"));
+        logs.push("Program log: Instruction: Fulfill".to_string());
+        logs.push(format!("Program {vrf} invoke [2]"));
+        logs.extend(events.iter().map(Event::to_program_data_log));
+        logs.push(format!(
+            "Program {vrf} consumed 42000 of 200000 compute units"
+        ));
+        logs.push(format!("Program {vrf} success"));
+        logs.push(format!("Program {client} success"));
+    }
+    logs
+}
+
+fn replay(c: &mut Criterion) {
+    let logs = log_dump();
+    let bytes = events().iter().map(Event::to_bytes).collect::<Vec<_>>();
+    let num_events = (TRANSACTIONS * bytes.len()) as u64;
+
+    let mut group = c.benchmark_group("replay");
+    group.throughput(Throughput::Elements(num_events));
+
+    group.bench_function("parse_logs", |b| {
+        b.iter(|| {
+            for event in parse_logs(&orao_solana_vrf_cb::id(), &logs) {
+                std::hint::black_box(event.unwrap());
+            }
+        })
+    });
+
+    group.bench_function("try_from_bytes", |b| {
+        b.iter(|| {
+            for _ in 0..TRANSACTIONS {
+                for bytes in &bytes {
+                    std::hint::black_box(Event::try_from_bytes(std::hint::black_box(bytes)))
+                        .unwrap();
+                }
+            }
+        })
+    });
+
+    group.finish();
+}
+
+/// A realistic mix of a busy indexer — mostly requests and fulfillments.
+fn realistic_events() -> Vec<Event> {
+    let client = Pubkey::new_unique();
+    let mut events = Vec::new();
+    for i in 0..100_u8 {
+        let seed = [i; 32];
+        events.push(match i % 10 {
+            0..=3 => Requested::new(client, seed, None, false).into(),
+            4..=7 => Fulfilled::new(client, seed, [2; 64]).into(),
+            8 => Responded::new(Pubkey::new_unique(), client, seed, [2; 64]).into(),
+            _ => Withdrawn::new(Pubkey::new_unique(), client, 42).into(),
+        });
+    }
+    events
+}
+
+/// A comparison chain in the declaration order — the most a decode cache could save.
+fn linear_scan(bytes: &[u8]) -> Option<usize> {
+    [
+        CALLBACK_UPDATED_DISCRIMINATOR,
+        CALLED_BACK_DISCRIMINATOR,
+        FULFILLED_DISCRIMINATOR,
+        REGISTERED_DISCRIMINATOR,
+        REQUESTED_DISCRIMINATOR,
+        REQUESTED_ALT_DISCRIMINATOR,
+        RESPONDED_DISCRIMINATOR,
+        TRANSFERRED_DISCRIMINATOR,
+        WITHDRAWN_DISCRIMINATOR,
+    ]
+    .iter()
+    .position(|x| bytes.starts_with(x))
+}
+
+fn dispatch(c: &mut Criterion) {
+    let bytes = realistic_events()
+        .iter()
+        .map(Event::to_bytes)
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("dispatch");
+    group.throughput(Throughput::Elements(bytes.len() as u64));
+
+    group.bench_function("try_from_bytes", |b| {
+        b.iter(|| {
+            for bytes in &bytes {
+                std::hint::black_box(Event::try_from_bytes(std::hint::black_box(bytes))).unwrap();
+            }
+        })
+    });
+
+    group.bench_function("linear_scan", |b| {
+        b.iter(|| {
+            for bytes in &bytes {
+                std::hint::black_box(linear_scan(std::hint::black_box(bytes))).unwrap();
+            }
+        })
+    });
+
+    group.finish();
+}
+
+/// Returns the number of allocations made by `f`.
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn format(c: &mut Criterion) {
+    let events = realistic_events();
+    let fulfilled = events
+        .iter()
+        .filter_map(|x| match x {
+            Event::Fulfilled(ev) => Some(ev.randomness),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut buf = String::with_capacity(1024);
+    let display = allocations(|| {
+        for event in &events {
+            buf.clear();
+            write!(buf, "{event}").unwrap();
+        }
+    });
+    let into = allocations(|| {
+        for randomness in &fulfilled {
+            buf.clear();
+            encode_base58_into(&mut buf, randomness);
+        }
+    });
+    let into_string = allocations(|| {
+        for randomness in &fulfilled {
+            std::hint::black_box(bs58::encode(randomness).into_string());
+        }
+    });
+    println!(
+        "allocations: {display} formatting {} events, {into} encoding {n} randomness \
+         into a buffer, {into_string} encoding {n} randomness into new strings",
+        events.len(),
+        n = fulfilled.len(),
+    );
+
+    let mut group = c.benchmark_group("format");
+    group.throughput(Throughput::Elements(events.len() as u64));
+
+    group.bench_function("display", |b| {
+        b.iter(|| {
+            for event in &events {
+                buf.clear();
+                write!(buf, "{}", std::hint::black_box(event)).unwrap();
+            }
+        })
+    });
+
+    group.bench_function("encode_base58_into", |b| {
+        b.iter(|| {
+            for randomness in &fulfilled {
+                buf.clear();
+                encode_base58_into(&mut buf, std::hint::black_box(randomness));
+            }
+        })
+    });
+
+    group.bench_function("into_string", |b| {
+        b.iter(|| {
+            for randomness in &fulfilled {
+                std::hint::black_box(bs58::encode(std::hint::black_box(randomness)).into_string());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, replay, dispatch, format);
+criterion_main!(benches);
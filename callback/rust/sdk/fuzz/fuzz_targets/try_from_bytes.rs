@@ -0,0 +1,41 @@
+//! Asserts that [`Event::try_from_bytes`] never panics on arbitrary input
+//! and that every parsed event round-trips through [`Event::to_bytes`].
+//!
+//! ```sh
+//! cargo +nightly fuzz run try_from_bytes
+//! ```
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use orao_solana_vrf_cb::sdk::{discriminator_for_name, Event};
+
+const EVENTS: [&str; 9] = [
+    "CallbackUpdated",
+    "CalledBack",
+    "Fulfilled",
+    "Registered",
+    "Requested",
+    "RequestedAlt",
+    "Responded",
+    "Transferred",
+    "Withdrawn",
+];
+
+fn check(bytes: &[u8]) {
+    if let Ok(event) = Event::try_from_bytes(bytes) {
+        let reparsed = Event::try_from_bytes(&event.to_bytes()).expect("round-trip");
+        assert_eq!(reparsed, event);
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    check(data);
+
+    // Random input rarely hits a known discriminator, so the first byte
+    // selects one to reach the borsh deserialization of event fields.
+    if let Some((selector, payload)) = data.split_first() {
+        let name = EVENTS[*selector as usize % EVENTS.len()];
+        let discriminator = discriminator_for_name(name).expect("known event");
+        check(&[discriminator, payload].concat());
+    }
+});
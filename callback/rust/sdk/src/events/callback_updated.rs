@@ -20,4 +20,13 @@ impl CallbackUpdated {
             defined,
         }
     }
+
+    /// Returns `true` if this update is known to leave the client callback as is,
+    /// given the previous update of the same client.
+    ///
+    /// The event does not carry the callback itself, so only unsetting an already
+    /// unset callback is detected — replacing a callback with an identical one is not.
+    pub fn is_noop(&self, prev: &CallbackUpdated) -> bool {
+        self.client == prev.client && !self.defined && !prev.defined
+    }
 }
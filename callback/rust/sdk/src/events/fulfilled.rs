@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 
 /// Event that signals a request was fulfilled.
 #[event]
@@ -20,4 +21,95 @@ impl Fulfilled {
             randomness,
         }
     }
+
+    /// A cheap sanity check of the randomness to run before deriving values from it.
+    ///
+    /// Returns `false` if the randomness is degenerate — all the bytes are the same
+    /// (e.g. all-zero) or both halves are the same. This should never happen and
+    /// indicates a bug or an attack.
+    ///
+    /// Note that this is not a cryptographic proof — use the `sdk` verification
+    /// helpers (`check_misbehavior`) to verify the randomness against the oracle responses.
+    pub fn looks_valid(&self) -> bool {
+        let (first, second) = self.randomness.split_at(32);
+        let all_same = self.randomness.iter().all(|x| *x == self.randomness[0]);
+        !all_same && first != second
+    }
+
+    /// Returns the first 32 bytes of the randomness as a 256-bit integer
+    /// split into `(low, high)` 128-bit halves, e.g. for wide modular reduction.
+    ///
+    /// The bytes are read as a little-endian integer (the byte order of [`Self::weighted_choice`]):
+    /// `low` is `randomness[..16]` and `high` is `randomness[16..32]`, both little-endian,
+    /// so the integer is `high << 128 | low` (the first integer of `as_u256` with the `u256` feature).
+    ///
+    /// The remaining 32 bytes are not used — read them the same way if 512 bits are needed.
+    pub fn as_u128_pair(&self) -> (u128, u128) {
+        let low = u128::from_le_bytes(self.randomness[..16].try_into().unwrap());
+        let high = u128::from_le_bytes(self.randomness[16..32].try_into().unwrap());
+        (low, high)
+    }
+
+    /// Returns the randomness as two 256-bit integers — `randomness[..32]`
+    /// and `randomness[32..]`, both little-endian (see [`Self::as_u128_pair`]).
+    #[cfg(feature = "u256")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "u256")))]
+    pub fn as_u256(&self) -> [primitive_types::U256; 2] {
+        [
+            primitive_types::U256::from_little_endian(&self.randomness[..32]),
+            primitive_types::U256::from_little_endian(&self.randomness[32..]),
+        ]
+    }
+
+    /// Deterministically selects an index with probability proportional to its weight
+    /// (e.g. to pick a lottery winner or a loot table entry).
+    ///
+    /// The randomness is consumed as 128-bit little-endian chunks, and rejection sampling
+    /// is used to avoid the modulo bias, so the result is exactly proportional to the weights.
+    /// If all the chunks are rejected (this is astronomically unlikely), then the sampling
+    /// continues with `sha256(randomness || counter)` chunks.
+    ///
+    /// Every index owns a contiguous range of the sampled value proportional to its weight,
+    /// in the slice order, so there are no ties to break, and zero-weight entries
+    /// are never selected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` are empty or sum up to zero.
+    pub fn weighted_choice(&self, weights: &[u64]) -> usize {
+        let total = weights.iter().map(|x| *x as u128).sum::<u128>();
+        assert!(total > 0, "weights must sum up to a positive value");
+
+        // samples above this value are rejected (2^128 is not a multiple of `total`)
+        let zone = u128::MAX - (u128::MAX - total + 1) % total;
+        let sample = (0u64..)
+            .flat_map(|counter| {
+                let bytes = match counter {
+                    0 => self.randomness,
+                    _ => {
+                        let first = hashv(&[&self.randomness, &counter.to_le_bytes(), &[0]]);
+                        let second = hashv(&[&self.randomness, &counter.to_le_bytes(), &[1]]);
+                        let mut bytes = [0_u8; 64];
+                        bytes[..32].copy_from_slice(first.as_ref());
+                        bytes[32..].copy_from_slice(second.as_ref());
+                        bytes
+                    }
+                };
+                (0..4).map(move |i| {
+                    u128::from_le_bytes(bytes[i * 16..(i + 1) * 16].try_into().unwrap())
+                })
+            })
+            .find(|x| *x <= zone)
+            .expect("the sequence is infinite")
+            % total;
+
+        let mut cumulative = 0_u128;
+        weights
+            .iter()
+            .position(|weight| {
+                cumulative += *weight as u128;
+                sample < cumulative
+            })
+            .expect("sample is less than the total weight")
+    }
 }
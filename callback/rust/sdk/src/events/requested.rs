@@ -12,7 +12,11 @@ pub struct Requested {
     pub seed: [u8; 32],
     /// The callback that would be called.
     pub callback: Option<ValidatedCallback>,
-    /// True if [`Requested::callback`] is a request-level callback.
+    /// True if [`Requested::callback`] is a request-level callback
+    /// (otherwise it is the client-level one at the time of the request).
+    ///
+    /// Note that ALT requests are always request-level
+    /// (see [`RequestedAlt::callback_override`](super::RequestedAlt::callback_override)).
     pub callback_override: bool,
 }
 
@@ -30,4 +34,13 @@ impl Requested {
             callback_override,
         }
     }
+
+    /// Returns `true` if the response is for this request.
+    ///
+    /// A request is identified by the [`Requested::client`] and [`Requested::seed`] pair
+    /// (the request PDA is derived from it) — neither the callback nor the response
+    /// authority and randomness take part in the comparison.
+    pub fn same_request(&self, responded: &super::Responded) -> bool {
+        self.client == responded.client && self.seed == responded.seed
+    }
 }
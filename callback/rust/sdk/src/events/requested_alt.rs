@@ -11,6 +11,8 @@ pub struct RequestedAlt {
     pub client: Pubkey,
     pub seed: [u8; 32],
     /// The callback that would be called.
+    ///
+    /// It is always a request-level callback (see [`RequestedAlt::callback_override`]).
     pub callback: Option<ValidatedCallbackAlt>,
     /// Lookup Tables given to the callback.
     pub lookup_tables: Vec<Pubkey>,
@@ -30,4 +32,15 @@ impl RequestedAlt {
             lookup_tables,
         }
     }
+
+    /// Always `true` — the counterpart of [`Requested::callback_override`].
+    ///
+    /// The client-level callback is never applied to ALT requests, so
+    /// [`RequestedAlt::callback`] is always a request-level callback,
+    /// and no callback is called if it is `None`.
+    ///
+    /// [`Requested::callback_override`]: super::Requested::callback_override
+    pub fn callback_override(&self) -> bool {
+        true
+    }
 }
@@ -19,4 +19,9 @@ impl Transferred {
             new_owner,
         }
     }
+
+    /// Returns `true` if the client was "transferred" to its current owner.
+    pub fn is_noop(&self) -> bool {
+        self.owner == self.new_owner
+    }
 }
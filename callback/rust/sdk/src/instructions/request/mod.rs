@@ -14,6 +14,7 @@ use super::super::{
 
 /// [`Request`] instruction parameters.
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+#[cfg_attr(feature = "sdk", derive(Debug))]
 #[non_exhaustive]
 pub struct RequestParams {
     /// A random seed necessary to verify the generated randomness.
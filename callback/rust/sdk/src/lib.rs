@@ -9,6 +9,24 @@
 //!
 //!  * `sdk` (default) — use this feature to build an off-chain client
 //!  * `cpi` — use this feature to integrate your program with the oracle
+//!  * `otel` — adds [`Event::otel_attributes`] (OpenTelemetry span attributes)
+//!  * `zstd`, `gzip` — read events from compressed log archives
+//!    (see [`events_in_log_lines`])
+//!  * `webhook` — signs events forwarded to webhooks (see `WebhookPayload`)
+//!  * `idl` — decodes events dynamically given the program IDL (see `IdlEventDecoder`)
+//!  * `js` — (de)serializes events in the JSON shape of the JS SDK (see `JsEvent`)
+//!  * `jsonl` — exports events as JSON lines (see `write_jsonl`)
+//!  * `blocking` — blocking RPC helpers for synchronous code without an async runtime
+//!    (see `wait_for_fulfillment_blocking`). Note that `tokio` is still a dependency
+//!    of the `sdk` feature; the helpers just never touch it and block the calling thread
+//!    (polling instead of subscribing and fetching transactions sequentially),
+//!    so prefer the async helpers within an async runtime
+//!  * `test-vrf` — **for tests only**: evaluates the VRF locally given oracle secret keys
+//!    (see `sdk::test_vrf`), so that tests could predict the fulfilled randomness
+//!  * `tui` — colors and glyphs of event kinds for terminal dashboards
+//!    (see `EventKind::style`)
+//!  * `u256` — reads the randomness as `primitive-types` 256-bit integers
+//!    (see `Fulfilled::as_u256`)
 //!
 //!     ```toml
 //!     [dependencies.orao-solana-vrf-cb]
@@ -17,6 +35,21 @@
 //!     features = ["cpi"]
 //!     ```
 //!
+//! ### Dependency footprint
+//!
+//! Only the `sdk` feature depends on `anchor-client` (and thus pins a specific
+//! `solana-sdk` version). With default features disabled the crate depends on `anchor-lang`
+//! alone, so the account types and the [`events`] are usable along with another
+//! Solana client stack — decode the `Program data:` records with
+//! `AnchorDeserialize` after checking the event [`Discriminator`]:
+//!
+//! ```toml
+//! [dependencies.orao-solana-vrf-cb]
+//! version = "..."
+//! default-features = false
+//! features = ["no-entrypoint"]
+//! ```
+//!
 //! ## Integration
 //!
 //! The integration process consists of the following steps:
@@ -199,6 +232,9 @@
 //! [`callback_deadline`]: crate::state::network_state::NetworkConfiguration::callback_deadline
 //! [`parse_lookup_tables`]: crate::utils::parse_lookup_tables
 //! [`Context::remaining_accounts`]: anchor_lang::prelude::Context::remaining_accounts
+//! [`Discriminator`]: anchor_lang::Discriminator
+//! [`Event::otel_attributes`]: crate::sdk::Event
+//! [`events_in_log_lines`]: crate::sdk::events_in_log_lines
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod constants;
@@ -0,0 +1,149 @@
+use anchor_client::solana_sdk::{instruction::AccountMeta, sysvar};
+use anchor_lang::{
+    prelude::Pubkey, solana_program::address_lookup_table::AddressLookupTableAccount,
+    ToAccountMetas,
+};
+
+use super::Event;
+use crate::state::{
+    client::{Client, ValidatedRemainingAccountAlt},
+    network_state::{NetworkConfiguration, NetworkState},
+    request::RequestAccount,
+    request_alt::RequestAltAccount,
+};
+
+/// An error returned by [`expected_accounts`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub enum ExpectedAccountsError {
+    /// The event is neither [`Requested`] nor [`RequestedAlt`].
+    ///
+    /// [`Requested`]: crate::events::Requested
+    /// [`RequestedAlt`]: crate::events::RequestedAlt
+    #[error("not a request event")]
+    NotARequest,
+    /// The oracle is not one of the [`NetworkConfiguration::fulfill_authorities`].
+    #[error("{0} is not a fulfill authority")]
+    NotAnAuthority(Pubkey),
+    /// A lookup account of an ALT callback refers to a table that is not given
+    /// or to an address out of the table bounds.
+    #[error("lookup account #{address_index} of table #{table_index} is not resolved")]
+    UnresolvedLookup { table_index: u8, address_index: u8 },
+}
+
+/// Returns the accounts of the [`Fulfill`] (or the [`FulfillAlt`]) instruction expected
+/// by the program for the given request.
+///
+/// * `requested` — the [`Requested`] or the [`RequestedAlt`] event
+/// * `client` — the client account (see [`Requested::client`]) — it defines
+///   the client program and state
+/// * `config` — the active network configuration (see [`NetworkState::config`])
+/// * `oracle` — the fulfill authority that sends the fulfillment — it is
+///   the fee payer and the only signer (see [`NetworkConfiguration::fulfill_authorities`])
+/// * `lookup_tables` — the lookup tables of an ALT request (see [`RequestedAlt::lookup_tables`])
+///   to resolve the lookup accounts of the callback (ignored for [`Requested`])
+/// * `program_id` — the VRF program id (usually the [`crate::id`])
+///
+/// The instruction accounts are followed by the remaining accounts
+/// of the callback (see [`Requested::callback`]) if any.
+///
+/// Note that there is no fee recipient account: the request fee is paid
+/// to the [`NetworkConfiguration::treasury`] by the request instruction,
+/// so the fulfillment does not touch it.
+///
+/// This is meant for explorers and debugging — e.g. to compare the accounts
+/// of a fulfill transaction against the expected ones.
+///
+/// # Errors
+///
+/// See [`ExpectedAccountsError`].
+///
+/// [`Fulfill`]: crate::Fulfill
+/// [`FulfillAlt`]: crate::FulfillAlt
+/// [`Requested`]: crate::events::Requested
+/// [`Requested::client`]: crate::events::Requested::client
+/// [`Requested::callback`]: crate::events::Requested::callback
+/// [`RequestedAlt`]: crate::events::RequestedAlt
+/// [`RequestedAlt::lookup_tables`]: crate::events::RequestedAlt::lookup_tables
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn expected_accounts(
+    requested: &Event,
+    client: &Client,
+    config: &NetworkConfiguration,
+    oracle: &Pubkey,
+    lookup_tables: &[AddressLookupTableAccount],
+    program_id: &Pubkey,
+) -> Result<Vec<AccountMeta>, ExpectedAccountsError> {
+    if !config.is_fulfill_authority(oracle) {
+        return Err(ExpectedAccountsError::NotAnAuthority(*oracle));
+    }
+    let network_state = NetworkState::find_address(program_id).0;
+
+    match requested {
+        Event::Requested(ev) => {
+            let mut accounts = crate::accounts::Fulfill {
+                payer: *oracle,
+                program: client.program,
+                state: client.state,
+                client: ev.client,
+                request: RequestAccount::find_address(&ev.client, &ev.seed, program_id).0,
+                network_state,
+                instruction_acc: sysvar::instructions::ID,
+            }
+            .to_account_metas(None);
+
+            let remaining_accounts = ev
+                .callback
+                .iter()
+                .flat_map(|x| x.remaining_accounts())
+                .map(|x| account_meta(*x.pubkey(), x.is_writable()));
+            accounts.extend(remaining_accounts);
+
+            Ok(accounts)
+        }
+        Event::RequestedAlt(ev) => {
+            let mut accounts = crate::accounts::FulfillAlt {
+                payer: *oracle,
+                program: client.program,
+                state: client.state,
+                client: ev.client,
+                request: RequestAltAccount::find_address(&ev.client, &ev.seed, program_id).0,
+                network_state,
+                instruction_acc: sysvar::instructions::ID,
+            }
+            .to_account_metas(None);
+
+            for account in ev.callback.iter().flat_map(|x| x.remaining_accounts()) {
+                let meta = match account {
+                    ValidatedRemainingAccountAlt::Plain(x) => {
+                        account_meta(*x.pubkey(), x.is_writable())
+                    }
+                    ValidatedRemainingAccountAlt::Lookup(x) => {
+                        let address = ev
+                            .lookup_tables
+                            .get(usize::from(x.table_index()))
+                            .and_then(|key| lookup_tables.iter().find(|t| t.key == *key))
+                            .and_then(|table| table.addresses.get(usize::from(x.address_index())))
+                            .ok_or(ExpectedAccountsError::UnresolvedLookup {
+                                table_index: x.table_index(),
+                                address_index: x.address_index(),
+                            })?;
+                        account_meta(*address, x.is_writable())
+                    }
+                };
+                accounts.push(meta);
+            }
+
+            Ok(accounts)
+        }
+        _ => Err(ExpectedAccountsError::NotARequest),
+    }
+}
+
+fn account_meta(pubkey: Pubkey, is_writable: bool) -> AccountMeta {
+    match is_writable {
+        true => AccountMeta::new(pubkey, false),
+        false => AccountMeta::new_readonly(pubkey, false),
+    }
+}
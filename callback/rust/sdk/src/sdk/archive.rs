@@ -0,0 +1,66 @@
+use std::io::{self, BufRead};
+
+use anchor_lang::prelude::Pubkey;
+
+use super::{logs::InvocationFrames, Event};
+
+/// Parses VRF events from a stream of newline-delimited log records.
+///
+/// This is the format of log archives — the logs of consecutive transactions
+/// written one record per line. Records are read lazily, so the archive is never
+/// loaded into memory as a whole. Empty lines are skipped.
+///
+/// Events are yielded in the log emission order (see [`parse_logs`]), and every
+/// read or parse error is yielded in place of the corresponding event.
+///
+/// [`parse_logs`]: super::parse_logs
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn events_in_log_lines<R: BufRead>(reader: R) -> impl Iterator<Item = io::Result<Event>> {
+    program_events_in_log_lines(&crate::id(), reader)
+}
+
+/// Same as [`events_in_log_lines`] but for the given program id
+/// (see [`Network::events_in_log_lines`]).
+///
+/// [`Network::events_in_log_lines`]: super::Network::events_in_log_lines
+pub(crate) fn program_events_in_log_lines<R: BufRead>(
+    program_id: &Pubkey,
+    reader: R,
+) -> impl Iterator<Item = io::Result<Event>> {
+    let mut frames = InvocationFrames::new(program_id);
+    reader.lines().filter_map(move |line| match line {
+        Ok(line) => frames
+            .push_line(line.trim_end())
+            .map(Event::try_from_base64),
+        Err(err) => Some(Err(err)),
+    })
+}
+
+/// Parses VRF events from a zstd-compressed log archive
+/// (see [`events_in_log_lines`]).
+///
+/// The archive is decompressed on the fly.
+///
+/// # Errors
+///
+/// Errors if the decoder fails to initialize.
+#[cfg(feature = "zstd")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zstd")))]
+pub fn events_in_zstd_archive<R: io::Read>(
+    reader: R,
+) -> io::Result<impl Iterator<Item = io::Result<Event>>> {
+    let decoder = zstd::stream::read::Decoder::new(reader)?;
+    Ok(events_in_log_lines(io::BufReader::new(decoder)))
+}
+
+/// Parses VRF events from a gzip-compressed log archive
+/// (see [`events_in_log_lines`]).
+///
+/// The archive is decompressed on the fly. Multi-member archives
+/// (e.g. concatenated `.gz` files) are supported.
+#[cfg(feature = "gzip")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gzip")))]
+pub fn events_in_gzip_archive<R: io::Read>(reader: R) -> impl Iterator<Item = io::Result<Event>> {
+    let decoder = flate2::read::MultiGzDecoder::new(reader);
+    events_in_log_lines(io::BufReader::new(decoder))
+}
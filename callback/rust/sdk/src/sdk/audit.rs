@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CallbackOutcome, Event, RequestLifecycle, ResolvedEvent};
+
+/// A flat per-request record for compliance exports (see [`RequestLifecycle::to_audit_record`]).
+///
+/// Every field is a scalar (or a missing one), so a record is a single row of a CSV
+/// or a database table as well as a JSON object. Addresses, seeds, randomness and
+/// signatures are base58 strings, times are unix timestamps.
+///
+/// The schema is versioned with [`AuditRecord::schema_version`]: fields are only added
+/// within a version (so readers should ignore unknown fields), while renaming or
+/// removing a field or changing its meaning bumps the version.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct AuditRecord {
+    /// Always [`AuditRecord::SCHEMA_VERSION`] for records produced by this crate version.
+    pub schema_version: u32,
+    pub client: String,
+    pub seed: String,
+    /// The fulfilled randomness.
+    pub randomness: Option<String>,
+    pub request_signature: Option<String>,
+    pub request_slot: Option<u64>,
+    pub request_time: Option<i64>,
+    /// The number of observed oracle responses.
+    pub response_count: usize,
+    pub first_response_slot: Option<u64>,
+    pub last_response_slot: Option<u64>,
+    pub fulfill_signature: Option<String>,
+    pub fulfill_slot: Option<u64>,
+    pub fulfill_time: Option<i64>,
+    /// `true` if the request defines a request-level callback.
+    pub callback_defined: Option<bool>,
+    /// See [`Event::callback_override`].
+    pub callback_override: Option<bool>,
+    /// `true` if the callback executed (a [`CalledBack`] event is observed).
+    ///
+    /// [`CalledBack`]: crate::events::CalledBack
+    pub called_back: bool,
+    pub called_back_slot: Option<u64>,
+    /// One of `"not_defined"`, `"invoked"` or `"skipped"` (see [`CallbackOutcome`]).
+    pub callback_outcome: Option<String>,
+}
+
+impl AuditRecord {
+    /// The current schema version.
+    pub const SCHEMA_VERSION: u32 = 1;
+}
+
+impl RequestLifecycle {
+    /// Consolidates the observed events of this request into a single audit record.
+    ///
+    /// Unlike [`RequestReceipt`] it is also produced for requests that are not completed
+    /// (the missing parts are empty). Note that the callback is only known
+    /// for a request-level one — the client-level callback is not a part of the events
+    /// (see [`RequestReceipt::with_callback_updated`]).
+    ///
+    /// [`RequestReceipt`]: super::RequestReceipt
+    /// [`RequestReceipt::with_callback_updated`]: super::RequestReceipt::with_callback_updated
+    #[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+    pub fn to_audit_record(&self) -> AuditRecord {
+        let requested = self.requested.as_ref();
+        let fulfilled = self.fulfilled.as_ref();
+        let signature = |x: &ResolvedEvent| x.signature.to_string();
+
+        AuditRecord {
+            schema_version: AuditRecord::SCHEMA_VERSION,
+            client: self.client.to_string(),
+            seed: self.seed.to_string(),
+            randomness: self.randomness().map(|x| x.to_string()),
+            request_signature: requested.map(signature),
+            request_slot: self.requested_slot(),
+            request_time: requested.and_then(|x| x.block_time),
+            response_count: self.responses.len(),
+            first_response_slot: self.responses.iter().map(|x| x.slot).min(),
+            last_response_slot: self.responses.iter().map(|x| x.slot).max(),
+            fulfill_signature: fulfilled.map(signature),
+            fulfill_slot: self.fulfilled_slot(),
+            fulfill_time: fulfilled.and_then(|x| x.block_time),
+            callback_defined: requested.and_then(|x| match &x.event {
+                Event::Requested(ev) => Some(ev.callback.is_some()),
+                Event::RequestedAlt(ev) => Some(ev.callback.is_some()),
+                _ => None,
+            }),
+            callback_override: requested.and_then(|x| x.event.callback_override()),
+            called_back: self.called_back.is_some(),
+            called_back_slot: self.called_back.as_ref().map(|x| x.slot),
+            callback_outcome: self.callback_outcome().map(|x| {
+                match x {
+                    CallbackOutcome::NotDefined => "not_defined",
+                    CallbackOutcome::Invoked => "invoked",
+                    CallbackOutcome::Skipped => "skipped",
+                }
+                .to_string()
+            }),
+        }
+    }
+}
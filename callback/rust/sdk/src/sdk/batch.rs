@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use futures::{Stream, StreamExt};
+
+use super::ResolvedEvent;
+
+/// Groups a stream of events into per-slot batches.
+///
+/// A batch is emitted whenever the slot changes, so every batch contains consecutive
+/// events of a single slot (e.g. to write them to a database transactionally —
+/// this also aligns with reorg boundaries). The last batch is emitted once the inner
+/// stream ends.
+///
+/// If `flush_timeout` is given, then a pending batch is emitted after no events arrived
+/// for the timeout (a slot is never known to be complete until the next one begins).
+/// Events of the same slot that arrive later form a separate batch in this case.
+/// Requires a Tokio runtime if the timeout is given.
+///
+/// Empty batches are never emitted.
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn batch_by_slot<S: Stream<Item = ResolvedEvent>>(
+    events: S,
+    flush_timeout: Option<Duration>,
+) -> impl Stream<Item = Vec<ResolvedEvent>> {
+    let state = (Box::pin(events), Vec::<ResolvedEvent>::new(), false);
+
+    futures::stream::unfold(state, move |(mut events, mut batch, mut done)| async move {
+        loop {
+            if done {
+                return (!batch.is_empty())
+                    .then(|| (std::mem::take(&mut batch), (events, batch, done)));
+            }
+
+            let next = match flush_timeout {
+                Some(timeout) if !batch.is_empty() => {
+                    match tokio::time::timeout(timeout, events.next()).await {
+                        Ok(next) => next,
+                        Err(_) => {
+                            return Some((std::mem::take(&mut batch), (events, batch, done)));
+                        }
+                    }
+                }
+                _ => events.next().await,
+            };
+
+            match next {
+                Some(resolved) if batch.last().is_some_and(|x| x.slot != resolved.slot) => {
+                    let ready = std::mem::replace(&mut batch, vec![resolved]);
+                    return Some((ready, (events, batch, done)));
+                }
+                Some(resolved) => batch.push(resolved),
+                None => done = true,
+            }
+        }
+    })
+}
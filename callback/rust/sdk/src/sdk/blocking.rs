@@ -0,0 +1,138 @@
+// The helpers return the solana client error as is, like their async counterparts do.
+#![allow(clippy::result_large_err)]
+
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use anchor_client::solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    rpc_client::RpcClient,
+};
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+
+use super::{
+    transaction::{program_events_in_transaction, transaction_config},
+    Randomness, RequestStatus, ResolvedEvent, SignaturePager,
+};
+
+/// The interval between request account polls of [`wait_for_fulfillment_blocking`].
+pub const FULFILLMENT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Fetches the request status using the blocking RPC client
+/// (see [`RequestStatus::from_account_data`]).
+///
+/// *   `request` — the request PDA (see [`RequestAccount::find_address`]
+///     and [`RequestAltAccount::find_address`])
+///
+/// # Errors
+///
+/// Errors if the RPC call fails or the account is not a request account.
+///
+/// [`RequestAccount::find_address`]: crate::state::request::RequestAccount::find_address
+/// [`RequestAltAccount::find_address`]: crate::state::request_alt::RequestAltAccount::find_address
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub fn fetch_request_status_blocking(
+    rpc: &RpcClient,
+    request: &Pubkey,
+) -> Result<RequestStatus, ClientError> {
+    let account = rpc
+        .get_account_with_commitment(request, rpc.commitment())?
+        .value;
+    let data = account.as_ref().map(|x| &x.data[..]).unwrap_or_default();
+    Ok(RequestStatus::from_account_data(data)?)
+}
+
+/// Polls the request account until the request is fulfilled
+/// (see [`fetch_request_status_blocking`]).
+///
+/// The account is polled every [`FULFILLMENT_POLL_INTERVAL`], and the missing account
+/// is treated as not yet created (i.e. the request transaction has not landed yet).
+/// Blocks the calling thread — use [`watch_request_account`] within an async runtime.
+///
+/// Returns `None` if the request is not fulfilled within the `timeout`.
+///
+/// # Errors
+///
+/// Errors on the first failed RPC call or if the account is not a request account.
+///
+/// [`watch_request_account`]: super::watch_request_account
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub fn wait_for_fulfillment_blocking(
+    rpc: &RpcClient,
+    request: &Pubkey,
+    timeout: Duration,
+) -> Result<Option<Randomness>, ClientError> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let RequestStatus::Fulfilled { randomness } =
+            fetch_request_status_blocking(rpc, request)?
+        {
+            return Ok(Some(randomness));
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return Ok(None);
+        }
+        thread::sleep(FULFILLMENT_POLL_INTERVAL.min(deadline - now));
+    }
+}
+
+/// Fetches the VRF events of every successful transaction involving the program,
+/// oldest first.
+///
+/// This is a blocking backfill: signatures are walked with the [`SignaturePager`]
+/// (as [`signature_pages`] does) and every transaction is fetched and decoded
+/// with [`events_in_transaction`] one by one, so it is only suitable for
+/// a bounded history.
+///
+/// *   `until` — stops at this signature (exclusive), e.g. the last one processed
+///     by the previous backfill; walks the whole history if `None`.
+///
+/// # Errors
+///
+/// Errors on the first failed RPC call or if any of the events fails to parse.
+///
+/// [`signature_pages`]: super::signature_pages
+/// [`events_in_transaction`]: super::events_in_transaction
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub fn fetch_all_events_blocking(
+    rpc: &RpcClient,
+    until: Option<Signature>,
+) -> Result<Vec<ResolvedEvent>, ClientError> {
+    fetch_all_program_events_blocking(&crate::id(), rpc, until)
+}
+
+/// Same as [`fetch_all_events_blocking`] but for the given program id
+/// (see [`Network::fetch_all_events_blocking`]).
+///
+/// [`Network::fetch_all_events_blocking`]: super::Network::fetch_all_events_blocking
+pub(crate) fn fetch_all_program_events_blocking(
+    program_id: &Pubkey,
+    rpc: &RpcClient,
+    until: Option<Signature>,
+) -> Result<Vec<ResolvedEvent>, ClientError> {
+    let mut pager = SignaturePager::new(until);
+    let mut signatures = Vec::new();
+    while let Some(config) = pager.next_config(Some(rpc.commitment())) {
+        let page = rpc.get_signatures_for_address_with_config(program_id, config)?;
+        let page = pager
+            .push_page(page)
+            .map_err(|err| ClientErrorKind::Custom(format!("malformed signature: {err}")))?;
+        signatures.extend(page.into_iter().flatten().filter(|x| x.err.is_none()));
+    }
+
+    let mut events = Vec::new();
+    for status in signatures.iter().rev() {
+        let signature = status
+            .signature
+            .parse::<Signature>()
+            .map_err(|err| ClientErrorKind::Custom(format!("malformed signature: {err}")))?;
+        let tx =
+            rpc.get_transaction_with_config(&signature, transaction_config(rpc.commitment()))?;
+        events.extend(program_events_in_transaction(program_id, &tx)?);
+    }
+    Ok(events)
+}
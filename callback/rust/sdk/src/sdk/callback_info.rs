@@ -0,0 +1,249 @@
+use core::fmt;
+
+use anchor_lang::prelude::Pubkey;
+
+use super::{Event, ResolvedEvent};
+use crate::{
+    events::{Requested, RequestedAlt},
+    state::client::ValidatedRemainingAccountAlt,
+};
+
+/// A remaining account of a callback (see [`CallbackInfo`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub enum CallbackAccount {
+    /// An account given by its address.
+    Plain { pubkey: Pubkey, is_writable: bool },
+    /// An account of a lookup table given to an ALT request.
+    Lookup {
+        /// The lookup table address — `None` if the index is out of bounds
+        /// of [`RequestedAlt::lookup_tables`].
+        table: Option<Pubkey>,
+        table_index: u8,
+        address_index: u8,
+        is_writable: bool,
+    },
+}
+
+impl CallbackAccount {
+    pub fn is_writable(&self) -> bool {
+        match self {
+            CallbackAccount::Plain { is_writable, .. }
+            | CallbackAccount::Lookup { is_writable, .. } => *is_writable,
+        }
+    }
+}
+
+/// The callback attached to a request, for auditing — "calls program X with data Y".
+///
+/// The callback is invoked on the client program (see [`Registered::program`])
+/// with the fixed accounts (see [`Callback::remaining_accounts`]) followed by
+/// the [`CallbackInfo::accounts`].
+///
+/// [`Registered::program`]: crate::events::Registered::program
+/// [`Callback::remaining_accounts`]: crate::state::client::Callback::remaining_accounts
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct CallbackInfo {
+    /// The client program the callback is invoked on.
+    pub program: Pubkey,
+    /// The remaining accounts of the callback instruction.
+    pub accounts: Vec<CallbackAccount>,
+    /// The callback instruction data.
+    pub data: Vec<u8>,
+}
+
+impl Requested {
+    /// Returns the callback details of this request.
+    ///
+    /// *   `program` — the client program, which is not a part of the event
+    ///     (see [`Registered::program`] or [`Client::program`])
+    ///
+    /// Returns `None` if there is no callback attached to the event. Note that a request
+    /// without a request-level callback still gets the client-level one unless
+    /// [`Requested::callback_override`] is set — that one is not a part of the event.
+    ///
+    /// [`Registered::program`]: crate::events::Registered::program
+    /// [`Client::program`]: crate::state::client::Client::program
+    #[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+    pub fn callback_info(&self, program: Pubkey) -> Option<CallbackInfo> {
+        let callback = self.callback.as_ref()?;
+        Some(CallbackInfo {
+            program,
+            accounts: callback
+                .remaining_accounts()
+                .iter()
+                .map(|x| CallbackAccount::Plain {
+                    pubkey: *x.pubkey(),
+                    is_writable: x.is_writable(),
+                })
+                .collect(),
+            data: callback.data().to_vec(),
+        })
+    }
+}
+
+impl RequestedAlt {
+    /// Returns the callback details of this request (see [`Requested::callback_info`]).
+    ///
+    /// Returns `None` if there is no callback — no callback is called in this case.
+    /// Lookup accounts refer to [`RequestedAlt::lookup_tables`], and the addresses
+    /// in the tables are not resolved (that requires fetching the tables).
+    #[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+    pub fn callback_info(&self, program: Pubkey) -> Option<CallbackInfo> {
+        let callback = self.callback.as_ref()?;
+        Some(CallbackInfo {
+            program,
+            accounts: callback
+                .remaining_accounts()
+                .iter()
+                .map(|x| match x {
+                    ValidatedRemainingAccountAlt::Plain(x) => CallbackAccount::Plain {
+                        pubkey: *x.pubkey(),
+                        is_writable: x.is_writable(),
+                    },
+                    ValidatedRemainingAccountAlt::Lookup(x) => CallbackAccount::Lookup {
+                        table: self.lookup_tables.get(x.table_index() as usize).copied(),
+                        table_index: x.table_index(),
+                        address_index: x.address_index(),
+                        is_writable: x.is_writable(),
+                    },
+                })
+                .collect(),
+            data: callback.data().to_vec(),
+        })
+    }
+}
+
+impl fmt::Display for CallbackAccount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let access = if self.is_writable() {
+            "writable"
+        } else {
+            "read-only"
+        };
+        match self {
+            CallbackAccount::Plain { pubkey, .. } => write!(f, "{pubkey} ({access})"),
+            CallbackAccount::Lookup {
+                table: Some(table),
+                address_index,
+                ..
+            } => write!(f, "{table}[{address_index}] ({access})"),
+            CallbackAccount::Lookup {
+                table: None,
+                table_index,
+                address_index,
+                ..
+            } => write!(f, "<table #{table_index}>[{address_index}] ({access})"),
+        }
+    }
+}
+
+/// Formats the callback as `calls <program> with data 0x<hex>`
+/// followed by the accounts (one per line in the alternate form).
+impl fmt::Display for CallbackInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "calls {} with data 0x", self.program)?;
+        for byte in &self.data {
+            write!(f, "{byte:02x}")?;
+        }
+        if self.accounts.is_empty() {
+            return write!(f, " and no remaining accounts");
+        }
+
+        write!(f, " and remaining accounts:")?;
+        for (i, account) in self.accounts.iter().enumerate() {
+            match (f.alternate(), i) {
+                (true, _) => write!(f, "\n  {account}")?,
+                (false, 0) => write!(f, " {account}")?,
+                (false, _) => write!(f, ", {account}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The callback that applies to a request (see [`resolve_effective_callback`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub enum EffectiveCallback {
+    /// No callback is called — the request gives none and the client has none.
+    None,
+    /// The request-level callback given with the request
+    /// (the [`Requested::callback`] or the [`RequestedAlt::callback`]).
+    RequestLevel,
+    /// The client-level callback, a snapshot of which is the [`Requested::callback`].
+    ClientLevel {
+        /// The latest [`CallbackUpdated`] event of the client preceding the request
+        /// (`None` if it is not observed, e.g. the callback is given at registration).
+        ///
+        /// [`CallbackUpdated`]: crate::events::CallbackUpdated
+        updated: Option<Box<ResolvedEvent>>,
+    },
+}
+
+/// Resolves which callback applies to the given request — the request-level
+/// override or the client-level default.
+///
+/// *   `requested` — the [`Requested`] or the [`RequestedAlt`] event
+/// *   `callback_history` — the [`CallbackUpdated`] events of the client (others are ignored)
+///
+/// The precedence:
+///
+/// 1.  a request-level callback ([`Requested::callback_override`]) — it is called
+///     instead of the client-level one; the client-level callback never applies
+///     to a [`RequestedAlt`], so it is either a request-level callback or none
+/// 2.  otherwise, the client-level callback at the time of the request — the one defined
+///     by the latest preceding [`CallbackUpdated`] event (an event of an earlier slot,
+///     an earlier event of the same transaction, or an event of another transaction
+///     of the same slot, as their order is unknown)
+/// 3.  no callback if neither is defined
+///
+/// The event carries the snapshot of the applied callback, so it is the source of truth,
+/// while the history attributes a client-level callback to its update and is checked
+/// against it: returns `None` if the latest preceding update contradicts the event
+/// (i.e. some updates are missing from the history) or if `requested` is not a request.
+///
+/// [`CallbackUpdated`]: crate::events::CallbackUpdated
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn resolve_effective_callback<'a>(
+    requested: &ResolvedEvent,
+    callback_history: impl IntoIterator<Item = &'a ResolvedEvent>,
+) -> Option<EffectiveCallback> {
+    let (client, defined) = match &requested.event {
+        Event::Requested(ev) if ev.callback_override => {
+            return Some(EffectiveCallback::RequestLevel)
+        }
+        Event::Requested(ev) => (ev.client, ev.callback.is_some()),
+        Event::RequestedAlt(ev) if ev.callback.is_some() => {
+            return Some(EffectiveCallback::RequestLevel)
+        }
+        Event::RequestedAlt(_) => return Some(EffectiveCallback::None),
+        _ => return None,
+    };
+
+    let precedes = |x: &ResolvedEvent| {
+        x.slot < requested.slot
+            || (x.slot == requested.slot
+                && (x.signature != requested.signature || x.index < requested.index))
+    };
+    let updated = callback_history
+        .into_iter()
+        .filter(|x| matches!(&x.event, Event::CallbackUpdated(ev) if ev.client == client))
+        .filter(|x| precedes(x))
+        .max_by_key(|x| (x.slot, x.signature == requested.signature, x.index));
+    let updated_defined = updated.map(|x| match &x.event {
+        Event::CallbackUpdated(ev) => ev.defined,
+        _ => unreachable!("filtered above"),
+    });
+
+    match (defined, updated_defined) {
+        (true, None | Some(true)) => Some(EffectiveCallback::ClientLevel {
+            updated: updated.cloned().map(Box::new),
+        }),
+        (false, None | Some(false)) => Some(EffectiveCallback::None),
+        _ => None,
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+
+use super::{Event, ResolvedEvent};
+
+/// A change of the callback program of a client (see [`CallbackProgramMonitor`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct CallbackProgramChanged {
+    pub client: Pubkey,
+    /// The program observed before.
+    pub old: Pubkey,
+    /// The program observed now.
+    pub new: Pubkey,
+    /// The slot of the event that revealed the change.
+    pub slot: u64,
+    /// The signature of the transaction that revealed the change.
+    pub signature: Signature,
+}
+
+/// Tracks the callback program of every client and reports changes,
+/// e.g. to alert on a compromised account or spoofed logs.
+///
+/// The callback program of a client is the program it is registered for — the client PDA
+/// is derived from that program (see [`Client::find_address`]), and callbacks
+/// (request-level ones included) always call it, while [`CallbackUpdated`] only changes
+/// the callback accounts and data, so it does not name the program. The program is
+/// observed with:
+///
+/// *   [`Registered`] events
+/// *   [`CalledBack`] events — attributed to the client of the preceding [`Fulfilled`]
+///     event of the same transaction, so push the events of a transaction
+///     in the emission order (as returned by [`events_in_transaction`])
+///
+/// The first observation of a client is its baseline (see [`CallbackProgramMonitor::with_program`]
+/// to start from a known one), and every observed change is reported once —
+/// the new program becomes the baseline. Since the program cannot legitimately change,
+/// any report deserves investigation.
+///
+/// [`Client::find_address`]: crate::state::client::Client::find_address
+/// [`CallbackUpdated`]: crate::events::CallbackUpdated
+/// [`Registered`]: crate::events::Registered
+/// [`CalledBack`]: crate::events::CalledBack
+/// [`Fulfilled`]: crate::events::Fulfilled
+/// [`events_in_transaction`]: super::events_in_transaction
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct CallbackProgramMonitor {
+    programs: HashMap<Pubkey, Pubkey>,
+    /// The client of the last fulfilled request along with the fulfill transaction signature.
+    last_fulfilled: Option<(Signature, Pubkey)>,
+}
+
+impl CallbackProgramMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the known callback program of the client.
+    pub fn with_program(mut self, client: Pubkey, program: Pubkey) -> Self {
+        self.programs.insert(client, program);
+        self
+    }
+
+    /// Returns the callback program of the client observed last.
+    pub fn program(&self, client: &Pubkey) -> Option<Pubkey> {
+        self.programs.get(client).copied()
+    }
+
+    /// Records the given event.
+    ///
+    /// Returns the change if the event reveals one.
+    pub fn push(&mut self, resolved: &ResolvedEvent) -> Option<CallbackProgramChanged> {
+        let (client, program) = match &resolved.event {
+            Event::Registered(ev) => (ev.client, ev.program),
+            Event::Fulfilled(ev) => {
+                self.last_fulfilled = Some((resolved.signature, ev.client));
+                return None;
+            }
+            Event::CalledBack(ev) => match self.last_fulfilled {
+                Some((signature, client)) if signature == resolved.signature => {
+                    (client, ev.program)
+                }
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        match self.programs.insert(client, program) {
+            Some(old) if old != program => Some(CallbackProgramChanged {
+                client,
+                old,
+                new: program,
+                slot: resolved.slot,
+                signature: resolved.signature,
+            }),
+            _ => None,
+        }
+    }
+}
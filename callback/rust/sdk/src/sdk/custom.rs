@@ -0,0 +1,175 @@
+use core::fmt;
+use std::{
+    any::Any,
+    collections::HashMap,
+    io,
+    sync::{Arc, LazyLock, RwLock},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::events::is_built_in_discriminator;
+
+/// A parser of a custom event registered with [`register_custom_event`].
+///
+/// It is given the event payload (the bytes following the discriminator)
+/// and returns the decoded event (see [`CustomEvent::downcast_ref`]).
+pub type CustomEventParser = fn(&[u8]) -> io::Result<Box<dyn Any + Send + Sync>>;
+
+/// An error returned by [`register_custom_event`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub enum CustomEventError {
+    #[error("discriminator belongs to a built-in event")]
+    BuiltIn,
+    #[error("discriminator is already registered")]
+    AlreadyRegistered,
+}
+
+static REGISTRY: LazyLock<RwLock<HashMap<[u8; 8], CustomEventParser>>> =
+    LazyLock::new(Default::default);
+
+/// Registers a custom event, so that [`Event::try_from_bytes`] (and thus every
+/// event extractor of this crate) recognizes it as [`Event::Custom`].
+///
+/// This is meant for forks of the program that emit additional events.
+/// The registry is global and thread-safe, so register custom events once at startup —
+/// events parsed before the registration are not recognized.
+///
+/// # Errors
+///
+/// *   [`CustomEventError::BuiltIn`] — built-in events could not be overridden
+/// *   [`CustomEventError::AlreadyRegistered`] — the existing registration is kept
+///
+/// [`Event::try_from_bytes`]: super::Event::try_from_bytes
+/// [`Event::Custom`]: super::Event::Custom
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn register_custom_event(
+    discriminator: [u8; 8],
+    parser: CustomEventParser,
+) -> Result<(), CustomEventError> {
+    if is_built_in_discriminator(&discriminator) {
+        return Err(CustomEventError::BuiltIn);
+    }
+    let mut registry = REGISTRY
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if registry.contains_key(&discriminator) {
+        return Err(CustomEventError::AlreadyRegistered);
+    }
+    registry.insert(discriminator, parser);
+    Ok(())
+}
+
+/// Parses a custom event.
+///
+/// Returns `None` if the discriminator is not registered.
+pub(crate) fn parse_custom_event(
+    discriminator: &[u8; 8],
+    payload: &[u8],
+) -> Option<io::Result<CustomEvent>> {
+    let parser = *REGISTRY
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(discriminator)?;
+
+    Some(parser(payload).map(|value| CustomEvent {
+        discriminator: *discriminator,
+        payload: payload.to_vec(),
+        value: value.into(),
+    }))
+}
+
+/// An event registered with [`register_custom_event`].
+///
+/// Events are compared by their binary representation.
+///
+/// It is serialized as the discriminator along with the base64-encoded payload,
+/// and is parsed again while deserializing (so it must be registered beforehand).
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct CustomEvent {
+    discriminator: [u8; 8],
+    payload: Vec<u8>,
+    value: Arc<dyn Any + Send + Sync>,
+}
+
+impl CustomEvent {
+    /// Returns the event discriminator.
+    pub fn discriminator(&self) -> &[u8; 8] {
+        &self.discriminator
+    }
+
+    /// Returns the event payload (the bytes following the discriminator).
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Returns the decoded event if it is of type `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+
+    /// Serializes the event the way the program emits it.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [&self.discriminator[..], &self.payload].concat()
+    }
+}
+
+impl fmt::Debug for CustomEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomEvent")
+            .field("discriminator", &self.discriminator)
+            .field("payload", &self.payload)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for CustomEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Custom: {:?} with {} bytes",
+            self.discriminator,
+            self.payload.len()
+        )
+    }
+}
+
+impl PartialEq for CustomEvent {
+    fn eq(&self, other: &Self) -> bool {
+        self.discriminator == other.discriminator && self.payload == other.payload
+    }
+}
+
+impl Eq for CustomEvent {}
+
+#[derive(Serialize, Deserialize)]
+struct CustomEventRepr {
+    discriminator: [u8; 8],
+    payload: String,
+}
+
+impl Serialize for CustomEvent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CustomEventRepr {
+            discriminator: self.discriminator,
+            payload: STANDARD.encode(&self.payload),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let repr = CustomEventRepr::deserialize(deserializer)?;
+        let payload = STANDARD.decode(&repr.payload).map_err(D::Error::custom)?;
+        parse_custom_event(&repr.discriminator, &payload)
+            .ok_or_else(|| D::Error::custom("unregistered custom event"))?
+            .map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::{HashSet, VecDeque};
+
+use anchor_client::solana_sdk::signature::Signature;
+use futures::{future, Stream, StreamExt};
+
+use super::ResolvedEvent;
+
+/// The number of keys [`SeenWindow`] allocates for upfront — the window might be
+/// huge (e.g. `usize::MAX` for "dedup everything"), so it grows on demand past that.
+const PREALLOCATED_KEYS: usize = 1024;
+
+/// The last `capacity` distinct `(signature, log_index)` pairs.
+struct SeenWindow {
+    order: VecDeque<(Signature, usize)>,
+    set: HashSet<(Signature, usize)>,
+    capacity: usize,
+}
+
+impl SeenWindow {
+    fn new(capacity: usize) -> Self {
+        Self {
+            order: VecDeque::with_capacity(capacity.min(PREALLOCATED_KEYS)),
+            set: HashSet::with_capacity(capacity.min(PREALLOCATED_KEYS)),
+            capacity,
+        }
+    }
+
+    /// Returns `true` if the key is not in the window, and remembers it.
+    fn insert(&mut self, key: (Signature, usize)) -> bool {
+        if self.capacity == 0 {
+            return true;
+        }
+        if !self.set.insert(key) {
+            return false;
+        }
+        if self.order.len() == self.capacity {
+            let oldest = self.order.pop_front().expect("the window is full");
+            self.set.remove(&oldest);
+        }
+        self.order.push_back(key);
+        true
+    }
+}
+
+/// Drops events already seen among the last `window` distinct events,
+/// e.g. duplicate notifications replayed by a subscription after a reconnection.
+///
+/// Events are identified by `(signature, log_index)` only — unlike
+/// [`MultiSourceMerger`] nothing else is tracked, so memory is bounded by the window.
+/// A repeat older than the window passes through, so size it to cover the longest
+/// expected replay. A `window` of zero disables deduplication.
+///
+/// [`MultiSourceMerger`]: super::MultiSourceMerger
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn dedup_by_signature<S: Stream<Item = ResolvedEvent>>(
+    events: S,
+    window: usize,
+) -> impl Stream<Item = ResolvedEvent> {
+    let mut seen = SeenWindow::new(window);
+    events.filter(move |x| future::ready(seen.insert((x.signature, x.log_index))))
+}
@@ -0,0 +1,77 @@
+use futures::{Stream, StreamExt};
+
+use super::Event;
+use crate::events::Fulfilled;
+
+/// An event yielded by [`with_derived`] and [`with_derived_stream`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub enum DerivedEvent<T> {
+    /// The fulfillment along with the value derived from its randomness.
+    Fulfilled(Fulfilled, T),
+    /// Any other event, passed through unchanged.
+    Other(Event),
+}
+
+impl<T> DerivedEvent<T> {
+    fn new(event: Event, derive: &mut impl FnMut(&Fulfilled) -> T) -> Self {
+        match event {
+            Event::Fulfilled(ev) => {
+                let value = derive(&ev);
+                DerivedEvent::Fulfilled(ev, value)
+            }
+            other => DerivedEvent::Other(other),
+        }
+    }
+
+    /// Returns the derived value if this is a fulfillment.
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            DerivedEvent::Fulfilled(_, value) => Some(value),
+            DerivedEvent::Other(_) => None,
+        }
+    }
+
+    /// Returns the event dropping the derived value.
+    pub fn into_event(self) -> Event {
+        match self {
+            DerivedEvent::Fulfilled(ev, _) => Event::Fulfilled(ev),
+            DerivedEvent::Other(event) => event,
+        }
+    }
+}
+
+/// Attaches the application-level value derived from the randomness
+/// to every [`Fulfilled`] event, e.g. to compute game results inline:
+///
+/// ```
+/// # use orao_solana_vrf_cb::sdk::{with_derived, Event};
+/// # fn f(events: Vec<Event>) {
+/// for event in with_derived(events, |ev| ev.weighted_choice(&[1, 2, 7])) {
+///     // ...
+/// }
+/// # }
+/// ```
+///
+/// `derive` is called once per fulfillment, in the iteration order.
+/// Other events are passed through unchanged.
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn with_derived<I, T, F>(events: I, mut derive: F) -> impl Iterator<Item = DerivedEvent<T>>
+where
+    I: IntoIterator<Item = Event>,
+    F: FnMut(&Fulfilled) -> T,
+{
+    events
+        .into_iter()
+        .map(move |event| DerivedEvent::new(event, &mut derive))
+}
+
+/// A stream version of [`with_derived`].
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn with_derived_stream<S, T, F>(events: S, mut derive: F) -> impl Stream<Item = DerivedEvent<T>>
+where
+    S: Stream<Item = Event>,
+    F: FnMut(&Fulfilled) -> T,
+{
+    events.map(move |event| DerivedEvent::new(event, &mut derive))
+}
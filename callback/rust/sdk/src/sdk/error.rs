@@ -1,3 +1,5 @@
+use anchor_lang::prelude::Pubkey;
+
 use crate::error::ErrorCode;
 
 impl std::error::Error for ErrorCode {}
@@ -28,3 +30,105 @@ impl ErrorCode {
         }
     }
 }
+
+/// An error code the program fails with, decoded from [`InstructionError::Custom`]
+/// or from the transaction logs (see [`VrfProgramError::from_logs`]).
+///
+/// Use it to turn a failed transaction into a meaningful diagnostic:
+///
+/// ```
+/// # use orao_solana_vrf_cb::sdk::VrfProgramError;
+/// let error = VrfProgramError::from_code(6004);
+/// assert_eq!(error.to_string(), "Too many accounts given (TooManyAccounts, code 6004)");
+/// ```
+///
+/// [`InstructionError::Custom`]: anchor_client::solana_sdk::instruction::InstructionError::Custom
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub enum VrfProgramError {
+    /// An error of the program itself.
+    Program(ErrorCode),
+    /// A code below [`ERROR_CODE_OFFSET`] — an error of the Anchor framework
+    /// (e.g. a failed account constraint).
+    ///
+    /// [`ERROR_CODE_OFFSET`]: anchor_lang::error::ERROR_CODE_OFFSET
+    Framework(u32),
+    /// A program error code unknown to this crate version.
+    Unknown(u32),
+}
+
+impl VrfProgramError {
+    /// Decodes the error code.
+    pub fn from_code(code: u32) -> Self {
+        if code < anchor_lang::error::ERROR_CODE_OFFSET {
+            Self::Framework(code)
+        } else {
+            ErrorCode::from_custom_error(code).map_or(Self::Unknown(code), Self::Program)
+        }
+    }
+
+    /// Returns the numeric error code.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::Program(code) => anchor_lang::error::ERROR_CODE_OFFSET + *code as u32,
+            Self::Framework(code) | Self::Unknown(code) => *code,
+        }
+    }
+
+    /// Parses the error of the VRF program from the transaction logs,
+    /// i.e. the `Program <id> failed: custom program error: 0x…` record.
+    ///
+    /// Failures of other programs of the transaction are ignored.
+    /// Returns `None` if the VRF program did not fail with a custom error.
+    pub fn from_logs<I>(logs: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        Self::from_program_logs(&crate::id(), logs)
+    }
+
+    /// Same as [`VrfProgramError::from_logs`] but for the given program id
+    /// (see [`Network::program_error_from_logs`]).
+    ///
+    /// [`Network::program_error_from_logs`]: super::Network::program_error_from_logs
+    pub(crate) fn from_program_logs<I>(program_id: &Pubkey, logs: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let prefix = format!("Program {program_id} failed: custom program error: 0x");
+        logs.into_iter().find_map(|line| {
+            let code = line.as_ref().strip_prefix(&prefix)?;
+            u32::from_str_radix(code.trim_end(), 16)
+                .ok()
+                .map(Self::from_code)
+        })
+    }
+}
+
+impl std::fmt::Display for VrfProgramError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Program(error) => write!(f, "{error} ({error:?}, code {})", self.code()),
+            Self::Framework(code) => write!(f, "{} error (code {code})", framework_category(*code)),
+            Self::Unknown(code) => write!(f, "Unknown program error (code {code})"),
+        }
+    }
+}
+
+impl std::error::Error for VrfProgramError {}
+
+/// Returns the category of an Anchor framework error code.
+fn framework_category(code: u32) -> &'static str {
+    match code {
+        100..1000 => "Anchor instruction",
+        1000..2000 => "Anchor IDL instruction",
+        2000..2500 => "Anchor constraint",
+        2500..3000 => "Anchor require",
+        3000..4000 => "Anchor account",
+        4100..5000 => "Anchor",
+        _ => "Framework",
+    }
+}
@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+use anchor_client::solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    nonblocking::rpc_client::RpcClient,
+};
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use tokio::time::Instant;
+
+use super::{
+    events_in_transaction, transaction::transaction_config, Event, ResolvedEvent, SignaturePager,
+};
+
+/// The interval between polls of [`wait_for_event`].
+pub const EVENT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The number of the most recent transactions [`wait_for_event`] looks through on the first poll.
+pub const EVENT_WAIT_LOOKBACK: usize = 10;
+
+/// Polls the transactions involving the given address until one of them emits
+/// an event matching the `predicate` and returns that event.
+///
+/// *   `address` — usually the client PDA, as every request, response, fulfillment
+///     and callback transaction of the client references it. Note that all the events
+///     of these transactions are given to the `predicate` including ones that do not
+///     name the client (e.g. [`CalledBack`]), so check the request seed if needed.
+///
+/// Polling semantics:
+///
+/// *   the first poll looks through the [`EVENT_WAIT_LOOKBACK`] most recent transactions,
+///     so an event emitted right before the call is not missed; every subsequent poll
+///     (every [`EVENT_POLL_INTERVAL`]) looks through the transactions confirmed since
+///     the previous one, oldest first
+/// *   failed transactions are skipped (see [`events_in_transaction_strict`])
+/// *   the RPC client commitment is used
+///
+/// Returns `None` if there is no matching event within the `timeout`
+/// (the address is polled at least once).
+///
+/// # Errors
+///
+/// Errors on the first failed RPC call or if any of the events fails to parse.
+///
+/// [`CalledBack`]: crate::events::CalledBack
+/// [`events_in_transaction_strict`]: super::events_in_transaction_strict
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub async fn wait_for_event<F>(
+    rpc: &RpcClient,
+    address: &Pubkey,
+    predicate: F,
+    timeout: Duration,
+) -> Result<Option<ResolvedEvent>, ClientError>
+where
+    F: Fn(&Event) -> bool,
+{
+    let deadline = Instant::now() + timeout;
+    let mut newest = None;
+
+    loop {
+        let mut pager = match newest {
+            Some(_) => SignaturePager::new(newest),
+            None => SignaturePager::new(None).with_limit(EVENT_WAIT_LOOKBACK),
+        };
+        let mut signatures = Vec::new();
+        while let Some(config) = pager.next_config(Some(rpc.commitment())) {
+            let page = rpc
+                .get_signatures_for_address_with_config(address, config)
+                .await?;
+            let page = pager.push_page(page).map_err(malformed_signature)?;
+            signatures.extend(page.into_iter().flatten());
+            if newest.is_none() {
+                // the lookback is a single page
+                break;
+            }
+        }
+        if let Some(status) = signatures.first() {
+            newest = Some(status.signature.parse().map_err(malformed_signature)?);
+        }
+
+        for status in signatures.iter().rev().filter(|x| x.err.is_none()) {
+            let signature = status
+                .signature
+                .parse::<Signature>()
+                .map_err(malformed_signature)?;
+            let tx = rpc
+                .get_transaction_with_config(&signature, transaction_config(rpc.commitment()))
+                .await?;
+            if let Some(event) = events_in_transaction(&tx)?
+                .into_iter()
+                .find(|x| predicate(&x.event))
+            {
+                return Ok(Some(event));
+            }
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            return Ok(None);
+        }
+        tokio::time::sleep(EVENT_POLL_INTERVAL.min(deadline - now)).await;
+    }
+}
+
+fn malformed_signature(err: impl std::fmt::Display) -> ClientError {
+    ClientErrorKind::Custom(format!("malformed signature: {err}")).into()
+}
@@ -1,20 +1,74 @@
 use core::fmt;
 use std::io;
 
-use anchor_client::solana_sdk::{bs58, native_token::LAMPORTS_PER_SOL};
-use anchor_lang::{prelude::borsh::BorshDeserialize, Discriminator};
+use anchor_client::solana_sdk::native_token::LAMPORTS_PER_SOL;
+use anchor_lang::{
+    prelude::{borsh::BorshDeserialize, Pubkey},
+    Discriminator, Event as _,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::{
+    error::ErrorCode,
+    events::{
+        CallbackUpdated, CalledBack, Fulfilled, Registered, Requested, RequestedAlt, Responded,
+        Transferred, Withdrawn,
+    },
+    state::client::{Callback, Client},
+};
 
-use crate::events::{
-    CallbackUpdated, CalledBack, Fulfilled, Registered, Requested, RequestedAlt, Responded,
-    Transferred, Withdrawn,
+use super::{
+    custom::parse_custom_event, CustomEvent, ParseBytesError, Randomness, Seed, PROGRAM_DATA_PREFIX,
 };
 
+/// Maximum size of an event representation accepted by [`Event::try_from_bytes`].
+///
+/// Solana truncates program logs at 10 000 bytes per transaction, so no legitimate
+/// event could exceed this size. Bounding the input also bounds every allocation
+/// made while deserializing variable-length fields.
+pub const MAX_EVENT_SIZE: usize = 10_000;
+
+/// Version of the representation written by [`Event::to_versioned_bytes`].
+pub const EVENT_FORMAT_VERSION: u8 = 1;
+
+/// It is an error indicating that a persisted event is written by an incompatible
+/// version of this crate (see [`Event::try_from_versioned_bytes`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("unsupported event format version {found} (expected {EVENT_FORMAT_VERSION})")]
+#[non_exhaustive]
+pub struct VersionMismatch {
+    /// The version of the representation.
+    pub found: u8,
+}
+
 /// It is an error indicating that the event discriminator does not match known events
 /// (see [`Event::try_from_bytes`]).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 #[error("unknown event")]
 pub struct UnknownEvent;
 
+/// It is an error indicating that the event representation is too short
+/// (see [`Event::try_from_bytes`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum TruncatedEvent {
+    /// The representation is empty.
+    #[error("empty event")]
+    Empty,
+    /// The representation is shorter than the 8-byte discriminator.
+    #[error("truncated event discriminator: {len} bytes")]
+    Discriminator { len: usize },
+    /// The discriminator is known, but the payload is shorter than the event layout requires.
+    #[error(
+        "truncated {kind} event: expected at least {expected} bytes of payload, found {actual}"
+    )]
+    Payload {
+        kind: EventKind,
+        expected: usize,
+        actual: usize,
+    },
+}
+
 /// This helper enumerates all the events emitted by the program.
 #[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
 #[non_exhaustive]
@@ -28,9 +82,102 @@ pub enum Event {
     Responded(crate::events::Responded),
     Transferred(crate::events::Transferred),
     Withdrawn(crate::events::Withdrawn),
+    /// An event of a program fork (see [`register_custom_event`](super::register_custom_event)).
+    Custom(CustomEvent),
+}
+
+/// A fieldless counterpart of [`Event`] (see [`Event::kind`]).
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[non_exhaustive]
+pub enum EventKind {
+    CallbackUpdated,
+    CalledBack,
+    Fulfilled,
+    Registered,
+    Requested,
+    RequestedAlt,
+    Responded,
+    Transferred,
+    Withdrawn,
+    Custom,
+}
+
+impl EventKind {
+    /// All the event kinds.
+    pub const ALL: [EventKind; 10] = [
+        EventKind::CallbackUpdated,
+        EventKind::CalledBack,
+        EventKind::Fulfilled,
+        EventKind::Registered,
+        EventKind::Requested,
+        EventKind::RequestedAlt,
+        EventKind::Responded,
+        EventKind::Transferred,
+        EventKind::Withdrawn,
+        EventKind::Custom,
+    ];
+
+    /// Returns the event name (e.g. `"Fulfilled"`).
+    pub const fn name(&self) -> &'static str {
+        match self {
+            EventKind::CallbackUpdated => "CallbackUpdated",
+            EventKind::CalledBack => "CalledBack",
+            EventKind::Fulfilled => "Fulfilled",
+            EventKind::Registered => "Registered",
+            EventKind::Requested => "Requested",
+            EventKind::RequestedAlt => "RequestedAlt",
+            EventKind::Responded => "Responded",
+            EventKind::Transferred => "Transferred",
+            EventKind::Withdrawn => "Withdrawn",
+            EventKind::Custom => "Custom",
+        }
+    }
+}
+
+impl fmt::Display for EventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
 }
 
 impl Event {
+    /// Returns the kind of this event.
+    pub fn kind(&self) -> EventKind {
+        match self {
+            Event::CallbackUpdated(_) => EventKind::CallbackUpdated,
+            Event::CalledBack(_) => EventKind::CalledBack,
+            Event::Fulfilled(_) => EventKind::Fulfilled,
+            Event::Registered(_) => EventKind::Registered,
+            Event::Requested(_) => EventKind::Requested,
+            Event::RequestedAlt(_) => EventKind::RequestedAlt,
+            Event::Responded(_) => EventKind::Responded,
+            Event::Transferred(_) => EventKind::Transferred,
+            Event::Withdrawn(_) => EventKind::Withdrawn,
+            Event::Custom(_) => EventKind::Custom,
+        }
+    }
+
+    /// Returns the client PDA address this event is about.
+    ///
+    /// Returns `None` for [`CalledBack`] — it only names the client program,
+    /// and for [`Event::Custom`].
+    pub fn client(&self) -> Option<Pubkey> {
+        match self {
+            Event::CallbackUpdated(ev) => Some(ev.client),
+            Event::CalledBack(_) => None,
+            Event::Fulfilled(ev) => Some(ev.client),
+            Event::Registered(ev) => Some(ev.client),
+            Event::Requested(ev) => Some(ev.client),
+            Event::RequestedAlt(ev) => Some(ev.client),
+            Event::Responded(ev) => Some(ev.client),
+            Event::Transferred(ev) => Some(ev.client),
+            Event::Withdrawn(ev) => Some(ev.client),
+            Event::Custom(_) => None,
+        }
+    }
+
     /// Try to create an event based on the given bytes.
     ///
     /// This can deserialize an event from a representation written
@@ -41,39 +188,607 @@ impl Event {
     /// # Error
     ///
     /// *   errors with [`UnknownEvent`] wrapped in [`io::ErrorKind::InvalidData`]
-    ///     in case of unknown event
+    ///     in case of unknown event (custom events are recognized
+    ///     once registered, see [`register_custom_event`](super::register_custom_event))
+    /// *   errors with [`TruncatedEvent`] wrapped in [`io::ErrorKind::UnexpectedEof`]
+    ///     if `bytes` are empty, shorter than the discriminator, or shorter than
+    ///     the fixed-size part of a known event
+    /// *   errors with [`io::ErrorKind::InvalidData`] if `bytes` exceeds [`MAX_EVENT_SIZE`],
+    ///     if a length prefix exceeds the bytes remaining after it,
+    ///     or if a callback exceeds [`Callback::MAX_REMAINING_ACCOUNTS`]
+    ///
+    /// Length prefixes of a known event are checked before it is deserialized,
+    /// so no allocation exceeds the size of `bytes`. The payload of a custom event
+    /// is given to its parser as is (bounded by [`MAX_EVENT_SIZE`]).
     pub fn try_from_bytes(bytes: &[u8]) -> io::Result<Self> {
-        macro_rules! match_bytes {
-            ($($name:ident,)+) => {
-                $(
-                    if bytes.starts_with(crate::events::$name::DISCRIMINATOR) {
-                        return crate::events::$name::deserialize(
-                            &mut &bytes[crate::events::$name::DISCRIMINATOR.len()..]
-                        ).map(Self::$name);
-                    }
-                )+
-            };
-        }
-
-        match_bytes!(
-            CallbackUpdated,
-            CalledBack,
-            Fulfilled,
-            Registered,
-            Requested,
-            RequestedAlt,
-            Responded,
-            Transferred,
-            Withdrawn,
-        );
-
-        Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            "unknown discriminator for an event",
+        if bytes.len() > MAX_EVENT_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("event exceeds {MAX_EVENT_SIZE} bytes"),
+            ));
+        }
+
+        let unknown = || io::Error::new(io::ErrorKind::InvalidData, UnknownEvent);
+        let truncated = |err: TruncatedEvent| io::Error::new(io::ErrorKind::UnexpectedEof, err);
+        if bytes.is_empty() {
+            return Err(truncated(TruncatedEvent::Empty));
+        }
+        let (discriminator, mut payload) = split_discriminator(bytes)
+            .ok_or_else(|| truncated(TruncatedEvent::Discriminator { len: bytes.len() }))?;
+
+        // A single integer match instead of checking every discriminator in turn.
+        macro_rules! match_discriminator {
+            ($($name:ident => $const:ident,)+) => {{
+                #[allow(non_upper_case_globals)]
+                mod tags {
+                    $(pub const $name: u64 = u64::from_le_bytes(super::$const);)+
+                }
+
+                match u64::from_le_bytes(*discriminator) {
+                    $(
+                        tags::$name => {
+                            let expected = min_payload_size(EventKind::$name);
+                            if payload.len() < expected {
+                                return Err(truncated(TruncatedEvent::Payload {
+                                    kind: EventKind::$name,
+                                    expected,
+                                    actual: payload.len(),
+                                }));
+                            }
+                            check_length_prefixes(EventKind::$name, payload)?;
+                            crate::events::$name::deserialize(&mut payload).map(Self::$name)
+                        }
+                    )+
+                    _ => parse_custom_event(discriminator, payload)
+                        .ok_or_else(unknown)?
+                        .map(Self::Custom),
+                }
+            }};
+        }
+
+        match_discriminator!(
+            CallbackUpdated => CALLBACK_UPDATED_DISCRIMINATOR,
+            CalledBack => CALLED_BACK_DISCRIMINATOR,
+            Fulfilled => FULFILLED_DISCRIMINATOR,
+            Registered => REGISTERED_DISCRIMINATOR,
+            Requested => REQUESTED_DISCRIMINATOR,
+            RequestedAlt => REQUESTED_ALT_DISCRIMINATOR,
+            Responded => RESPONDED_DISCRIMINATOR,
+            Transferred => TRANSFERRED_DISCRIMINATOR,
+            Withdrawn => WITHDRAWN_DISCRIMINATOR,
+        )
+    }
+
+    /// Try to create an event based on the base64 payload of a `Program data: <base64...>`
+    /// log record (i.e. the part after the `Program data: ` prefix).
+    ///
+    /// Every whitespace-separated chunk (one per `sol_log_data` field) is decoded
+    /// and the results are concatenated, so this is the base64 counterpart of
+    /// [`Event::try_from_log_data_fields`], or of [`Event::try_from_log_data`]
+    /// for a single-chunk record (that is how Anchor emits events).
+    ///
+    /// # Error
+    ///
+    /// *   errors with [`io::ErrorKind::InvalidData`] if the payload is not a valid base64
+    /// *   see [`Event::try_from_bytes`]
+    pub fn try_from_base64(data: &str) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+        for chunk in data.split_whitespace() {
+            STANDARD
+                .decode_vec(chunk, &mut bytes)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        }
+        Self::try_from_bytes(&bytes)
+    }
+
+    /// Try to create an event based on the raw bytes logged with the `sol_log_data` syscall.
+    ///
+    /// Unlike [`Event::try_from_base64`] there is no base64 step here — this is meant
+    /// for low-level tooling (custom validators, Geyser plugins, etc.) that observes
+    /// the logged data rather than the formatted `Program data:` log record.
+    /// Anchor emits an event as a single field, so `data` is that field
+    /// (see [`Event::try_from_log_data_fields`] for the syscall arguments as is).
+    ///
+    /// # Error
+    ///
+    /// See [`Event::try_from_bytes`].
+    pub fn try_from_log_data(data: &[u8]) -> io::Result<Self> {
+        Self::try_from_bytes(data)
+    }
+
+    /// Same as [`Event::try_from_log_data`] but for all the fields given
+    /// to the `sol_log_data` syscall.
+    ///
+    /// Fields are concatenated to mirror the [`Event::try_from_base64`] behavior.
+    ///
+    /// # Error
+    ///
+    /// See [`Event::try_from_bytes`].
+    pub fn try_from_log_data_fields(fields: &[&[u8]]) -> io::Result<Self> {
+        match fields {
+            [field] => Self::try_from_log_data(field),
+            fields => Self::try_from_log_data(&fields.concat()),
+        }
+    }
+
+    /// Returns the `(client, seed)` pair identifying the request this event belongs to.
+    ///
+    /// Returns `None` for events that are not bound to a particular request.
+    pub fn request_key(&self) -> Option<(Pubkey, Seed)> {
+        match self {
+            Event::Fulfilled(ev) => Some((ev.client, Seed(ev.seed))),
+            Event::Requested(ev) => Some((ev.client, Seed(ev.seed))),
+            Event::RequestedAlt(ev) => Some((ev.client, Seed(ev.seed))),
+            Event::Responded(ev) => Some((ev.client, Seed(ev.seed))),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the callback of a request event is a request-level one,
+    /// i.e. the client-level callback is not applied to the request.
+    ///
+    /// This unifies [`Requested::callback_override`] and [`RequestedAlt::callback_override`]:
+    /// ALT requests never get the client-level callback, so it is always `true` for them.
+    /// Returns `None` for events other than requests.
+    ///
+    /// [`Requested::callback_override`]: crate::events::Requested::callback_override
+    /// [`RequestedAlt::callback_override`]: crate::events::RequestedAlt::callback_override
+    pub fn callback_override(&self) -> Option<bool> {
+        match self {
+            Event::Requested(ev) => Some(ev.callback_override),
+            Event::RequestedAlt(ev) => Some(ev.callback_override()),
+            _ => None,
+        }
+    }
+
+    /// Returns the randomness carried by this event.
+    ///
+    /// This is the final randomness for [`Fulfilled`] and an individual oracle
+    /// response for [`Responded`], `None` for other events.
+    ///
+    /// The length is guaranteed by the event layout — [`Event::try_from_bytes`]
+    /// errors on events with truncated randomness.
+    ///
+    /// [`Fulfilled`]: crate::events::Fulfilled
+    /// [`Responded`]: crate::events::Responded
+    pub fn randomness(&self) -> Option<&[u8; 64]> {
+        match self {
+            Event::Fulfilled(ev) => Some(&ev.randomness),
+            Event::Responded(ev) => Some(&ev.randomness),
+            _ => None,
+        }
+    }
+
+    /// Serializes the event the way the program emits it (discriminator followed
+    /// by the borsh-serialized event).
+    ///
+    /// This is the inverse of [`Event::try_from_bytes`].
+    ///
+    /// This is exactly what the program logs, so it carries no version: should the program
+    /// change the layout of an event, a representation of the older layout might still
+    /// deserialize (e.g. if a field is appended) and be misread. Use
+    /// [`Event::to_versioned_bytes`] to persist events (e.g. in a cache).
+    /// The layout of [`Event::Custom`] is defined by its registered parser.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Event::CallbackUpdated(ev) => ev.data(),
+            Event::CalledBack(ev) => ev.data(),
+            Event::Fulfilled(ev) => ev.data(),
+            Event::Registered(ev) => ev.data(),
+            Event::Requested(ev) => ev.data(),
+            Event::RequestedAlt(ev) => ev.data(),
+            Event::Responded(ev) => ev.data(),
+            Event::Transferred(ev) => ev.data(),
+            Event::Withdrawn(ev) => ev.data(),
+            Event::Custom(ev) => ev.to_bytes(),
+        }
+    }
+
+    /// Serializes the event to persist it — [`EVENT_FORMAT_VERSION`]
+    /// followed by [`Event::to_bytes`].
+    ///
+    /// The version is bumped whenever the layout of a known event changes,
+    /// so representations written by another version of this crate are rejected
+    /// by [`Event::try_from_versioned_bytes`] instead of being misread —
+    /// re-index the persisted events after such an upgrade.
+    /// The version does not cover the layouts of custom events.
+    pub fn to_versioned_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![EVENT_FORMAT_VERSION];
+        bytes.extend(self.to_bytes());
+        bytes
+    }
+
+    /// Deserializes an event written by [`Event::to_versioned_bytes`].
+    ///
+    /// # Error
+    ///
+    /// *   errors with [`VersionMismatch`] wrapped in [`io::ErrorKind::InvalidData`]
+    ///     if the version is not [`EVENT_FORMAT_VERSION`]
+    /// *   see [`Event::try_from_bytes`]
+    pub fn try_from_versioned_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let Some((&version, bytes)) = bytes.split_first() else {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                TruncatedEvent::Empty,
+            ));
+        };
+        if version != EVENT_FORMAT_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                VersionMismatch { found: version },
+            ));
+        }
+        Self::try_from_bytes(bytes)
+    }
+
+    /// Formats the event as a `Program data: <base64...>` log record.
+    ///
+    /// Useful to generate log fixtures for [`parse_logs`] and friends.
+    ///
+    /// [`parse_logs`]: super::parse_logs
+    pub fn to_program_data_log(&self) -> String {
+        format!("{PROGRAM_DATA_PREFIX}{}", STANDARD.encode(self.to_bytes()))
+    }
+}
+
+macro_rules! impl_from_event {
+    ($($name:ident,)+) => {
+        $(
+            impl From<$name> for Event {
+                fn from(value: $name) -> Self {
+                    Self::$name(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_event!(
+    CallbackUpdated,
+    CalledBack,
+    Fulfilled,
+    Registered,
+    Requested,
+    RequestedAlt,
+    Responded,
+    Transferred,
+    Withdrawn,
+);
+
+impl From<CustomEvent> for Event {
+    fn from(value: CustomEvent) -> Self {
+        Self::Custom(value)
+    }
+}
+
+impl Fulfilled {
+    /// Creates the event validating the lengths of the given `seed` and `randomness`.
+    ///
+    /// This is a convenience constructor for tests and fixtures (see [`Fulfilled::new`]).
+    pub fn from_slices(
+        client: Pubkey,
+        seed: &[u8],
+        randomness: &[u8],
+    ) -> Result<Self, ParseBytesError> {
+        Ok(Self::new(
+            client,
+            Seed::try_from(seed)?.0,
+            Randomness::try_from(randomness)?.0,
+        ))
+    }
+}
+
+impl Registered {
+    /// Verifies that [`Registered::client`] is the client PDA derived from
+    /// [`Registered::program`] and [`Registered::state`], and returns its canonical bump.
+    ///
+    /// *   `vrf_id` — use the [`crate::id()`] to get the proper address
+    ///     (see [`Client::find_address`])
+    ///
+    /// This is an integrity check for onboarding a client: it catches events whose
+    /// `state` does not match the expected derivation.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`ErrorCode::UnexpectedClientState`] if addresses do not match.
+    ///
+    /// [`Client::find_address`]: crate::state::client::Client::find_address
+    pub fn verify_state_pda(&self, vrf_id: &Pubkey) -> Result<u8, ErrorCode> {
+        let (client, bump) = Client::find_address(&self.program, &self.state, vrf_id);
+        if client != self.client {
+            return Err(ErrorCode::UnexpectedClientState);
+        }
+        Ok(bump)
+    }
+}
+
+impl Responded {
+    /// Creates the event validating the lengths of the given `seed` and `randomness`.
+    ///
+    /// This is a convenience constructor for tests and fixtures (see [`Responded::new`]).
+    pub fn from_slices(
+        authority: Pubkey,
+        client: Pubkey,
+        seed: &[u8],
+        randomness: &[u8],
+    ) -> Result<Self, ParseBytesError> {
+        Ok(Self::new(
+            authority,
+            client,
+            Seed::try_from(seed)?.0,
+            Randomness::try_from(randomness)?.0,
         ))
     }
 }
 
+/// Copies a discriminator into an array.
+///
+/// It fails to compile unless the discriminator is exactly 8 bytes, as the payload offset
+/// of [`Event::try_from_bytes`] relies on that (e.g. if a future Anchor version or a
+/// hand-written `Discriminator` impl changes the length).
+const fn discriminator_array(discriminator: &[u8]) -> [u8; 8] {
+    assert!(
+        discriminator.len() == 8,
+        "event discriminators must be 8 bytes long"
+    );
+    let mut array = [0_u8; 8];
+    let mut i = 0;
+    while i < array.len() {
+        array[i] = discriminator[i];
+        i += 1;
+    }
+    array
+}
+
+macro_rules! discriminator_consts {
+    ($($name:ident => $const:ident,)+) => {
+        $(
+            #[doc = concat!("Discriminator of the [`", stringify!($name), "`] event.")]
+            ///
+            /// It is the wire identifier of the event — the first 8 bytes of its
+            /// `Program data:` representation (see [`Event::try_from_bytes`]).
+            pub const $const: [u8; 8] = discriminator_array($name::DISCRIMINATOR);
+        )+
+    };
+}
+
+/// Discriminators of the built-in events.
+const BUILT_IN_DISCRIMINATORS: [[u8; 8]; 9] = [
+    CALLBACK_UPDATED_DISCRIMINATOR,
+    CALLED_BACK_DISCRIMINATOR,
+    FULFILLED_DISCRIMINATOR,
+    REGISTERED_DISCRIMINATOR,
+    REQUESTED_DISCRIMINATOR,
+    REQUESTED_ALT_DISCRIMINATOR,
+    RESPONDED_DISCRIMINATOR,
+    TRANSFERRED_DISCRIMINATOR,
+    WITHDRAWN_DISCRIMINATOR,
+];
+
+// Events are matched by the exact 8-byte discriminator, so a duplicate would misroute
+// one of the events — fail the build instead.
+const _: () = assert!(
+    all_distinct(&BUILT_IN_DISCRIMINATORS),
+    "event discriminators must be distinct"
+);
+
+const fn all_distinct(discriminators: &[[u8; 8]]) -> bool {
+    let mut i = 0;
+    while i < discriminators.len() {
+        let mut j = i + 1;
+        while j < discriminators.len() {
+            if u64::from_le_bytes(discriminators[i]) == u64::from_le_bytes(discriminators[j]) {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if the discriminator belongs to a built-in event.
+pub(crate) fn is_built_in_discriminator(discriminator: &[u8; 8]) -> bool {
+    BUILT_IN_DISCRIMINATORS.contains(discriminator)
+}
+
+discriminator_consts!(
+    CallbackUpdated => CALLBACK_UPDATED_DISCRIMINATOR,
+    CalledBack => CALLED_BACK_DISCRIMINATOR,
+    Fulfilled => FULFILLED_DISCRIMINATOR,
+    Registered => REGISTERED_DISCRIMINATOR,
+    Requested => REQUESTED_DISCRIMINATOR,
+    RequestedAlt => REQUESTED_ALT_DISCRIMINATOR,
+    Responded => RESPONDED_DISCRIMINATOR,
+    Transferred => TRANSFERRED_DISCRIMINATOR,
+    Withdrawn => WITHDRAWN_DISCRIMINATOR,
+);
+
+/// Splits the given event bytes into the 8-byte discriminator and the remaining payload.
+///
+/// This is a low-level helper for custom parsers that need to peek the discriminator
+/// without deserializing the whole [`Event`] (see also [`discriminator_for_name`]).
+///
+/// Returns `None` if `bytes` is shorter than the discriminator.
+pub fn split_discriminator(bytes: &[u8]) -> Option<(&[u8; 8], &[u8])> {
+    let (discriminator, payload) = bytes.split_first_chunk::<8>()?;
+    Some((discriminator, payload))
+}
+
+/// Size of a borsh-serialized [`ValidatedRemainingAccount`](crate::state::client::ValidatedRemainingAccount).
+const REMAINING_ACCOUNT_SIZE: usize = 32 + 1;
+
+/// Size of a borsh-serialized [`ValidatedLookupAccount`](crate::state::client::ValidatedLookupAccount).
+const LOOKUP_ACCOUNT_SIZE: usize = 1 + 1 + 1;
+
+/// Walks the variable-length fields of a known event payload and checks every
+/// length prefix against the bytes remaining after it, as well as the number
+/// of callback accounts against [`Callback::MAX_REMAINING_ACCOUNTS`].
+///
+/// Borsh pre-allocates vectors according to the length prefix (up to 1 MiB for bytes),
+/// so this runs before the event is deserialized. Truncated fields and invalid
+/// enum tags are left to the deserializer to report.
+fn check_length_prefixes(kind: EventKind, payload: &[u8]) -> io::Result<()> {
+    let mut cursor = PrefixCursor {
+        kind,
+        rest: payload,
+    };
+    match cursor.walk() {
+        Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => Ok(()),
+        result => result,
+    }
+}
+
+/// A cursor over length-prefixed borsh fields (see [`check_length_prefixes`]).
+struct PrefixCursor<'a> {
+    kind: EventKind,
+    rest: &'a [u8],
+}
+
+impl PrefixCursor<'_> {
+    fn walk(&mut self) -> io::Result<()> {
+        match self.kind {
+            EventKind::Requested => {
+                self.skip(32 + 32)?;
+                if self.option()? {
+                    let accounts = self.remaining_accounts(REMAINING_ACCOUNT_SIZE)?;
+                    self.skip(accounts * REMAINING_ACCOUNT_SIZE)?;
+                    let data = self.vec_len(1)?;
+                    self.skip(data)?;
+                }
+            }
+            EventKind::RequestedAlt => {
+                self.skip(32 + 32)?;
+                if self.option()? {
+                    // accounts hash
+                    self.skip(32)?;
+                    let accounts = self.remaining_accounts(1 + LOOKUP_ACCOUNT_SIZE)?;
+                    for _ in 0..accounts {
+                        match self.byte()? {
+                            0 => self.skip(REMAINING_ACCOUNT_SIZE)?,
+                            1 => self.skip(LOOKUP_ACCOUNT_SIZE)?,
+                            _ => return Ok(()),
+                        }
+                    }
+                    let data = self.vec_len(1)?;
+                    self.skip(data)?;
+                }
+                let lookup_tables = self.vec_len(32)?;
+                self.skip(lookup_tables * 32)?;
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    fn skip(&mut self, len: usize) -> io::Result<()> {
+        self.rest = self.rest.get(len..).ok_or(io::ErrorKind::UnexpectedEof)?;
+        Ok(())
+    }
+
+    fn byte(&mut self) -> io::Result<u8> {
+        let (byte, rest) = self
+            .rest
+            .split_first()
+            .ok_or(io::ErrorKind::UnexpectedEof)?;
+        self.rest = rest;
+        Ok(*byte)
+    }
+
+    fn option(&mut self) -> io::Result<bool> {
+        Ok(self.byte()? != 0)
+    }
+
+    /// Reads a vector length and checks that `len` elements of at least
+    /// `min_element_size` bytes fit in the remaining bytes.
+    fn vec_len(&mut self, min_element_size: usize) -> io::Result<usize> {
+        let (len, rest) = self
+            .rest
+            .split_first_chunk::<4>()
+            .ok_or(io::ErrorKind::UnexpectedEof)?;
+        let len = u32::from_le_bytes(*len) as usize;
+        if len.saturating_mul(min_element_size) > rest.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "{} event: length prefix {len} exceeds the remaining {} bytes",
+                    self.kind,
+                    rest.len()
+                ),
+            ));
+        }
+        self.rest = rest;
+        Ok(len)
+    }
+
+    /// Same as [`PrefixCursor::vec_len`] for the callback accounts.
+    fn remaining_accounts(&mut self, min_element_size: usize) -> io::Result<usize> {
+        let len = self.vec_len(min_element_size)?;
+        if len > Callback::MAX_REMAINING_ACCOUNTS {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "callback exceeds {} remaining accounts",
+                    Callback::MAX_REMAINING_ACCOUNTS
+                ),
+            ));
+        }
+        Ok(len)
+    }
+}
+
+/// Returns the size of the fixed-size part of the event payload
+/// (variable-length fields are counted as empty).
+const fn min_payload_size(kind: EventKind) -> usize {
+    match kind {
+        // owner, client, defined
+        EventKind::CallbackUpdated => 32 + 32 + 1,
+        // program
+        EventKind::CalledBack => 32,
+        // client, seed, randomness
+        EventKind::Fulfilled => 32 + 32 + 64,
+        // owner, program, state, client
+        EventKind::Registered => 32 + 32 + 32 + 32,
+        // client, seed, callback option tag, callback_override
+        EventKind::Requested => 32 + 32 + 1 + 1,
+        // client, seed, callback option tag, lookup_tables length
+        EventKind::RequestedAlt => 32 + 32 + 1 + 4,
+        // authority, client, seed, randomness
+        EventKind::Responded => 32 + 32 + 32 + 64,
+        // owner, client, new_owner
+        EventKind::Transferred => 32 + 32 + 32,
+        // owner, client, amount
+        EventKind::Withdrawn => 32 + 32 + 8,
+        EventKind::Custom => 0,
+    }
+}
+
+/// Returns the discriminator of a known event given its name (e.g. `"Fulfilled"`).
+///
+/// Returns `None` if there is no such event (see also [`split_discriminator`]).
+pub fn discriminator_for_name(name: &str) -> Option<&'static [u8]> {
+    macro_rules! match_name {
+        ($($name:ident,)+) => {
+            match name {
+                $(stringify!($name) => Some(crate::events::$name::DISCRIMINATOR),)+
+                _ => None,
+            }
+        };
+    }
+
+    match_name!(
+        CallbackUpdated,
+        CalledBack,
+        Fulfilled,
+        Registered,
+        Requested,
+        RequestedAlt,
+        Responded,
+        Transferred,
+        Withdrawn,
+    )
+}
+
 impl fmt::Display for Event {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -86,6 +801,7 @@ impl fmt::Display for Event {
             Event::Responded(ev) => ev.fmt(f),
             Event::Transferred(ev) => ev.fmt(f),
             Event::Withdrawn(ev) => ev.fmt(f),
+            Event::Custom(ev) => ev.fmt(f),
         }
     }
 }
@@ -109,12 +825,13 @@ impl fmt::Display for CalledBack {
 
 impl fmt::Display for Fulfilled {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let alt = f.alternate();
         write!(
             f,
             "Fulfilled: {} for {} with {}",
-            bs58::encode(&self.seed).into_string(),
+            Nested(Seed(self.seed), alt),
             self.client,
-            bs58::encode(&self.randomness).into_string()
+            Nested(Randomness(self.randomness), alt),
         )
     }
 }
@@ -140,10 +857,11 @@ impl fmt::Display for Requested {
         } else {
             "without"
         };
+        let alt = f.alternate();
         write!(
             f,
             "Requested: {} by {} {with} callback",
-            bs58::encode(&self.seed).into_string(),
+            Nested(Seed(self.seed), alt),
             self.client,
         )
     }
@@ -156,10 +874,11 @@ impl fmt::Display for RequestedAlt {
         } else {
             "without"
         };
+        let alt = f.alternate();
         write!(
             f,
             "Requested (ALT): {} by {} {with} callback",
-            bs58::encode(&self.seed).into_string(),
+            Nested(Seed(self.seed), alt),
             self.client,
         )
     }
@@ -167,13 +886,14 @@ impl fmt::Display for RequestedAlt {
 
 impl fmt::Display for Responded {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let alt = f.alternate();
         write!(
             f,
             "Responded: {} to {} of {} with {}",
             self.client,
-            bs58::encode(&self.seed).into_string(),
+            Nested(Seed(self.seed), alt),
             self.client,
-            bs58::encode(&self.randomness).into_string(),
+            Nested(Randomness(self.randomness), alt),
         )
     }
 }
@@ -199,3 +919,84 @@ impl fmt::Display for Withdrawn {
         )
     }
 }
+
+/// Describes the VRF activity of a transaction in one line,
+/// e.g. `"requested 3 randoms, fulfilled 1"`.
+///
+/// Events are counted by [`EventKind`] and listed in the order of the request lifecycle
+/// ([`RequestedAlt`] is counted as [`Requested`], [`Withdrawn`] amounts are summed up
+/// and given in SOL without rounding).
+/// Returns `"no VRF activity"` if there are no events.
+pub fn summarize_transaction(events: &[Event]) -> String {
+    let mut counts = std::collections::BTreeMap::<EventKind, usize>::new();
+    let mut withdrawn = 0_u64;
+    for event in events {
+        let kind = match event {
+            Event::RequestedAlt(_) => EventKind::Requested,
+            Event::Withdrawn(ev) => {
+                withdrawn = withdrawn.saturating_add(ev.amount);
+                EventKind::Withdrawn
+            }
+            event => event.kind(),
+        };
+        *counts.entry(kind).or_default() += 1;
+    }
+
+    // `(kind, verb, noun)` in the order of the request lifecycle — the count
+    // is followed by the noun (if any), and withdrawals are described by the amount.
+    const PARTS: [(EventKind, &str, Option<&str>); 9] = [
+        (EventKind::Registered, "registered", Some("client")),
+        (EventKind::CallbackUpdated, "updated", Some("callback")),
+        (EventKind::Transferred, "transferred", Some("client")),
+        (EventKind::Requested, "requested", Some("random")),
+        (EventKind::Responded, "received", Some("response")),
+        (EventKind::Fulfilled, "fulfilled", None),
+        (EventKind::CalledBack, "called back", None),
+        (EventKind::Withdrawn, "withdrew", None),
+        (EventKind::Custom, "emitted", Some("custom event")),
+    ];
+
+    let parts = PARTS
+        .into_iter()
+        .filter_map(|(kind, verb, noun)| {
+            let n = *counts.get(&kind)?;
+            if kind == EventKind::Withdrawn {
+                return Some(format!("{verb} {} SOL", sol(withdrawn)));
+            }
+            Some(match noun {
+                Some(noun) if n == 1 => format!("{verb} {n} {noun}"),
+                Some(noun) => format!("{verb} {n} {noun}s"),
+                None => format!("{verb} {n}"),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if parts.is_empty() {
+        "no VRF activity".into()
+    } else {
+        parts.join(", ")
+    }
+}
+
+/// Formats lamports as SOL exactly (integer division, trailing zeros trimmed).
+fn sol(lamports: u64) -> String {
+    let (whole, fraction) = (lamports / LAMPORTS_PER_SOL, lamports % LAMPORTS_PER_SOL);
+    if fraction == 0 {
+        return whole.to_string();
+    }
+    let fraction = format!("{fraction:09}");
+    format!("{whole}.{}", fraction.trim_end_matches('0'))
+}
+
+/// Formats the inner value keeping the alternate flag (`{:#}`) of the outer formatter.
+struct Nested<T>(T, bool);
+
+impl<T: fmt::Display> fmt::Display for Nested<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.1 {
+            write!(f, "{:#}", self.0)
+        } else {
+            self.0.fmt(f)
+        }
+    }
+}
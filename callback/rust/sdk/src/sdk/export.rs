@@ -0,0 +1,200 @@
+use std::io::{self, Write};
+
+use super::{Event, Randomness, ResolvedEvent};
+
+/// A flat representation of an event — every field of every event kind is a column,
+/// so events of all kinds share a table layout (see [`write_csv`]).
+///
+/// Fields that do not apply to the event kind are `None`. Addresses, seeds,
+/// randomness and signatures are base58 strings, lamports are integers.
+/// For [`Event::Custom`] only the location and the kind are given.
+///
+/// The column set is fixed (see [`EventRow::COLUMNS`]): columns are only appended
+/// when events get new fields, so columns could be read by name or by position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct EventRow {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    /// See [`ResolvedEvent::index`].
+    pub index: usize,
+    /// See [`EventKind::name`](super::EventKind::name).
+    pub kind: &'static str,
+    pub client: Option<String>,
+    pub seed: Option<String>,
+    pub owner: Option<String>,
+    /// The new owner of [`Transferred`](crate::events::Transferred).
+    pub new_owner: Option<String>,
+    pub program: Option<String>,
+    /// The client state of [`Registered`](crate::events::Registered).
+    pub state: Option<String>,
+    /// The fulfill authority of [`Responded`](crate::events::Responded).
+    pub authority: Option<String>,
+    /// See [`Event::randomness`].
+    pub randomness: Option<String>,
+    /// The withdrawn lamports.
+    pub amount: Option<u64>,
+    /// `true` if the request or the client defines a callback
+    /// (for requests and [`CallbackUpdated`](crate::events::CallbackUpdated)).
+    pub callback_defined: Option<bool>,
+    /// See [`Event::callback_override`].
+    pub callback_override: Option<bool>,
+    /// Space-separated lookup tables of [`RequestedAlt`](crate::events::RequestedAlt).
+    pub lookup_tables: Option<String>,
+}
+
+impl EventRow {
+    /// Names of the columns in the order of [`EventRow`] fields.
+    pub const COLUMNS: [&'static str; 17] = [
+        "signature",
+        "slot",
+        "block_time",
+        "index",
+        "kind",
+        "client",
+        "seed",
+        "owner",
+        "new_owner",
+        "program",
+        "state",
+        "authority",
+        "randomness",
+        "amount",
+        "callback_defined",
+        "callback_override",
+        "lookup_tables",
+    ];
+
+    /// Flattens the given event.
+    pub fn new(resolved: &ResolvedEvent) -> Self {
+        let event = &resolved.event;
+        let mut row = Self {
+            signature: resolved.signature.to_string(),
+            slot: resolved.slot,
+            block_time: resolved.block_time,
+            index: resolved.index,
+            kind: event.kind().name(),
+            client: event.client().map(|x| x.to_string()),
+            seed: event.request_key().map(|(_, seed)| seed.to_string()),
+            owner: None,
+            new_owner: None,
+            program: None,
+            state: None,
+            authority: None,
+            randomness: event.randomness().map(|x| Randomness(*x).to_string()),
+            amount: None,
+            callback_defined: None,
+            callback_override: event.callback_override(),
+            lookup_tables: None,
+        };
+
+        match event {
+            Event::CallbackUpdated(ev) => {
+                row.owner = Some(ev.owner.to_string());
+                row.callback_defined = Some(ev.defined);
+            }
+            Event::CalledBack(ev) => row.program = Some(ev.program.to_string()),
+            Event::Registered(ev) => {
+                row.owner = Some(ev.owner.to_string());
+                row.program = Some(ev.program.to_string());
+                row.state = Some(ev.state.to_string());
+            }
+            Event::Requested(ev) => row.callback_defined = Some(ev.callback.is_some()),
+            Event::RequestedAlt(ev) => {
+                row.callback_defined = Some(ev.callback.is_some());
+                row.lookup_tables = Some(
+                    ev.lookup_tables
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                );
+            }
+            Event::Responded(ev) => row.authority = Some(ev.authority.to_string()),
+            Event::Transferred(ev) => {
+                row.owner = Some(ev.owner.to_string());
+                row.new_owner = Some(ev.new_owner.to_string());
+            }
+            Event::Withdrawn(ev) => {
+                row.owner = Some(ev.owner.to_string());
+                row.amount = Some(ev.amount);
+            }
+            Event::Fulfilled(_) | Event::Custom(_) => (),
+        }
+
+        row
+    }
+
+    /// Returns the cells in the order of [`EventRow::COLUMNS`] (empty for `None`).
+    fn cells(&self) -> [String; 17] {
+        fn cell<T: ToString>(x: &Option<T>) -> String {
+            x.as_ref().map(T::to_string).unwrap_or_default()
+        }
+
+        [
+            self.signature.clone(),
+            self.slot.to_string(),
+            cell(&self.block_time),
+            self.index.to_string(),
+            self.kind.to_string(),
+            cell(&self.client),
+            cell(&self.seed),
+            cell(&self.owner),
+            cell(&self.new_owner),
+            cell(&self.program),
+            cell(&self.state),
+            cell(&self.authority),
+            cell(&self.randomness),
+            cell(&self.amount),
+            cell(&self.callback_defined),
+            cell(&self.callback_override),
+            cell(&self.lookup_tables),
+        ]
+    }
+}
+
+impl From<&ResolvedEvent> for EventRow {
+    fn from(value: &ResolvedEvent) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Writes events as CSV — the header row of [`EventRow::COLUMNS`] followed by
+/// one row per event (see [`EventRow`]), e.g. to analyze a scan in a spreadsheet.
+///
+/// Rows are terminated with `\r\n` (RFC 4180). The writer is not flushed.
+///
+/// # Errors
+///
+/// Errors if the writer fails.
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn write_csv<'a, W: Write>(
+    events: impl IntoIterator<Item = &'a ResolvedEvent>,
+    mut w: W,
+) -> io::Result<()> {
+    write_record(&mut w, EventRow::COLUMNS)?;
+    for resolved in events {
+        write_record(&mut w, EventRow::new(resolved).cells())?;
+    }
+    Ok(())
+}
+
+fn write_record<W: Write>(
+    w: &mut W,
+    cells: impl IntoIterator<Item = impl AsRef<str>>,
+) -> io::Result<()> {
+    for (i, cell) in cells.into_iter().enumerate() {
+        if i > 0 {
+            w.write_all(b",")?;
+        }
+        let cell = cell.as_ref();
+        if cell.contains([',', '"', '\r', '\n']) {
+            write!(w, "\"{}\"", cell.replace('"', "\"\""))?;
+        } else {
+            w.write_all(cell.as_bytes())?;
+        }
+    }
+    w.write_all(b"\r\n")
+}
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::Pubkey;
+
+use super::{check_misbehavior, Event, MisbehaviorReport};
+use crate::events::{Fulfilled, Requested, Responded};
+
+/// An error returned by [`FairnessProof`] methods.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub enum FairnessError {
+    #[error("events belong to different requests")]
+    RequestMismatch,
+    #[error("randomness is not verified: {0:?}")]
+    Misbehavior(MisbehaviorReport),
+    #[error("result does not match the randomness")]
+    ResultMismatch,
+}
+
+/// A self-contained bundle that proves a game result is derived from a verified randomness.
+///
+/// Anyone could re-verify it (see [`FairnessProof::verify`]):
+///
+/// 1.  every response is an oracle signature of `client || seed`
+/// 2.  the fulfilled randomness is the XOR of those responses
+/// 3.  the result is deterministically derived from the randomness
+///
+/// It is serializable, so it could be handed over to a frontend as is.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct FairnessProof<T> {
+    pub client: Pubkey,
+    pub seed: [u8; 32],
+    #[serde(with = "crate::sdk::misc::arrays")]
+    pub randomness: [u8; 64],
+    /// Oracle responses the randomness is derived from.
+    pub responses: Vec<Responded>,
+    /// The game result derived from the randomness.
+    pub result: T,
+}
+
+impl<T> FairnessProof<T> {
+    /// Builds the proof, applying `result_fn` to the fulfilled randomness.
+    ///
+    /// `responses` are the [`Responded`] events of the request — the [`Fulfilled`] randomness
+    /// alone is not verifiable (see [`RequestLifecycle::responses`]). Responses of other
+    /// requests are ignored.
+    ///
+    /// Note that the proof is not verified here — use [`FairnessProof::verify`].
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`FairnessError::RequestMismatch`] if `fulfilled` is not for `requested`.
+    ///
+    /// [`RequestLifecycle::responses`]: super::RequestLifecycle::responses
+    pub fn build<'a>(
+        requested: &Requested,
+        fulfilled: &Fulfilled,
+        responses: impl IntoIterator<Item = &'a Responded>,
+        result_fn: impl FnOnce(&[u8; 64]) -> T,
+    ) -> Result<Self, FairnessError> {
+        if requested.client != fulfilled.client || requested.seed != fulfilled.seed {
+            return Err(FairnessError::RequestMismatch);
+        }
+
+        Ok(Self {
+            client: fulfilled.client,
+            seed: fulfilled.seed,
+            randomness: fulfilled.randomness,
+            responses: responses
+                .into_iter()
+                .filter(|x| x.client == fulfilled.client && x.seed == fulfilled.seed)
+                .cloned()
+                .collect(),
+            result: result_fn(&fulfilled.randomness),
+        })
+    }
+
+    /// Re-runs the response verification and the result derivation.
+    ///
+    /// *   `fulfill_authorities` — published oracle public keys
+    ///     (see [`NetworkConfiguration::fulfill_authorities`])
+    /// *   `result_fn` — the same derivation used to build the proof
+    ///
+    /// # Errors
+    ///
+    /// *   [`FairnessError::RequestMismatch`] — if some response is for another request
+    /// *   [`FairnessError::Misbehavior`] — if the randomness is not verified
+    ///     (see [`check_misbehavior`])
+    /// *   [`FairnessError::ResultMismatch`] — if the result differs from the derived one
+    ///
+    /// [`NetworkConfiguration::fulfill_authorities`]: crate::state::network_state::NetworkConfiguration::fulfill_authorities
+    pub fn verify(
+        &self,
+        fulfill_authorities: &[Pubkey],
+        result_fn: impl FnOnce(&[u8; 64]) -> T,
+    ) -> Result<(), FairnessError>
+    where
+        T: PartialEq,
+    {
+        if self
+            .responses
+            .iter()
+            .any(|x| x.client != self.client || x.seed != self.seed)
+        {
+            return Err(FairnessError::RequestMismatch);
+        }
+
+        let events = self
+            .responses
+            .iter()
+            .cloned()
+            .map(Event::Responded)
+            .chain([Event::Fulfilled(Fulfilled::new(
+                self.client,
+                self.seed,
+                self.randomness,
+            ))])
+            .collect::<Vec<_>>();
+        let report = check_misbehavior(&self.client, &self.seed, &events, fulfill_authorities);
+        if !report.is_clean() {
+            return Err(FairnessError::Misbehavior(report));
+        }
+
+        if result_fn(&self.randomness) != self.result {
+            return Err(FairnessError::ResultMismatch);
+        }
+
+        Ok(())
+    }
+}
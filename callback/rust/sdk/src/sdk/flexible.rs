@@ -0,0 +1,142 @@
+use std::io;
+
+use anchor_lang::prelude::Pubkey;
+
+use super::{
+    split_discriminator, EventKind, TruncatedEvent, UnknownEvent, FULFILLED_DISCRIMINATOR,
+    MAX_EVENT_SIZE, RESPONDED_DISCRIMINATOR,
+};
+
+/// A [`Fulfilled`] or [`Responded`] event parsed without asserting
+/// the randomness length (see [`FlexibleRandomnessEvent::try_from_bytes`]).
+///
+/// [`Fulfilled`]: crate::events::Fulfilled
+/// [`Responded`]: crate::events::Responded
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct FlexibleRandomnessEvent {
+    /// Either [`EventKind::Fulfilled`] or [`EventKind::Responded`].
+    pub kind: EventKind,
+    /// The fulfill authority of a [`Responded`] event.
+    ///
+    /// [`Responded`]: crate::events::Responded
+    pub authority: Option<Pubkey>,
+    pub client: Pubkey,
+    pub seed: [u8; 32],
+    pub randomness: Vec<u8>,
+}
+
+/// The layout of the randomness field (see [`FlexibleRandomnessEvent::try_from_bytes`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub enum RandomnessLayout {
+    /// The fixed 64-byte array of the current program.
+    Fixed,
+    /// A length-prefixed `Vec<u8>` of any length.
+    LengthPrefixed,
+}
+
+impl FlexibleRandomnessEvent {
+    /// Parses a [`Fulfilled`] or [`Responded`] event given its `Program data:` bytes
+    /// (see [`Event::try_from_bytes`]) and the randomness layout.
+    ///
+    /// The randomness is the field most likely to change in a program upgrade,
+    /// so the layout is chosen by the caller — [`RandomnessLayout::Fixed`] for the current
+    /// program, or [`RandomnessLayout::LengthPrefixed`] to accept the randomness of any
+    /// length. The layouts can't be told apart reliably from the bytes (e.g. a `Vec` of
+    /// 60 bytes is as long as the fixed array), so nothing is guessed, and the randomness
+    /// must end the event — trailing bytes are rejected.
+    ///
+    /// The trade-off is the fixed-length guarantee of [`Event::try_from_bytes`]:
+    /// a 64-byte randomness is an ed25519 signature that the verification helpers
+    /// accept, while this one is not checked — use [`Self::randomness_array`]
+    /// to get it back.
+    ///
+    /// # Errors
+    ///
+    /// *   errors with [`UnknownEvent`] wrapped in [`io::ErrorKind::InvalidData`]
+    ///     if this is not a [`Fulfilled`] or [`Responded`] event
+    /// *   errors with [`TruncatedEvent`] wrapped in [`io::ErrorKind::UnexpectedEof`]
+    ///     if the event is truncated
+    /// *   errors with [`io::ErrorKind::InvalidData`] if bytes follow the randomness
+    ///
+    /// [`Fulfilled`]: crate::events::Fulfilled
+    /// [`Responded`]: crate::events::Responded
+    /// [`Event::try_from_bytes`]: super::Event::try_from_bytes
+    pub fn try_from_bytes(bytes: &[u8], layout: RandomnessLayout) -> io::Result<Self> {
+        if bytes.len() > MAX_EVENT_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("event exceeds {MAX_EVENT_SIZE} bytes"),
+            ));
+        }
+        if bytes.is_empty() {
+            return Err(truncated(TruncatedEvent::Empty));
+        }
+        let (discriminator, payload) = split_discriminator(bytes)
+            .ok_or_else(|| truncated(TruncatedEvent::Discriminator { len: bytes.len() }))?;
+
+        let (kind, fixed) = match *discriminator {
+            FULFILLED_DISCRIMINATOR => (EventKind::Fulfilled, 32 + 32),
+            RESPONDED_DISCRIMINATOR => (EventKind::Responded, 32 + 32 + 32),
+            _ => return Err(io::Error::new(io::ErrorKind::InvalidData, UnknownEvent)),
+        };
+        let payload_truncated = |expected| {
+            truncated(TruncatedEvent::Payload {
+                kind,
+                expected,
+                actual: payload.len(),
+            })
+        };
+
+        let (prefix, randomness_len) = match layout {
+            RandomnessLayout::Fixed => (0, 64),
+            RandomnessLayout::LengthPrefixed => {
+                let len = payload
+                    .get(fixed..fixed + 4)
+                    .ok_or_else(|| payload_truncated(fixed + 4))?;
+                (4, u32::from_le_bytes(len.try_into().unwrap()) as usize)
+            }
+        };
+        let expected = fixed + prefix + randomness_len;
+        if payload.len() < expected {
+            return Err(payload_truncated(expected));
+        }
+        if payload.len() > expected {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "{kind} event: {} trailing bytes after the randomness",
+                    payload.len() - expected
+                ),
+            ));
+        }
+
+        let keys = &payload[..fixed];
+        let randomness = payload[fixed + prefix..].to_vec();
+        let key = |at: usize| Pubkey::new_from_array(keys[at..at + 32].try_into().unwrap());
+        let (authority, keys_at) = match kind {
+            EventKind::Responded => (Some(key(0)), 32),
+            _ => (None, 0),
+        };
+        Ok(Self {
+            kind,
+            authority,
+            client: key(keys_at),
+            seed: keys[keys_at + 32..keys_at + 64].try_into().unwrap(),
+            randomness,
+        })
+    }
+
+    /// Returns the randomness as the fixed-size array of the current layout,
+    /// or `None` if its length differs from 64 bytes.
+    pub fn randomness_array(&self) -> Option<[u8; 64]> {
+        self.randomness.as_slice().try_into().ok()
+    }
+}
+
+fn truncated(err: TruncatedEvent) -> io::Error {
+    io::Error::new(io::ErrorKind::UnexpectedEof, err)
+}
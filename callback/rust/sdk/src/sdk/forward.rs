@@ -0,0 +1,52 @@
+use std::{future::Future, pin::pin};
+
+use futures::{
+    future::{self, Either},
+    Sink, SinkExt, Stream, StreamExt,
+};
+
+/// Forwards every item of the stream into the sink (e.g. a channel or a websocket
+/// connection) — a building block of relays that consume events from one source
+/// and push them to another:
+///
+/// ```
+/// # use futures::{channel::mpsc, Stream};
+/// # use orao_solana_vrf_cb::sdk::{forward_events, ResolvedEvent};
+/// # async fn f(events: impl Stream<Item = ResolvedEvent>, tx: mpsc::Sender<ResolvedEvent>) {
+/// let shutdown = async { /* e.g. a Ctrl-C signal */ };
+/// let forwarded = forward_events(events, tx, shutdown).await;
+/// # }
+/// ```
+///
+/// *   every item is flushed as soon as it is sent, so a relay adds no latency
+/// *   once the stream ends or the `shutdown` future resolves, the sink is closed
+///     (which flushes it) and the number of forwarded items is returned — an item
+///     in flight is delivered before the shutdown
+/// *   the first sink error stops the forwarding and is returned as is
+///     (the sink is not closed in this case)
+///
+/// Items are forwarded as is, so map the stream beforehand to convert events
+/// into the sink messages (or to handle parse errors, e.g. with [`parse_logs_partitioned`]).
+///
+/// [`parse_logs_partitioned`]: super::parse_logs_partitioned
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub async fn forward_events<S, K, F>(events: S, sink: K, shutdown: F) -> Result<usize, K::Error>
+where
+    S: Stream,
+    K: Sink<S::Item>,
+    F: Future<Output = ()>,
+{
+    let mut events = pin!(events);
+    let mut sink = pin!(sink);
+    let mut shutdown = pin!(shutdown);
+    let mut forwarded = 0;
+
+    while let Either::Left((Some(item), _)) = future::select(events.next(), shutdown.as_mut()).await
+    {
+        sink.send(item).await?;
+        forwarded += 1;
+    }
+
+    sink.close().await?;
+    Ok(forwarded)
+}
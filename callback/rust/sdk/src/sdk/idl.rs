@@ -0,0 +1,265 @@
+use std::{collections::HashMap, io};
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang_idl_spec::{Idl, IdlArrayLen, IdlDefinedFields, IdlType, IdlTypeDef, IdlTypeDefTy};
+use serde_json::{Map, Value};
+
+use super::{split_discriminator, TruncatedEvent, UnknownEvent, MAX_EVENT_SIZE};
+
+/// The maximum nesting depth of the decoded types (bounds the recursion).
+const MAX_DEPTH: usize = 32;
+
+/// Decodes events dynamically given the program IDL.
+///
+/// This is meant for tooling that works across program versions (e.g. forks
+/// with modified events) — no recompilation is needed to decode an event defined
+/// in the IDL. Every event is decoded into a generic JSON value:
+///
+/// *   structs are objects, tuple structs and tuples are arrays
+/// *   unit enum variants are strings, other variants are `{"<variant>": <fields>}`
+/// *   `pubkey` is a base58 string, `u128`/`i128` are decimal strings
+/// *   `bytes`, vectors and arrays are arrays (byte arrays included)
+/// *   `option` is either `null` or the value
+///
+/// Generic types and 256-bit integers are not supported.
+///
+/// Use [`Event::try_from_bytes`] for events of the program this crate is built for.
+///
+/// [`Event::try_from_bytes`]: super::Event::try_from_bytes
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "idl")))]
+pub struct IdlEventDecoder {
+    events: HashMap<[u8; 8], String>,
+    types: HashMap<String, IdlTypeDef>,
+}
+
+/// An event decoded by the [`IdlEventDecoder`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "idl")))]
+#[non_exhaustive]
+pub struct DecodedEvent {
+    /// The event name as given in the IDL.
+    pub name: String,
+    /// The event fields (see [`IdlEventDecoder`]).
+    pub value: Value,
+}
+
+impl IdlEventDecoder {
+    /// Parses the IDL JSON (the Anchor `0.30` format or newer).
+    pub fn from_json(idl: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(idl).map(Self::new)
+    }
+
+    /// Creates a decoder for the events of the given IDL.
+    ///
+    /// Events with a malformed discriminator are ignored.
+    pub fn new(idl: Idl) -> Self {
+        Self {
+            events: idl
+                .events
+                .into_iter()
+                .filter_map(|x| Some((x.discriminator.try_into().ok()?, x.name)))
+                .collect(),
+            types: idl.types.into_iter().map(|x| (x.name.clone(), x)).collect(),
+        }
+    }
+
+    /// Returns the names of the known events in arbitrary order.
+    pub fn event_names(&self) -> impl Iterator<Item = &str> {
+        self.events.values().map(String::as_str)
+    }
+
+    /// Decodes an event from bytes written in the `Program data:` log record
+    /// (see [`Event::try_from_bytes`]).
+    ///
+    /// # Errors
+    ///
+    /// *   errors with [`UnknownEvent`] wrapped in [`io::ErrorKind::InvalidData`]
+    ///     if the discriminator does not belong to an IDL event
+    /// *   errors with [`TruncatedEvent`] wrapped in [`io::ErrorKind::UnexpectedEof`]
+    ///     if `bytes` are shorter than the discriminator
+    /// *   errors with [`io::ErrorKind::InvalidData`] if `bytes` exceeds [`MAX_EVENT_SIZE`],
+    ///     do not match the event schema, or if the schema is unsupported
+    ///
+    /// [`Event::try_from_bytes`]: super::Event::try_from_bytes
+    pub fn decode(&self, bytes: &[u8]) -> io::Result<DecodedEvent> {
+        if bytes.len() > MAX_EVENT_SIZE {
+            return Err(invalid(format!("event exceeds {MAX_EVENT_SIZE} bytes")));
+        }
+        let (discriminator, mut payload) = split_discriminator(bytes).ok_or_else(|| {
+            let err = match bytes.len() {
+                0 => TruncatedEvent::Empty,
+                len => TruncatedEvent::Discriminator { len },
+            };
+            io::Error::new(io::ErrorKind::UnexpectedEof, err)
+        })?;
+        let name = self
+            .events
+            .get(discriminator)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, UnknownEvent))?;
+
+        let value = self.decode_defined(name, &mut payload, 0)?;
+        if !payload.is_empty() {
+            return Err(invalid(format!(
+                "{} bytes left after the {name} event",
+                payload.len()
+            )));
+        }
+
+        Ok(DecodedEvent {
+            name: name.clone(),
+            value,
+        })
+    }
+
+    fn decode_defined(&self, name: &str, data: &mut &[u8], depth: usize) -> io::Result<Value> {
+        let def = self
+            .types
+            .get(name)
+            .ok_or_else(|| invalid(format!("type {name} is not defined")))?;
+        if !def.generics.is_empty() {
+            return Err(invalid(format!("generic type {name} is not supported")));
+        }
+
+        match &def.ty {
+            IdlTypeDefTy::Struct { fields } => self.decode_fields(fields.as_ref(), data, depth),
+            IdlTypeDefTy::Enum { variants } => {
+                let index = take::<1>(data)?[0] as usize;
+                let variant = variants
+                    .get(index)
+                    .ok_or_else(|| invalid(format!("invalid {name} variant {index}")))?;
+                match &variant.fields {
+                    None => Ok(Value::String(variant.name.clone())),
+                    fields => {
+                        let value = self.decode_fields(fields.as_ref(), data, depth)?;
+                        Ok(Value::Object(Map::from_iter([(
+                            variant.name.clone(),
+                            value,
+                        )])))
+                    }
+                }
+            }
+            IdlTypeDefTy::Type { alias } => self.decode_type(alias, data, depth + 1),
+        }
+    }
+
+    fn decode_fields(
+        &self,
+        fields: Option<&IdlDefinedFields>,
+        data: &mut &[u8],
+        depth: usize,
+    ) -> io::Result<Value> {
+        match fields {
+            None => Ok(Value::Null),
+            Some(IdlDefinedFields::Named(fields)) => fields
+                .iter()
+                .map(|x| Ok((x.name.clone(), self.decode_type(&x.ty, data, depth + 1)?)))
+                .collect::<io::Result<Map<_, _>>>()
+                .map(Value::Object),
+            Some(IdlDefinedFields::Tuple(types)) => types
+                .iter()
+                .map(|x| self.decode_type(x, data, depth + 1))
+                .collect::<io::Result<Vec<_>>>()
+                .map(Value::Array),
+        }
+    }
+
+    fn decode_type(&self, ty: &IdlType, data: &mut &[u8], depth: usize) -> io::Result<Value> {
+        if depth > MAX_DEPTH {
+            return Err(invalid(format!("types nested deeper than {MAX_DEPTH}")));
+        }
+
+        Ok(match ty {
+            IdlType::Bool => match take::<1>(data)?[0] {
+                0 => Value::Bool(false),
+                1 => Value::Bool(true),
+                x => return Err(invalid(format!("invalid bool {x}"))),
+            },
+            IdlType::U8 => u8::from_le_bytes(take(data)?).into(),
+            IdlType::I8 => i8::from_le_bytes(take(data)?).into(),
+            IdlType::U16 => u16::from_le_bytes(take(data)?).into(),
+            IdlType::I16 => i16::from_le_bytes(take(data)?).into(),
+            IdlType::U32 => u32::from_le_bytes(take(data)?).into(),
+            IdlType::I32 => i32::from_le_bytes(take(data)?).into(),
+            IdlType::F32 => f32::from_le_bytes(take(data)?).into(),
+            IdlType::U64 => u64::from_le_bytes(take(data)?).into(),
+            IdlType::I64 => i64::from_le_bytes(take(data)?).into(),
+            IdlType::F64 => f64::from_le_bytes(take(data)?).into(),
+            IdlType::U128 => u128::from_le_bytes(take(data)?).to_string().into(),
+            IdlType::I128 => i128::from_le_bytes(take(data)?).to_string().into(),
+            IdlType::Pubkey => Pubkey::new_from_array(take(data)?).to_string().into(),
+            IdlType::String => {
+                let len = take_len(data)?;
+                let (bytes, rest) = data.split_at(len);
+                *data = rest;
+                String::from_utf8(bytes.to_vec())
+                    .map_err(|_| invalid("invalid UTF-8 string"))?
+                    .into()
+            }
+            IdlType::Bytes => {
+                let len = take_len(data)?;
+                let (bytes, rest) = data.split_at(len);
+                *data = rest;
+                bytes.to_vec().into()
+            }
+            IdlType::Option(ty) => match take::<1>(data)?[0] {
+                0 => Value::Null,
+                1 => self.decode_type(ty, data, depth + 1)?,
+                x => return Err(invalid(format!("invalid option tag {x}"))),
+            },
+            IdlType::Vec(ty) => {
+                let len = take_len(data)?;
+                self.decode_array(ty, len, data, depth)?
+            }
+            IdlType::Array(ty, IdlArrayLen::Value(len)) => {
+                if *len > data.len() {
+                    return Err(eof());
+                }
+                self.decode_array(ty, *len, data, depth)?
+            }
+            IdlType::Defined { name, generics } if generics.is_empty() => {
+                self.decode_defined(name, data, depth + 1)?
+            }
+            ty => return Err(invalid(format!("unsupported type {ty:?}"))),
+        })
+    }
+
+    fn decode_array(
+        &self,
+        ty: &IdlType,
+        len: usize,
+        data: &mut &[u8],
+        depth: usize,
+    ) -> io::Result<Value> {
+        (0..len)
+            .map(|_| self.decode_type(ty, data, depth + 1))
+            .collect::<io::Result<Vec<_>>>()
+            .map(Value::Array)
+    }
+}
+
+fn take<const N: usize>(data: &mut &[u8]) -> io::Result<[u8; N]> {
+    let (bytes, rest) = data.split_first_chunk::<N>().ok_or_else(eof)?;
+    *data = rest;
+    Ok(*bytes)
+}
+
+/// Reads the `u32` length prefix.
+///
+/// The length is bounded by the remaining bytes (every element takes at least one byte
+/// in practice), so that a malformed length never causes a huge allocation or loop.
+fn take_len(data: &mut &[u8]) -> io::Result<usize> {
+    let len = u32::from_le_bytes(take(data)?) as usize;
+    if len > data.len() {
+        return Err(eof());
+    }
+    Ok(len)
+}
+
+fn invalid(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+fn eof() -> io::Error {
+    io::Error::new(io::ErrorKind::UnexpectedEof, "unexpected end of the event")
+}
@@ -1,9 +1,11 @@
 mod register;
+mod request;
 mod set_callback;
 mod transfer;
 mod withdraw;
 
 pub use register::*;
+pub use request::*;
 pub use set_callback::*;
 pub use transfer::*;
 pub use withdraw::*;
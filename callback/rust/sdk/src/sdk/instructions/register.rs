@@ -2,17 +2,21 @@ use std::ops::Deref;
 use std::sync::Arc;
 
 use anchor_client::solana_sdk::instruction::Instruction;
+use anchor_client::solana_sdk::signature::Signature;
 use anchor_client::solana_sdk::signer::Signer;
 use anchor_client::solana_sdk::system_program;
 use anchor_client::{ClientError, ThreadSafeSigner};
 use anchor_lang::prelude::{Pubkey, UpgradeableLoaderState};
 use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
 
+use crate::error::ErrorCode;
+use crate::events::Registered;
 use crate::state::client::{Callback, Client};
 use crate::state::network_state::NetworkState;
 use crate::RegisterParams;
 
-use crate::sdk::ComputeBudgetConfig;
+use crate::sdk::transaction::transaction_config;
+use crate::sdk::{events_in_transaction, ComputeBudgetConfig, Event};
 
 /// [`Register`] instruction builder.
 ///
@@ -142,7 +146,7 @@ impl RegisterBuilder {
         program: Pubkey,
         state: Pubkey,
     ) -> Result<
-        anchor_client::RequestBuilder<C, Arc<dyn ThreadSafeSigner>>,
+        anchor_client::RequestBuilder<'_, C, Arc<dyn ThreadSafeSigner>>,
         anchor_client::ClientError,
     > {
         let mut builder = orao_vrf.request();
@@ -183,3 +187,68 @@ impl RegisterBuilder {
         Ok(builder)
     }
 }
+
+/// Registers a client program and returns the emitted [`Registered`] event.
+///
+/// This is the onboarding shortcut over the [`RegisterBuilder`] — it validates
+/// the registration locally, sends and confirms the transaction, and fetches
+/// the resulting event. Default compute budget config is used.
+///
+/// * `program` — a program being registered (see [`Register::program`])
+/// * `state_seeds` — seeds of the client state PDA (see [`RegisterParams::state_seeds`])
+/// * `callback` — an optional client-level callback (see [`RegisterParams::callback`])
+///
+/// # Errors
+///
+/// *   [`ErrorCode::UnexpectedClientState`] — state seeds does not derive a valid PDA
+///     of the `program` (this is checked before the submission)
+/// *   [`ErrorCode::TooManyAccounts`] — callback exceeds [`Callback::MAX_REMAINING_ACCOUNTS`]
+///     (this is checked before the submission)
+/// *   [`ClientError::LogParseError`] — confirmed transaction has no [`Registered`] event
+/// *   any error of [`RegisterBuilder::build`] or the transaction submission
+///
+/// [`Register::program`]: crate::Register::program
+/// [`Registered`]: crate::events::Registered
+/// [`ErrorCode::UnexpectedClientState`]: crate::error::ErrorCode::UnexpectedClientState
+/// [`ErrorCode::TooManyAccounts`]: crate::error::ErrorCode::TooManyAccounts
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub async fn register_client<C: Deref<Target = impl Signer> + Clone>(
+    orao_vrf: &anchor_client::Program<C>,
+    program: Pubkey,
+    state_seeds: Vec<Vec<u8>>,
+    callback: Option<Callback>,
+) -> Result<(Signature, Registered), ClientError> {
+    let seeds = state_seeds.iter().map(|x| x.as_slice()).collect::<Vec<_>>();
+    let state = Pubkey::create_program_address(&seeds, &program)
+        .map_err(|_| anchor_lang::error::Error::from(ErrorCode::UnexpectedClientState))?;
+    if callback
+        .as_ref()
+        .is_some_and(|x| x.remaining_accounts.len() > Callback::MAX_REMAINING_ACCOUNTS)
+    {
+        return Err(anchor_lang::error::Error::from(ErrorCode::TooManyAccounts).into());
+    }
+
+    let signature = RegisterBuilder::new(state_seeds)
+        .with_callback(callback)
+        .build(orao_vrf, program, state)
+        .await?
+        .send()
+        .await?;
+
+    let tx = orao_vrf
+        .rpc()
+        .get_transaction_with_config(&signature, transaction_config(orao_vrf.rpc().commitment()))
+        .await?;
+
+    let registered = events_in_transaction(&tx)?
+        .into_iter()
+        .find_map(|x| match x.event {
+            Event::Registered(registered) => Some(registered),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            ClientError::LogParseError(format!("no Registered event in transaction {signature}"))
+        })?;
+
+    Ok((signature, registered))
+}
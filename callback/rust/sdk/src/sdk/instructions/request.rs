@@ -0,0 +1,168 @@
+use std::ops::Deref;
+
+use anchor_client::solana_sdk::{instruction::Instruction, signer::Signer};
+
+use crate::{state::client::Callback, RequestParams};
+
+use crate::sdk::{ComputeBudgetConfig, Seed};
+
+/// An error returned by [`RequestParamsBuilder::build`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub enum RequestParamsError {
+    /// Callback override is requested, but no request-level callback is given
+    /// (the client-level callback would be used).
+    #[error("callback override requires a request-level callback")]
+    OverrideWithoutCallback,
+}
+
+/// [`RequestParams`] builder.
+///
+/// Randomness requests are made by the client program via CPI (see [`Request`]),
+/// so this builder prepares everything the client transaction needs — the instruction
+/// parameters to pass to the client program along with the compute budget instructions.
+/// The client transaction is sent by the caller, so it is sent and confirmed with
+/// whatever commitment the caller uses.
+///
+/// Every setting is optional:
+///
+/// *   seed — random by default
+/// *   callback — no request-level callback by default, i.e. the client-level
+///     callback is used (see [`RequestParams::callback`])
+/// *   compute budget — same as for the other builders (e.g. [`WithdrawBuilder`])
+///
+/// [`Request`]: crate::Request
+/// [`WithdrawBuilder`]: crate::sdk::WithdrawBuilder
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct RequestParamsBuilder {
+    seed: Option<[u8; 32]>,
+    callback: Option<Callback>,
+    callback_override: bool,
+    compute_budget_config: ComputeBudgetConfig,
+}
+
+impl RequestParams {
+    /// Creates a new [`RequestParamsBuilder`].
+    #[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+    pub fn builder() -> RequestParamsBuilder {
+        RequestParamsBuilder::default()
+    }
+}
+
+impl RequestParamsBuilder {
+    /// Defines the request seed (random by default).
+    pub fn with_seed(mut self, seed: [u8; 32]) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Defines the request-level callback (see [`RequestParams::callback`]).
+    pub fn with_callback(mut self, callback: Callback) -> Self {
+        self.callback = Some(callback);
+        self
+    }
+
+    /// Requires the request-level callback to override the client-level one.
+    ///
+    /// The program has no separate override flag — a request-level callback always
+    /// overrides the client-level one (see [`PreparedRequest::callback_override`]).
+    /// So this is a safety net: [`RequestParamsBuilder::build`] fails
+    /// if no request-level callback is given, rather than silently falling back
+    /// to the client-level callback.
+    pub fn with_callback_override(mut self) -> Self {
+        self.callback_override = true;
+        self
+    }
+
+    /// Defines a prioritization fee in micro-lamports (applied per compute unit).
+    ///
+    /// Adds `ComputeBudgetInstruction::SetComputeUnitPrice` to the request builder.
+    ///
+    /// *   if not specified, then median fee of the last 150 confirmed
+    ///     slots is used (this is by default)
+    /// *   if zero, then compute unit price is not applied at all.
+    pub fn with_compute_unit_price(mut self, compute_unit_price: u64) -> Self {
+        self.compute_budget_config.compute_unit_price = Some(compute_unit_price);
+        self
+    }
+
+    /// Defines a multiplier that is applied to a median compute unit price.
+    ///
+    /// This is only applied if no compute_unit_price specified, i.e. if compute unit price
+    /// is measured as a median fee of the last 150 confirmed slots.
+    ///
+    /// *   if not specified, then no multiplier is applied (this is by default)
+    /// *   if specified, then applied as follows: `compute_unit_price = median * multiplier`
+    pub fn with_compute_unit_price_multiplier(mut self, multiplier: f64) -> Self {
+        self.compute_budget_config.compute_unit_price_multiplier = Some(multiplier);
+        self
+    }
+
+    /// Defines a specific compute unit limit that the transaction is allowed to consume.
+    ///
+    /// Adds `ComputeBudgetInstruction::SetComputeUnitLimit` to the request builder.
+    ///
+    /// *   if not specified, then compute unit limit is not applied at all
+    ///     (this is by default)
+    /// *   if specified, then applied as is
+    pub fn with_compute_unit_limit(mut self, compute_unit_limit: u32) -> Self {
+        self.compute_budget_config.compute_unit_limit = Some(compute_unit_limit);
+        self
+    }
+
+    /// Validates the settings and builds the request.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`RequestParamsError::OverrideWithoutCallback`] if callback override
+    /// is requested without a request-level callback.
+    pub fn build(self) -> Result<PreparedRequest, RequestParamsError> {
+        if self.callback_override && self.callback.is_none() {
+            return Err(RequestParamsError::OverrideWithoutCallback);
+        }
+
+        let seed = self.seed.unwrap_or_else(rand::random);
+        Ok(PreparedRequest {
+            params: RequestParams::new(seed).with_callback(self.callback),
+            compute_budget_config: self.compute_budget_config,
+        })
+    }
+}
+
+/// A request built with [`RequestParamsBuilder`].
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct PreparedRequest {
+    /// Parameters to pass to the client program.
+    pub params: RequestParams,
+    compute_budget_config: ComputeBudgetConfig,
+}
+
+impl PreparedRequest {
+    /// Returns the request seed.
+    pub fn seed(&self) -> Seed {
+        Seed(self.params.seed)
+    }
+
+    /// Returns `true` if the request-level callback overrides the client-level one,
+    /// i.e. the expected [`Requested::callback_override`] of the request.
+    ///
+    /// It is always `true` if the request is built with
+    /// [`RequestParamsBuilder::with_callback_override`].
+    ///
+    /// [`Requested::callback_override`]: crate::events::Requested::callback_override
+    pub fn callback_override(&self) -> bool {
+        self.params.callback.is_some()
+    }
+
+    /// Returns the Compute Budget Program instructions to prepend to the client transaction.
+    pub async fn compute_budget_instructions<C: Deref<Target = impl Signer> + Clone>(
+        &self,
+        orao_vrf: &anchor_client::Program<C>,
+    ) -> Result<Vec<Instruction>, anchor_client::ClientError> {
+        self.compute_budget_config.get_instructions(orao_vrf).await
+    }
+}
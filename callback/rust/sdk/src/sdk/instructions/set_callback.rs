@@ -114,7 +114,7 @@ impl SetCallbackBuilder {
         orao_vrf: &anchor_client::Program<C>,
         client: Pubkey,
     ) -> Result<
-        anchor_client::RequestBuilder<C, Arc<dyn ThreadSafeSigner>>,
+        anchor_client::RequestBuilder<'_, C, Arc<dyn ThreadSafeSigner>>,
         anchor_client::ClientError,
     > {
         let mut builder = orao_vrf.request();
@@ -0,0 +1,155 @@
+use anchor_client::solana_sdk::bs58;
+use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+use super::{Event, TaggedEvent};
+
+/// A wrapper that (de)serializes [`Event`] in the JSON shape used by JS clients,
+/// so that Rust and JS services could exchange events losslessly:
+///
+/// ```json
+/// {"name": "Fulfilled", "data": {"client": "<base58>", "seed": "<base58>", "randomness": "<base58>"}}
+/// ```
+///
+/// *   the `name`/`data` pair and the field names are the ones of the Anchor TS event coder —
+///     field names are camelCase (e.g. `callbackOverride`)
+/// *   public keys and byte arrays (seeds, randomness, callback data) are base58-encoded,
+///     i.e. the `PublicKey` JSON form and what the JS SDK examples use for randomness
+///     (`bs58.encode(randomness)`)
+/// *   integers are JSON numbers (convert `BN` amounts with `toNumber()`)
+///
+/// Qualified event names (e.g. `orao_vrf_cb::events::Fulfilled`) are accepted
+/// while deserializing. Custom events (see [`Event::Custom`]) are not supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "js")))]
+pub struct JsEvent(pub Event);
+
+impl From<Event> for JsEvent {
+    fn from(value: Event) -> Self {
+        Self(value)
+    }
+}
+
+impl From<JsEvent> for Event {
+    fn from(value: JsEvent) -> Self {
+        value.0
+    }
+}
+
+impl Serialize for JsEvent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if matches!(self.0, Event::Custom(_)) {
+            return Err(S::Error::custom("custom events have no JS representation"));
+        }
+        let Value::Object(mut data) =
+            serde_json::to_value(TaggedEvent(self.0.clone())).map_err(S::Error::custom)?
+        else {
+            unreachable!("events are serialized as objects");
+        };
+        data.remove("type");
+
+        let mut repr = Map::new();
+        repr.insert("name".into(), self.0.kind().name().into());
+        repr.insert("data".into(), to_js(Value::Object(data)));
+        repr.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for JsEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Repr {
+            name: String,
+            data: Map<String, Value>,
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+        let name = repr.name.rsplit("::").next().unwrap_or_default();
+        let Value::Object(mut data) =
+            from_js(Value::Object(repr.data)).map_err(D::Error::custom)?
+        else {
+            unreachable!("objects are converted to objects");
+        };
+        data.insert("type".into(), name.into());
+
+        serde_json::from_value::<TaggedEvent>(Value::Object(data))
+            .map(|x| Self(x.0))
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Converts the serde representation of an event into the JS one.
+fn to_js(value: Value) -> Value {
+    match value {
+        Value::Array(items) if !items.is_empty() && items.iter().all(is_byte) => {
+            let bytes: Vec<u8> = items
+                .iter()
+                .filter_map(|x| x.as_u64().map(|x| x as u8))
+                .collect();
+            Value::String(bs58::encode(bytes).into_string())
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(to_js).collect()),
+        Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (camel_case(&key), to_js(value)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+/// Converts the JS representation of an event into the serde one.
+///
+/// Events have no text fields, so every string is a base58-encoded byte array.
+fn from_js(value: Value) -> Result<Value, bs58::decode::Error> {
+    Ok(match value {
+        Value::String(s) => Value::Array(
+            bs58::decode(s)
+                .into_vec()?
+                .into_iter()
+                .map(Value::from)
+                .collect(),
+        ),
+        Value::Array(items) => {
+            Value::Array(items.into_iter().map(from_js).collect::<Result<_, _>>()?)
+        }
+        Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| Ok((snake_case(&key), from_js(value)?)))
+                .collect::<Result<_, _>>()?,
+        ),
+        other => other,
+    })
+}
+
+fn is_byte(value: &Value) -> bool {
+    value.as_u64().is_some_and(|x| x <= u8::MAX as u64)
+}
+
+fn camel_case(key: &str) -> String {
+    let mut parts = key.split('_');
+    let mut result = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            result.extend(first.to_uppercase());
+            result.push_str(chars.as_str());
+        }
+    }
+    result
+}
+
+fn snake_case(key: &str) -> String {
+    let mut result = String::with_capacity(key.len() + 4);
+    for c in key.chars() {
+        if c.is_ascii_uppercase() {
+            result.push('_');
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
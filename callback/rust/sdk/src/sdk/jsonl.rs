@@ -0,0 +1,28 @@
+use std::io::{self, Write};
+
+use super::{Event, TaggedEvent};
+
+/// Writes events as JSON lines — one internally tagged JSON object per line
+/// (see [`TaggedEvent`]), the common ingestion format of log pipelines.
+///
+/// Every line is written with [`Write::write_all`], so partial writes are retried
+/// and the writer is flushed once all the events are written. Wrap unbuffered writers
+/// (e.g. a [`File`]) into a [`BufWriter`] for performance.
+///
+/// # Errors
+///
+/// Errors on the first failed write (the preceding lines are written).
+///
+/// [`File`]: std::fs::File
+/// [`BufWriter`]: std::io::BufWriter
+#[cfg_attr(docsrs, doc(cfg(feature = "jsonl")))]
+pub fn write_jsonl<W: Write>(events: impl IntoIterator<Item = Event>, w: &mut W) -> io::Result<()> {
+    let mut line = Vec::new();
+    for event in events {
+        line.clear();
+        serde_json::to_writer(&mut line, &TaggedEvent(event))?;
+        line.push(b'\n');
+        w.write_all(&line)?;
+    }
+    w.flush()
+}
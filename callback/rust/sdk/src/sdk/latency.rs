@@ -0,0 +1,99 @@
+use super::RequestLifecycle;
+
+/// A fulfillment latency (see [`LatencyStats`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct Latency {
+    pub slots: u64,
+    /// `None` if no sample has the block time available
+    /// (see [`RequestLifecycle::latency_seconds`]).
+    pub seconds: Option<u64>,
+}
+
+/// Fulfillment latency percentiles over a window of completed requests.
+///
+/// Use it to show users an ETA while waiting for the randomness:
+///
+/// ```ignore
+/// let stats = LatencyStats::from_lifecycles(tracker.lifecycles());
+/// if let Some(eta) = stats.p90() {
+///     println!("fulfilled within {} slots in 90% of cases", eta.slots);
+/// }
+/// ```
+///
+/// Percentiles are exact (nearest-rank over sorted samples), so memory is linear
+/// in the window size — bound the window by the number of requests or slots.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct LatencyStats {
+    /// Sorted slot latencies.
+    slots: Vec<u64>,
+    /// Sorted latencies in seconds (might have fewer samples than `slots`).
+    seconds: Vec<u64>,
+}
+
+impl LatencyStats {
+    /// Collects latencies of the fulfilled lifecycles (others are ignored).
+    pub fn from_lifecycles<'a>(lifecycles: impl IntoIterator<Item = &'a RequestLifecycle>) -> Self {
+        let mut stats = Self::default();
+        for lifecycle in lifecycles {
+            let Some(slots) = lifecycle.latency_slots() else {
+                continue;
+            };
+            stats.slots.push(slots);
+            stats.seconds.extend(lifecycle.latency_seconds());
+        }
+        stats.slots.sort_unstable();
+        stats.seconds.sort_unstable();
+        stats
+    }
+
+    /// Returns the number of samples.
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns `true` if there are no samples.
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Returns the given percentile (`0.0..=100.0`, clamped) of latencies.
+    ///
+    /// Returns `None` if there are no samples.
+    pub fn percentile(&self, percentile: f64) -> Option<Latency> {
+        Some(Latency {
+            slots: nearest_rank(&self.slots, percentile)?,
+            seconds: nearest_rank(&self.seconds, percentile),
+        })
+    }
+
+    /// Returns the median latency.
+    pub fn p50(&self) -> Option<Latency> {
+        self.percentile(50.0)
+    }
+
+    /// Returns the 90th percentile of latencies.
+    pub fn p90(&self) -> Option<Latency> {
+        self.percentile(90.0)
+    }
+
+    /// Returns the 99th percentile of latencies.
+    pub fn p99(&self) -> Option<Latency> {
+        self.percentile(99.0)
+    }
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn nearest_rank(sorted: &[u64], percentile: f64) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let percentile = if percentile.is_nan() {
+        0.0
+    } else {
+        percentile.clamp(0.0, 100.0)
+    };
+    let rank = (percentile / 100.0 * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.clamp(1, sorted.len()) - 1])
+}
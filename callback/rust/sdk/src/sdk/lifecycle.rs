@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+
+use super::{Event, Randomness, ResolvedEvent, Seed};
+
+/// All the observed events of a single randomness request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct RequestLifecycle {
+    pub client: Pubkey,
+    pub seed: Seed,
+    /// The [`Requested`] or [`RequestedAlt`] event.
+    ///
+    /// [`Requested`]: crate::events::Requested
+    /// [`RequestedAlt`]: crate::events::RequestedAlt
+    pub requested: Option<ResolvedEvent>,
+    /// [`Responded`] events in the order of arrival.
+    ///
+    /// [`Responded`]: crate::events::Responded
+    pub responses: Vec<ResolvedEvent>,
+    /// The [`Fulfilled`] event.
+    ///
+    /// [`Fulfilled`]: crate::events::Fulfilled
+    pub fulfilled: Option<ResolvedEvent>,
+    /// The [`CalledBack`] event emitted by the fulfill transaction.
+    ///
+    /// [`CalledBack`]: crate::events::CalledBack
+    pub called_back: Option<ResolvedEvent>,
+}
+
+impl RequestLifecycle {
+    pub fn new(client: Pubkey, seed: Seed) -> Self {
+        Self {
+            client,
+            seed,
+            requested: None,
+            responses: Vec::new(),
+            fulfilled: None,
+            called_back: None,
+        }
+    }
+
+    /// Returns `true` if the request is observed but not yet fulfilled.
+    pub fn is_pending(&self) -> bool {
+        self.requested.is_some() && self.fulfilled.is_none()
+    }
+
+    /// Returns `true` if the request is fulfilled.
+    pub fn is_fulfilled(&self) -> bool {
+        self.fulfilled.is_some()
+    }
+
+    /// Returns the slot of the request transaction, if observed.
+    pub fn requested_slot(&self) -> Option<u64> {
+        self.requested.as_ref().map(|x| x.slot)
+    }
+
+    /// Returns the slot of the fulfill transaction, if observed.
+    pub fn fulfilled_slot(&self) -> Option<u64> {
+        self.fulfilled.as_ref().map(|x| x.slot)
+    }
+
+    /// Returns the number of slots between the request and the fulfillment.
+    pub fn latency_slots(&self) -> Option<u64> {
+        Some(
+            self.fulfilled_slot()?
+                .saturating_sub(self.requested_slot()?),
+        )
+    }
+
+    /// Returns the number of seconds between the request and the fulfillment.
+    ///
+    /// Requires [`ResolvedEvent::block_time`] of both events.
+    pub fn latency_seconds(&self) -> Option<u64> {
+        let requested = self.requested.as_ref()?.block_time?;
+        let fulfilled = self.fulfilled.as_ref()?.block_time?;
+        Some(fulfilled.saturating_sub(requested).max(0) as u64)
+    }
+
+    /// Returns the fulfilled randomness.
+    pub fn randomness(&self) -> Option<Randomness> {
+        match self.fulfilled.as_ref().map(|x| &x.event) {
+            Some(Event::Fulfilled(ev)) => Some(Randomness(ev.randomness)),
+            _ => None,
+        }
+    }
+
+    /// Returns the outcome of the callback of a fulfilled request.
+    ///
+    /// Returns `None` if either the request or the fulfillment is not observed.
+    /// Note that the lifecycle is expected to be built from successful transactions
+    /// (see [`CallbackOutcome`]).
+    pub fn callback_outcome(&self) -> Option<CallbackOutcome> {
+        self.fulfilled.as_ref()?;
+        let defined = match &self.requested.as_ref()?.event {
+            Event::Requested(ev) => ev.callback.is_some(),
+            Event::RequestedAlt(ev) => ev.callback.is_some(),
+            _ => return None,
+        };
+        Some(match (defined, self.called_back.is_some()) {
+            (_, true) => CallbackOutcome::Invoked,
+            (true, false) => CallbackOutcome::Skipped,
+            (false, false) => CallbackOutcome::NotDefined,
+        })
+    }
+
+    /// Returns all the events of this request in the order of arrival.
+    pub fn events(&self) -> impl Iterator<Item = &ResolvedEvent> {
+        self.requested
+            .iter()
+            .chain(&self.responses)
+            .chain(&self.fulfilled)
+            .chain(&self.called_back)
+    }
+
+    fn contains(&self, resolved: &ResolvedEvent) -> bool {
+        self.events()
+            .any(|x| x.signature == resolved.signature && x.log_index == resolved.log_index)
+    }
+
+    /// Returns `false` if the event is already recorded or is not a request event.
+    fn push(&mut self, resolved: ResolvedEvent) -> bool {
+        if self.contains(&resolved) {
+            return false;
+        }
+        match resolved.event {
+            Event::Requested(_) | Event::RequestedAlt(_) => self.requested = Some(resolved),
+            Event::Responded(_) => self.responses.push(resolved),
+            Event::Fulfilled(_) => self.fulfilled = Some(resolved),
+            Event::CalledBack(_) => self.called_back = Some(resolved),
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// The callback outcome of a fulfilled request (see [`RequestLifecycle::callback_outcome`]).
+///
+/// What could be inferred from the events:
+///
+/// *   a callback is a CPI call made by the fulfill instruction, and a failed CPI call
+///     fails the whole transaction — so there is no such thing as a failed callback
+///     within a successful transaction. A [`CalledBack`] event of a successful transaction
+///     means that the callback succeeded.
+/// *   a failed callback fails the fulfill transaction, so the request stays pending
+///     and the fulfillment is retried. Such attempts are only visible as failed transactions
+///     (note that [`events_in_transaction`] extracts events of failed transactions as well,
+///     so check the transaction status before feeding a [`LifecycleTracker`]).
+/// *   once the callback deadline is reached (see [`NetworkConfiguration::callback_deadline`])
+///     the request is fulfilled without calling the callback — this is the
+///     [`CallbackOutcome::Skipped`] case, i.e. the client program never received
+///     the randomness via the callback (it is still available in the request account).
+///
+/// What could not be inferred: the reason of a failure — it is only available in logs
+/// of the failed transactions.
+///
+/// [`CalledBack`]: crate::events::CalledBack
+/// [`events_in_transaction`]: super::events_in_transaction
+/// [`NetworkConfiguration::callback_deadline`]: crate::state::network_state::NetworkConfiguration::callback_deadline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub enum CallbackOutcome {
+    /// The request has no callback.
+    NotDefined,
+    /// The callback is invoked successfully.
+    Invoked,
+    /// The request is fulfilled without invoking its callback.
+    Skipped,
+}
+
+/// Groups events into per-request [`RequestLifecycle`]s.
+///
+/// Events of a transaction are expected to be pushed in the emission order
+/// (as returned by [`events_in_transaction`]), because a [`CalledBack`] event
+/// is not bound to a request and is attributed to the preceding [`Fulfilled`]
+/// event of the same transaction. Transactions themselves may come in any order,
+/// and pushing the same event twice is a no-op.
+///
+/// [`events_in_transaction`]: super::events_in_transaction
+/// [`CalledBack`]: crate::events::CalledBack
+/// [`Fulfilled`]: crate::events::Fulfilled
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct LifecycleTracker {
+    lifecycles: HashMap<(Pubkey, Seed), RequestLifecycle>,
+    /// The last fulfilled request along with the fulfill transaction signature.
+    last_fulfilled: Option<(Signature, (Pubkey, Seed))>,
+}
+
+impl LifecycleTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the given event.
+    ///
+    /// Returns the updated lifecycle, or `None` if the event is ignored
+    /// (it is a duplicate or is not related to a request).
+    pub fn push(&mut self, resolved: ResolvedEvent) -> Option<&RequestLifecycle> {
+        let key = match resolved.event.request_key() {
+            Some(key) => key,
+            None if matches!(resolved.event, Event::CalledBack(_)) => match self.last_fulfilled {
+                Some((signature, key)) if signature == resolved.signature => key,
+                _ => return None,
+            },
+            None => return None,
+        };
+
+        if matches!(resolved.event, Event::Fulfilled(_)) {
+            self.last_fulfilled = Some((resolved.signature, key));
+        }
+
+        let lifecycle = self
+            .lifecycles
+            .entry(key)
+            .or_insert_with(|| RequestLifecycle::new(key.0, key.1));
+        lifecycle.push(resolved).then_some(&*lifecycle)
+    }
+
+    /// Returns the lifecycle of the given request.
+    pub fn get(&self, client: &Pubkey, seed: &Seed) -> Option<&RequestLifecycle> {
+        self.lifecycles.get(&(*client, *seed))
+    }
+
+    /// Stops tracking the given request and returns its lifecycle.
+    pub fn remove(&mut self, client: &Pubkey, seed: &Seed) -> Option<RequestLifecycle> {
+        self.lifecycles.remove(&(*client, *seed))
+    }
+
+    /// Returns all the tracked lifecycles in arbitrary order.
+    pub fn lifecycles(&self) -> impl Iterator<Item = &RequestLifecycle> {
+        self.lifecycles.values()
+    }
+
+    /// Returns the number of tracked requests.
+    pub fn len(&self) -> usize {
+        self.lifecycles.len()
+    }
+
+    /// Returns `true` if no requests are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.lifecycles.is_empty()
+    }
+}
+
+impl Extend<ResolvedEvent> for LifecycleTracker {
+    fn extend<T: IntoIterator<Item = ResolvedEvent>>(&mut self, iter: T) {
+        for resolved in iter {
+            self.push(resolved);
+        }
+    }
+}
+
+impl FromIterator<ResolvedEvent> for LifecycleTracker {
+    fn from_iter<T: IntoIterator<Item = ResolvedEvent>>(iter: T) -> Self {
+        let mut tracker = Self::new();
+        tracker.extend(iter);
+        tracker
+    }
+}
+
+/// Returns pending requests that are not fulfilled within `max_slots` since the request.
+///
+/// Use it to detect stuck requests and trigger retries or refunds.
+///
+/// `current_slot` should be obtained with the same commitment the events are
+/// observed with (e.g. `rpc.get_slot_with_commitment(CommitmentConfig::confirmed())`
+/// for events of confirmed transactions): a `processed` slot runs ahead of
+/// the confirmed events and makes requests look older than they are.
+/// Avoid caching it — a stale slot makes expired requests look fresh.
+///
+/// Requests without an observed [`Requested`] event are not considered.
+///
+/// [`Requested`]: crate::events::Requested
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn detect_expired_requests<'a>(
+    lifecycles: impl IntoIterator<Item = &'a RequestLifecycle>,
+    current_slot: u64,
+    max_slots: u64,
+) -> Vec<(Pubkey, Seed)> {
+    lifecycles
+        .into_iter()
+        .filter(|x| x.is_pending())
+        .filter(|x| {
+            x.requested_slot()
+                .is_some_and(|slot| current_slot.saturating_sub(slot) > max_slots)
+        })
+        .map(|x| (x.client, x.seed))
+        .collect()
+}
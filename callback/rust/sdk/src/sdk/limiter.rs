@@ -0,0 +1,195 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use anchor_lang::prelude::Pubkey;
+use tokio::sync::Notify;
+
+use super::{Event, ResolvedEvent, Seed};
+
+/// Caps the number of in-flight (i.e. not yet fulfilled) requests of a client.
+///
+/// The flow is as follows:
+///
+/// 1.  [`InFlightLimiter::acquire`] waits for a free slot and reserves it for the given seed
+/// 2.  the request is submitted
+/// 3.  the observed [`Requested`] event for the reserved seed turns the reservation
+///     into an in-flight request (see [`InFlightLimiter::push`])
+/// 4.  the observed [`Fulfilled`] event frees the slot
+///
+/// Slots are never leaked:
+///
+/// *   a reservation that is not followed by a [`Requested`] event within the reservation
+///     timeout (e.g. the submission failed) is dropped automatically
+///     (see [`InFlightLimiter::with_reservation_timeout`])
+/// *   requests that are never fulfilled are dropped with [`InFlightLimiter::expire`]
+///
+/// Only events of the given client are considered. Requests of the client that were
+/// not reserved by this limiter (e.g. submitted by another process sharing the client)
+/// are ignored, so they neither consume a reservation nor occupy a slot.
+/// Requires a Tokio runtime.
+///
+/// [`Requested`]: crate::events::Requested
+/// [`Fulfilled`]: crate::events::Fulfilled
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct InFlightLimiter {
+    client: Pubkey,
+    max_in_flight: usize,
+    reservation_timeout: Duration,
+    state: Mutex<InFlightState>,
+    released: Notify,
+}
+
+#[derive(Debug, Default)]
+struct InFlightState {
+    /// In-flight requests along with the request slot.
+    in_flight: HashMap<Seed, u64>,
+    /// Reserved seeds along with the reservation time in the order of acquisition.
+    reservations: VecDeque<(Seed, Instant)>,
+}
+
+impl InFlightLimiter {
+    /// The default reservation timeout (see [`InFlightLimiter::with_reservation_timeout`]).
+    pub const DEFAULT_RESERVATION_TIMEOUT: Duration = Duration::from_secs(60);
+
+    /// Creates a limiter for the given client PDA (`max_in_flight` is at least `1`).
+    pub fn new(client: Pubkey, max_in_flight: usize) -> Self {
+        Self {
+            client,
+            max_in_flight: max_in_flight.max(1),
+            reservation_timeout: Self::DEFAULT_RESERVATION_TIMEOUT,
+            state: Default::default(),
+            released: Notify::new(),
+        }
+    }
+
+    /// Defines how long a reservation made by [`InFlightLimiter::acquire`] waits
+    /// for the corresponding [`Requested`] event (defaults to
+    /// [`InFlightLimiter::DEFAULT_RESERVATION_TIMEOUT`]).
+    ///
+    /// It should exceed the submission time along with the event delivery lag.
+    ///
+    /// [`Requested`]: crate::events::Requested
+    pub fn with_reservation_timeout(mut self, reservation_timeout: Duration) -> Self {
+        self.reservation_timeout = reservation_timeout;
+        self
+    }
+
+    /// Waits for a free slot and reserves it for the request with the given seed.
+    ///
+    /// Acquiring a seed that is already reserved or in flight returns immediately.
+    pub async fn acquire(&self, seed: [u8; 32]) {
+        loop {
+            let released = self.released.notified();
+            let wait = {
+                let mut state = self.lock();
+                let now = Instant::now();
+                while state
+                    .reservations
+                    .front()
+                    .is_some_and(|(_, x)| now.duration_since(*x) >= self.reservation_timeout)
+                {
+                    state.reservations.pop_front();
+                }
+
+                if state.in_flight.contains_key(&Seed(seed))
+                    || state.reservations.iter().any(|(x, _)| x.0 == seed)
+                {
+                    return;
+                }
+
+                if state.in_flight.len() + state.reservations.len() < self.max_in_flight {
+                    state.reservations.push_back((Seed(seed), now));
+                    return;
+                }
+
+                // the oldest reservation expires first
+                state
+                    .reservations
+                    .front()
+                    .map(|(_, x)| (*x + self.reservation_timeout).saturating_duration_since(now))
+            };
+
+            match wait {
+                Some(wait) => {
+                    let _ = tokio::time::timeout(wait, released).await;
+                }
+                None => released.await,
+            }
+        }
+    }
+
+    /// Records the given event.
+    ///
+    /// Returns `true` if the number of in-flight requests is changed
+    /// (it is never changed by a request that was not reserved).
+    pub fn push(&self, resolved: &ResolvedEvent) -> bool {
+        match &resolved.event {
+            Event::Requested(ev) if ev.client == self.client => {
+                self.requested(ev.seed, resolved.slot)
+            }
+            Event::RequestedAlt(ev) if ev.client == self.client => {
+                self.requested(ev.seed, resolved.slot)
+            }
+            Event::Fulfilled(ev) if ev.client == self.client => {
+                let removed = self.lock().in_flight.remove(&Seed(ev.seed)).is_some();
+                if removed {
+                    self.released.notify_waiters();
+                }
+                removed
+            }
+            _ => false,
+        }
+    }
+
+    /// Drops requests that are not fulfilled within `max_slots` since the request
+    /// (see [`detect_expired_requests`]) and returns their seeds.
+    ///
+    /// [`detect_expired_requests`]: super::detect_expired_requests
+    pub fn expire(&self, current_slot: u64, max_slots: u64) -> Vec<Seed> {
+        let mut expired = vec![];
+        self.lock().in_flight.retain(|seed, slot| {
+            let keep = current_slot.saturating_sub(*slot) <= max_slots;
+            if !keep {
+                expired.push(*seed);
+            }
+            keep
+        });
+        if !expired.is_empty() {
+            self.released.notify_waiters();
+        }
+        expired
+    }
+
+    /// Returns the number of in-flight requests (reservations are not counted).
+    pub fn in_flight(&self) -> usize {
+        self.lock().in_flight.len()
+    }
+
+    /// Returns the number of reservations waiting for the [`Requested`] event
+    /// (expired reservations may be counted).
+    ///
+    /// [`Requested`]: crate::events::Requested
+    pub fn reserved(&self) -> usize {
+        self.lock().reservations.len()
+    }
+
+    fn requested(&self, seed: [u8; 32], slot: u64) -> bool {
+        let mut state = self.lock();
+        let Some(position) = state.reservations.iter().position(|(x, _)| x.0 == seed) else {
+            return false;
+        };
+        state.reservations.remove(position);
+        state.in_flight.insert(Seed(seed), slot);
+        true
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, InFlightState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
@@ -0,0 +1,229 @@
+use std::io;
+
+use anchor_lang::prelude::Pubkey;
+
+use super::Event;
+
+/// Prefix of a log record written by the `sol_log_data` syscall.
+pub const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Parses events emitted by the given program from the transaction logs.
+///
+/// Invocation frames are tracked (`Program <id> invoke [n]` … `Program <id> success`),
+/// so only the `Program data:` records written by `program_id` itself are considered —
+/// other programs of the same transaction may log arbitrary data.
+///
+/// Events are yielded in the log emission order.
+pub fn parse_logs<I>(program_id: &Pubkey, logs: I) -> impl Iterator<Item = io::Result<Event>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    parse_logs_indexed(program_id, logs).map(|(_, event)| event)
+}
+
+/// Same as [`parse_logs`] but for raw log records that might not be valid UTF-8
+/// (e.g. logs read from non-RPC sources).
+///
+/// Records are matched on bytes, and only the payload of a `Program data:` record
+/// of the tracked program is required to be UTF-8 — it is yielded as an error
+/// of [`io::ErrorKind::InvalidData`] kind otherwise. Other records with invalid
+/// encoding are arbitrary program logs, so they are skipped.
+pub fn parse_logs_bytes<I>(program_id: &Pubkey, logs: I) -> impl Iterator<Item = io::Result<Event>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    let mut frames = InvocationFrames::new(program_id);
+    logs.into_iter().filter_map(move |line| {
+        frames.push_line_bytes(line.as_ref()).map(|data| {
+            let data = std::str::from_utf8(data).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("program data is not valid UTF-8: {err}"),
+                )
+            })?;
+            Event::try_from_base64(data)
+        })
+    })
+}
+
+/// A parse error returned by [`parse_logs_partitioned`].
+#[derive(Debug, thiserror::Error)]
+#[error("log record {index}: {error}")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct ParseError {
+    /// The index of the log record that failed to parse.
+    pub index: usize,
+    /// The underlying error (see [`Event::try_from_bytes`]).
+    #[source]
+    pub error: io::Error,
+}
+
+/// Same as [`parse_logs`] but collects the events and the errors separately in one pass,
+/// so that parse failures could be logged while the good events are processed.
+///
+/// Records with unknown discriminators are not errors in this mode — they are just skipped
+/// (e.g. events of a newer program version, see [`UnknownEvent`]).
+///
+/// [`UnknownEvent`]: super::UnknownEvent
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn parse_logs_partitioned<I>(program_id: &Pubkey, logs: I) -> (Vec<Event>, Vec<ParseError>)
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut events = Vec::new();
+    let mut errors = Vec::new();
+    for (index, event) in parse_logs_indexed(program_id, logs) {
+        match event {
+            Ok(event) => events.push(event),
+            Err(err) if is_unknown_event(&err) => (),
+            Err(error) => errors.push(ParseError { index, error }),
+        }
+    }
+    (events, errors)
+}
+
+fn is_unknown_event(err: &io::Error) -> bool {
+    err.get_ref().is_some_and(|x| x.is::<super::UnknownEvent>())
+}
+
+/// Same as [`parse_logs`] but also yields the index of the corresponding log record.
+pub(crate) fn parse_logs_indexed<I>(
+    program_id: &Pubkey,
+    logs: I,
+) -> impl Iterator<Item = (usize, io::Result<Event>)>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut frames = InvocationFrames::new(program_id);
+    logs.into_iter()
+        .enumerate()
+        .filter_map(move |(index, line)| {
+            frames
+                .push_line(line.as_ref())
+                .map(|data| (index, Event::try_from_base64(data)))
+        })
+}
+
+/// A stateful parser of log records arriving one by one,
+/// e.g. when tailing the output of `solana logs`.
+///
+/// The invocation frames (see [`parse_logs`]) are tracked across calls and
+/// the surrounding whitespace of a record is ignored (`solana logs` indents them).
+/// Use [`LineParser::push_chunk`] if the input is not split into lines,
+/// so a record split across reads is parsed once it is complete.
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct LineParser {
+    frames: InvocationFrames,
+    partial: String,
+}
+
+impl LineParser {
+    /// Creates a parser of the events emitted by the given program.
+    pub fn new(program_id: &Pubkey) -> Self {
+        Self {
+            frames: InvocationFrames::new(program_id),
+            partial: String::new(),
+        }
+    }
+
+    /// Visits the next complete log record.
+    ///
+    /// Returns the event if the record is the `Program data:` record of the tracked program.
+    pub fn push_line(&mut self, line: &str) -> Option<io::Result<Event>> {
+        self.frames
+            .push_line(line.trim())
+            .map(Event::try_from_base64)
+    }
+
+    /// Visits the next chunk of the log stream.
+    ///
+    /// Records are terminated by `\n`, so the trailing incomplete record
+    /// is kept until the rest of it arrives (see [`LineParser::finish`]).
+    pub fn push_chunk(&mut self, chunk: &str) -> Vec<io::Result<Event>> {
+        self.partial.push_str(chunk);
+        let Some(end) = self.partial.rfind('\n') else {
+            return Vec::new();
+        };
+
+        let rest = self.partial.split_off(end + 1);
+        let complete = std::mem::replace(&mut self.partial, rest);
+        complete
+            .lines()
+            .filter_map(|line| self.push_line(line))
+            .collect()
+    }
+
+    /// Parses the incomplete record kept by [`LineParser::push_chunk`] (if any),
+    /// e.g. once the stream ends without a trailing newline.
+    pub fn finish(&mut self) -> Option<io::Result<Event>> {
+        let partial = std::mem::take(&mut self.partial);
+        self.push_line(&partial)
+    }
+}
+
+/// Tracks the program invocation stack while visiting log records.
+#[derive(Debug, Clone)]
+pub(crate) struct InvocationFrames {
+    program_id: String,
+    /// `true` for frames of the tracked program.
+    stack: Vec<bool>,
+}
+
+impl InvocationFrames {
+    pub(crate) fn new(program_id: &Pubkey) -> Self {
+        Self {
+            program_id: program_id.to_string(),
+            stack: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the tracked program is executing at the moment.
+    pub(crate) fn in_program(&self) -> bool {
+        self.stack.last().copied().unwrap_or_default()
+    }
+
+    /// Visits the next log record.
+    ///
+    /// Returns the `Program data:` payload if it is written by the tracked program.
+    pub(crate) fn push_line<'a>(&mut self, line: &'a str) -> Option<&'a str> {
+        if let Some(data) = line.strip_prefix(PROGRAM_DATA_PREFIX) {
+            return self.in_program().then_some(data);
+        }
+
+        let (id, status) = line.strip_prefix("Program ")?.split_once(' ')?;
+
+        if let Some(depth) = status.strip_prefix("invoke [") {
+            // Truncated logs might miss some `success` records, so the depth resyncs the stack
+            // (this also separates transactions in a continuous stream of log records).
+            if let Some(depth) = depth
+                .strip_suffix(']')
+                .and_then(|x| x.parse::<usize>().ok())
+            {
+                self.stack.truncate(depth.saturating_sub(1));
+            }
+            self.stack.push(id == self.program_id);
+        } else if status == "success" || status.starts_with("failed") {
+            self.stack.pop();
+        }
+
+        None
+    }
+
+    /// Same as [`InvocationFrames::push_line`] but for a raw log record.
+    pub(crate) fn push_line_bytes<'a>(&mut self, line: &'a [u8]) -> Option<&'a [u8]> {
+        if let Some(data) = line.strip_prefix(PROGRAM_DATA_PREFIX.as_bytes()) {
+            return self.in_program().then_some(data);
+        }
+        // Invocation records are ASCII, so a record with invalid encoding is a program log.
+        if let Ok(line) = std::str::from_utf8(line) {
+            self.push_line(line);
+        }
+        None
+    }
+}
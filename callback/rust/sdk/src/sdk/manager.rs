@@ -0,0 +1,312 @@
+use std::{
+    collections::{HashMap, HashSet},
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, Weak,
+    },
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use anchor_client::solana_client::{
+    nonblocking::pubsub_client::PubsubClient,
+    rpc_response::{Response as RpcResponse, RpcLogsResponse},
+};
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_lang::prelude::Pubkey;
+use futures::{Stream, StreamExt};
+use tokio::{sync::mpsc, task::JoinHandle};
+
+use super::{
+    subscription::{logs_subscribe_args, program_events_in_logs_notification},
+    Event, EventKind, Network, ResolvedEvent,
+};
+
+/// Selects events delivered to a [`Subscription`].
+///
+/// An empty filter matches every event, otherwise an event must match
+/// one of the clients (if any given) and one of the kinds (if any given).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct EventFilter {
+    clients: HashSet<Pubkey>,
+    kinds: HashSet<EventKind>,
+}
+
+impl EventFilter {
+    /// Creates a filter that matches every event.
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given client PDA address to the filter.
+    pub fn with_client(mut self, client: Pubkey) -> Self {
+        self.clients.insert(client);
+        self
+    }
+
+    /// Adds the given event kind to the filter.
+    pub fn with_kind(mut self, kind: EventKind) -> Self {
+        self.kinds.insert(kind);
+        self
+    }
+
+    /// Returns `true` if an event of the given kind and client matches the filter.
+    pub fn matches(&self, client: Option<&Pubkey>, kind: EventKind) -> bool {
+        let client_matches =
+            self.clients.is_empty() || client.is_some_and(|x| self.clients.contains(x));
+        let kind_matches = self.kinds.is_empty() || self.kinds.contains(&kind);
+        client_matches && kind_matches
+    }
+}
+
+struct Subscriber {
+    filter: EventFilter,
+    sender: mpsc::Sender<ResolvedEvent>,
+    lagged: Arc<AtomicU64>,
+}
+
+struct Shared {
+    program_id: Pubkey,
+    subscribers: Mutex<HashMap<u64, Subscriber>>,
+    next_id: AtomicU64,
+    parse_errors: AtomicU64,
+}
+
+impl Shared {
+    fn subscribers(&self) -> std::sync::MutexGuard<'_, HashMap<u64, Subscriber>> {
+        self.subscribers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn route(&self, notification: &RpcResponse<RpcLogsResponse>) {
+        let Ok(events) = program_events_in_logs_notification(&self.program_id, notification) else {
+            self.parse_errors.fetch_add(1, Ordering::Relaxed);
+            return;
+        };
+
+        let mut subscribers = self.subscribers();
+        // `CalledBack` names no client, so it is routed as the preceding `Fulfilled`.
+        let mut fulfilled_client = None;
+        for resolved in events {
+            let client = match &resolved.event {
+                Event::CalledBack(_) => fulfilled_client,
+                event => event.client(),
+            };
+            if let Event::Fulfilled(ev) = &resolved.event {
+                fulfilled_client = Some(ev.client);
+            }
+
+            let kind = resolved.event.kind();
+            subscribers.retain(|_, subscriber| {
+                if !subscriber.filter.matches(client.as_ref(), kind) {
+                    return true;
+                }
+                match subscriber.sender.try_send(resolved.clone()) {
+                    Ok(()) => true,
+                    Err(mpsc::error::TrySendError::Full(_)) => {
+                        subscriber.lagged.fetch_add(1, Ordering::Relaxed);
+                        true
+                    }
+                    Err(mpsc::error::TrySendError::Closed(_)) => false,
+                }
+            });
+        }
+    }
+}
+
+/// Multiplexes any number of event subscriptions over a single websocket connection.
+///
+/// The manager holds one `logsSubscribe` subscription for the VRF program
+/// (of a custom deployment with [`SubscriptionManager::with_network`])
+/// and routes the decoded events (see [`events_in_logs_notification`]) to the
+/// matching [`Subscription`]s, so subscriptions are added, updated and removed
+/// without any websocket round-trips.
+///
+/// The connection is re-established (with an exponential backoff) whenever it is lost,
+/// and all the active subscriptions keep receiving events. Note that events emitted
+/// while disconnected are missed — use [`signature_pages`] to backfill if necessary.
+/// Notifications that fail to parse are skipped and counted (see [`SubscriptionManager::parse_errors`]).
+///
+/// Every subscription buffers up to [`SubscriptionManager::SUBSCRIPTION_BUFFER`] events
+/// (see [`SubscriptionManager::subscribe_with_buffer`]). The connection is shared,
+/// so a slow subscription never blocks the others — events that do not fit into
+/// its buffer are dropped and counted instead (see [`Subscription::lagged`]).
+///
+/// Dropping the manager closes the connection and ends all the subscriptions.
+///
+/// Requires a Tokio runtime.
+///
+/// [`signature_pages`]: super::signature_pages
+/// [`events_in_logs_notification`]: super::events_in_logs_notification
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct SubscriptionManager {
+    shared: Arc<Shared>,
+    task: JoinHandle<()>,
+}
+
+impl SubscriptionManager {
+    /// Initial delay before reconnecting.
+    pub const MIN_RECONNECT_DELAY: Duration = Duration::from_millis(500);
+    /// Maximum delay before reconnecting.
+    pub const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+    /// The default number of events buffered per subscription.
+    pub const SUBSCRIPTION_BUFFER: usize = 1024;
+
+    /// Spawns the connection task for the given websocket endpoint.
+    pub fn new(ws_url: impl Into<String>, commitment: CommitmentConfig) -> Self {
+        Self::spawn(ws_url.into(), commitment, crate::id())
+    }
+
+    /// Same as [`SubscriptionManager::new`] but subscribes to the program
+    /// of the given network (see [`Network::program_id`]).
+    pub fn with_network(
+        ws_url: impl Into<String>,
+        commitment: CommitmentConfig,
+        network: &Network,
+    ) -> Self {
+        Self::spawn(ws_url.into(), commitment, network.program_id())
+    }
+
+    fn spawn(ws_url: String, commitment: CommitmentConfig, program_id: Pubkey) -> Self {
+        let shared = Arc::new(Shared {
+            program_id,
+            subscribers: Default::default(),
+            next_id: Default::default(),
+            parse_errors: Default::default(),
+        });
+        let task = tokio::spawn(Self::run(ws_url, commitment, shared.clone()));
+        Self { shared, task }
+    }
+
+    /// Registers a new subscription with the given filter
+    /// and the default buffer of [`SubscriptionManager::SUBSCRIPTION_BUFFER`] events.
+    pub fn subscribe(&self, filter: EventFilter) -> Subscription {
+        self.subscribe_with_buffer(filter, Self::SUBSCRIPTION_BUFFER)
+    }
+
+    /// Registers a new subscription with the given filter that buffers up to `buffer`
+    /// events (at least one). Size it to cover the bursts the consumer lags behind.
+    pub fn subscribe_with_buffer(&self, filter: EventFilter, buffer: usize) -> Subscription {
+        let id = self.shared.next_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = mpsc::channel(buffer.max(1));
+        let lagged = Arc::new(AtomicU64::new(0));
+        self.shared.subscribers().insert(
+            id,
+            Subscriber {
+                filter,
+                sender,
+                lagged: lagged.clone(),
+            },
+        );
+
+        Subscription {
+            id,
+            receiver,
+            lagged,
+            shared: Arc::downgrade(&self.shared),
+        }
+    }
+
+    /// Routes the given notification to the matching subscriptions as if it was
+    /// received by the connection, e.g. a notification of another `logsSubscribe`
+    /// subscription. Duplicates are not detected.
+    pub fn route(&self, notification: &RpcResponse<RpcLogsResponse>) {
+        self.shared.route(notification);
+    }
+
+    /// Returns the number of notifications that failed to parse
+    /// (see [`events_in_logs_notification`]).
+    ///
+    /// [`events_in_logs_notification`]: super::events_in_logs_notification
+    pub fn parse_errors(&self) -> u64 {
+        self.shared.parse_errors.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of active subscriptions.
+    pub fn num_subscriptions(&self) -> usize {
+        self.shared.subscribers().len()
+    }
+
+    async fn run(ws_url: String, commitment: CommitmentConfig, shared: Arc<Shared>) {
+        let mut delay = Self::MIN_RECONNECT_DELAY;
+        loop {
+            if let Ok(pubsub) = PubsubClient::new(&ws_url).await {
+                let (filter, config) = logs_subscribe_args(&shared.program_id, commitment);
+                if let Ok((mut notifications, unsubscribe)) =
+                    pubsub.logs_subscribe(filter, config).await
+                {
+                    delay = Self::MIN_RECONNECT_DELAY;
+                    while let Some(notification) = notifications.next().await {
+                        shared.route(&notification);
+                    }
+                    drop(notifications);
+                    unsubscribe().await;
+                }
+                let _ = pubsub.shutdown().await;
+            }
+
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(Self::MAX_RECONNECT_DELAY);
+        }
+    }
+}
+
+impl Drop for SubscriptionManager {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// A stream of events registered with [`SubscriptionManager::subscribe`].
+///
+/// Dropping it unregisters the subscription.
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct Subscription {
+    id: u64,
+    receiver: mpsc::Receiver<ResolvedEvent>,
+    lagged: Arc<AtomicU64>,
+    shared: Weak<Shared>,
+}
+
+impl Subscription {
+    /// Returns the number of matching events dropped because the buffer was full.
+    pub fn lagged(&self) -> u64 {
+        self.lagged.load(Ordering::Relaxed)
+    }
+
+    /// Replaces the filter of this subscription.
+    ///
+    /// Returns `false` if the manager is gone.
+    pub fn set_filter(&self, filter: EventFilter) -> bool {
+        let Some(shared) = self.shared.upgrade() else {
+            return false;
+        };
+        let mut subscribers = shared.subscribers();
+        match subscribers.get_mut(&self.id) {
+            Some(subscriber) => {
+                subscriber.filter = filter;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Stream for Subscription {
+    type Item = ResolvedEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(shared) = self.shared.upgrade() {
+            shared.subscribers().remove(&self.id);
+        }
+    }
+}
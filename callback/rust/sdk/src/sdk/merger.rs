@@ -0,0 +1,197 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::RangeInclusive,
+};
+
+use anchor_client::solana_sdk::signature::Signature;
+
+use super::ResolvedEvent;
+
+/// Identifies an event across sources.
+type EventKey = (Signature, usize);
+
+/// Position of an event in the merged order.
+type OrderKey = (u64, Signature, usize);
+
+/// A set of disjoint, non-adjacent slot ranges kept sorted.
+#[derive(Debug, Clone, Default)]
+struct SlotRanges(Vec<RangeInclusive<u64>>);
+
+impl SlotRanges {
+    fn insert(&mut self, range: RangeInclusive<u64>) {
+        if range.is_empty() {
+            return;
+        }
+        let (mut start, mut end) = range.into_inner();
+        self.0.retain(|x| {
+            let overlaps =
+                *x.start() <= end.saturating_add(1) && start <= x.end().saturating_add(1);
+            if overlaps {
+                start = start.min(*x.start());
+                end = end.max(*x.end());
+            }
+            !overlaps
+        });
+        let position = self.0.partition_point(|x| *x.start() < start);
+        self.0.insert(position, start..=end);
+    }
+
+    fn contains(&self, slot: u64) -> bool {
+        self.0.iter().any(|x| x.contains(&slot))
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &RangeInclusive<u64>> {
+        self.0.iter()
+    }
+}
+
+/// Merges [`ResolvedEvent`]s coming from several redundant sources (e.g. RPC providers).
+///
+/// *   events are deduplicated by `(signature, log_index)`
+/// *   events are ordered by `(slot, signature, log_index)` — note that the relative order
+///     of transactions within a slot is not known here, so the signature is a tie-breaker
+/// *   sources report the slot ranges they observed completely
+///     (see [`MultiSourceMerger::mark_covered`]), so that:
+///     -   slots no source has covered are reported as gaps ([`MultiSourceMerger::gaps`])
+///     -   events a source should have seen but has not are reported as well
+///         ([`MultiSourceMerger::missed_by`])
+///
+/// Sources are identified by arbitrary indexes chosen by the caller.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct MultiSourceMerger {
+    events: BTreeMap<OrderKey, ResolvedEvent>,
+    /// Sources that reported the event.
+    sources: HashMap<EventKey, (u64, Vec<usize>)>,
+    coverage: HashMap<usize, SlotRanges>,
+}
+
+impl MultiSourceMerger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ingests an event reported by the given source.
+    ///
+    /// Returns `true` if the event is new (i.e. was not reported by any source before).
+    pub fn push(&mut self, source: usize, resolved: ResolvedEvent) -> bool {
+        let key = (resolved.signature, resolved.log_index);
+        match self.sources.get_mut(&key) {
+            Some((_, sources)) => {
+                if !sources.contains(&source) {
+                    sources.push(source);
+                }
+                false
+            }
+            None => {
+                self.sources.insert(key, (resolved.slot, vec![source]));
+                self.events.insert(
+                    (resolved.slot, resolved.signature, resolved.log_index),
+                    resolved,
+                );
+                true
+            }
+        }
+    }
+
+    /// Records that the source has observed every event within the given slot range.
+    ///
+    /// For example, this is the slot range spanned by a `getSignaturesForAddress` page,
+    /// or the slots processed while a websocket subscription was connected.
+    pub fn mark_covered(&mut self, source: usize, slots: RangeInclusive<u64>) {
+        self.coverage.entry(source).or_default().insert(slots);
+    }
+
+    /// Returns slot ranges within `range` that are not covered by any source.
+    ///
+    /// Events within these ranges may be missing from the merged stream.
+    pub fn gaps(&self, range: RangeInclusive<u64>) -> Vec<RangeInclusive<u64>> {
+        let mut covered = SlotRanges::default();
+        for ranges in self.coverage.values() {
+            for x in ranges.iter() {
+                covered.insert(x.clone());
+            }
+        }
+
+        let (start, end) = range.into_inner();
+        let mut gaps = Vec::new();
+        let mut next = start;
+        for x in covered.iter() {
+            if next > end {
+                break;
+            }
+            if *x.end() < next {
+                continue;
+            }
+            if *x.start() > next {
+                gaps.push(next..=(*x.start() - 1).min(end));
+            }
+            next = x.end().saturating_add(1);
+            if *x.end() == u64::MAX {
+                return gaps;
+            }
+        }
+        if next <= end {
+            gaps.push(next..=end);
+        }
+        gaps
+    }
+
+    /// Returns events within the source coverage that were reported by other sources only.
+    ///
+    /// A non-empty result means the source drops events (contrary to what it claims).
+    pub fn missed_by(&self, source: usize) -> Vec<&ResolvedEvent> {
+        let Some(coverage) = self.coverage.get(&source) else {
+            return Vec::new();
+        };
+        self.events
+            .values()
+            .filter(|x| coverage.contains(x.slot))
+            .filter(|x| {
+                self.sources
+                    .get(&(x.signature, x.log_index))
+                    .is_some_and(|(_, sources)| !sources.contains(&source))
+            })
+            .collect()
+    }
+
+    /// Returns all the merged events in order.
+    pub fn events(&self) -> impl Iterator<Item = &ResolvedEvent> {
+        self.events.values()
+    }
+
+    /// Removes and returns merged events up to the given slot (inclusive) in order.
+    ///
+    /// Deduplication state is kept for drained events, so late duplicates are still
+    /// recognized (see [`MultiSourceMerger::forget_until`]).
+    pub fn drain_until(&mut self, slot: u64) -> Vec<ResolvedEvent> {
+        let rest = match slot.checked_add(1) {
+            Some(next) => self
+                .events
+                .split_off(&(next, Signature::default(), usize::MIN)),
+            None => BTreeMap::new(),
+        };
+        std::mem::replace(&mut self.events, rest)
+            .into_values()
+            .collect()
+    }
+
+    /// Drops deduplication state and coverage up to the given slot (inclusive).
+    ///
+    /// Use it along with [`MultiSourceMerger::drain_until`] to bound the memory usage.
+    pub fn forget_until(&mut self, slot: u64) {
+        self.sources.retain(|_, (x, _)| *x > slot);
+        self.events.retain(|(x, _, _), _| *x > slot);
+        for ranges in self.coverage.values_mut() {
+            ranges.0.retain_mut(|x| {
+                if *x.end() <= slot {
+                    return false;
+                }
+                if *x.start() <= slot {
+                    *x = slot + 1..=*x.end();
+                }
+                true
+            });
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use futures::{Stream, StreamExt};
+
+use super::ResolvedEvent;
+
+/// An event along with arbitrary user metadata (e.g. a game round id),
+/// so that the application context travels through the processing chain
+/// instead of being correlated via a side map (see [`with_meta`] and [`map_meta`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct Tagged<T> {
+    pub event: ResolvedEvent,
+    pub meta: T,
+}
+
+impl<T> Tagged<T> {
+    pub fn new(event: ResolvedEvent, meta: T) -> Self {
+        Self { event, meta }
+    }
+
+    /// Replaces the metadata with the result of `f` keeping the event.
+    pub fn map_meta<U>(self, f: impl FnOnce(T) -> U) -> Tagged<U> {
+        Tagged {
+            event: self.event,
+            meta: f(self.meta),
+        }
+    }
+
+    /// Returns the event dropping the metadata.
+    pub fn into_event(self) -> ResolvedEvent {
+        self.event
+    }
+}
+
+/// Attaches the metadata returned by `meta` to every event of the stream:
+///
+/// ```
+/// # use futures::{Stream, StreamExt};
+/// # use orao_solana_vrf_cb::sdk::{with_meta, ResolvedEvent};
+/// # async fn f(events: impl Stream<Item = ResolvedEvent>) {
+/// let mut events = std::pin::pin!(with_meta(events, |ev| ev.slot / 432_000));
+/// while let Some(tagged) = events.next().await {
+///     let epoch = tagged.meta;
+///     // ...
+/// }
+/// # }
+/// ```
+///
+/// `meta` is called once per event, in the stream order.
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn with_meta<S, T, F>(events: S, mut meta: F) -> impl Stream<Item = Tagged<T>>
+where
+    S: Stream<Item = ResolvedEvent>,
+    F: FnMut(&ResolvedEvent) -> T,
+{
+    events.map(move |event| {
+        let meta = meta(&event);
+        Tagged::new(event, meta)
+    })
+}
+
+/// Maps the metadata of every tagged event of the stream (see [`Tagged::map_meta`]),
+/// e.g. to enrich it at a later stage of the pipeline.
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn map_meta<S, T, U, F>(events: S, mut f: F) -> impl Stream<Item = Tagged<U>>
+where
+    S: Stream<Item = Tagged<T>>,
+    F: FnMut(T) -> U,
+{
+    events.map(move |tagged| tagged.map_meta(&mut f))
+}
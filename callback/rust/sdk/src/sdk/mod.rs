@@ -1,24 +1,148 @@
 #![cfg(all(feature = "sdk", not(feature = "idl-build")))]
 //! Off-chain client module
 
+mod accounts;
+mod archive;
+mod audit;
+mod batch;
+#[cfg(feature = "blocking")]
+mod blocking;
+mod callback_info;
+mod callback_monitor;
+mod custom;
+mod dedup;
+mod derived;
 mod error;
+mod event_wait;
 mod events;
+mod export;
+mod fairness;
+mod flexible;
+mod forward;
+#[cfg(feature = "idl")]
+mod idl;
 mod instructions;
+#[cfg(feature = "js")]
+mod js;
+#[cfg(feature = "jsonl")]
+mod jsonl;
+mod latency;
+mod lifecycle;
+mod limiter;
+mod logs;
+mod manager;
+mod merger;
+mod meta;
 #[doc(hidden)]
 pub mod misc;
+mod network;
+#[cfg(feature = "otel")]
+mod otel;
+mod ownership;
+mod partition;
 mod priority;
+mod receipt;
+mod reorg;
+mod retry;
+mod seed;
+mod signatures;
 mod state;
+mod stats;
+mod status;
+mod subscription;
+mod tagged;
+mod template;
+#[cfg(feature = "test-vrf")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-vrf")))]
+pub mod test_vrf;
+mod throttle;
+mod transaction;
+#[cfg(feature = "tui")]
+mod tui;
+mod types;
+mod verify;
+mod visitor;
+#[cfg(feature = "webhook")]
+mod webhook;
 
 use std::ops::Deref;
 
+pub use accounts::*;
+use anchor_client::solana_sdk::instruction::Instruction;
 use anchor_client::solana_sdk::signer::Signer;
+use anchor_client::solana_sdk::system_instruction;
 use anchor_lang::prelude::*;
-pub use events::{Event, UnknownEvent};
+pub use archive::*;
+pub use audit::AuditRecord;
+pub use batch::*;
+#[cfg(feature = "blocking")]
+pub use blocking::*;
+pub use callback_info::*;
+pub use callback_monitor::*;
+pub use custom::{register_custom_event, CustomEvent, CustomEventError, CustomEventParser};
+pub use dedup::dedup_by_signature;
+pub use derived::*;
+pub use error::VrfProgramError;
+pub use event_wait::*;
+pub use events::{
+    discriminator_for_name, split_discriminator, summarize_transaction, Event, EventKind,
+    TruncatedEvent, UnknownEvent, VersionMismatch, CALLBACK_UPDATED_DISCRIMINATOR,
+    CALLED_BACK_DISCRIMINATOR, EVENT_FORMAT_VERSION, FULFILLED_DISCRIMINATOR, MAX_EVENT_SIZE,
+    REGISTERED_DISCRIMINATOR, REQUESTED_ALT_DISCRIMINATOR, REQUESTED_DISCRIMINATOR,
+    RESPONDED_DISCRIMINATOR, TRANSFERRED_DISCRIMINATOR, WITHDRAWN_DISCRIMINATOR,
+};
+pub use export::*;
+pub use fairness::*;
+pub use flexible::{FlexibleRandomnessEvent, RandomnessLayout};
+pub use forward::forward_events;
+#[cfg(feature = "idl")]
+pub use idl::*;
 pub use instructions::*;
+#[cfg(feature = "js")]
+pub use js::JsEvent;
+#[cfg(feature = "jsonl")]
+pub use jsonl::*;
+pub use latency::*;
+pub use lifecycle::*;
+pub use limiter::*;
+pub use logs::{
+    parse_logs, parse_logs_bytes, parse_logs_partitioned, LineParser, ParseError,
+    PROGRAM_DATA_PREFIX,
+};
+pub use manager::*;
+pub use merger::*;
+pub use meta::*;
+pub use network::Network;
+pub use ownership::*;
+pub use partition::*;
 pub use priority::*;
+pub use receipt::*;
+pub use reorg::*;
+pub use retry::*;
+pub use seed::*;
+pub use signatures::*;
+pub use state::*;
+pub use stats::*;
+pub use status::*;
+pub use subscription::*;
+pub use tagged::TaggedEvent;
+pub use template::EventTemplates;
+pub use throttle::throttle;
+pub use transaction::*;
+#[cfg(feature = "tui")]
+pub use tui::Color;
+pub use types::*;
+pub use verify::*;
+pub use visitor::EventVisitor;
+#[cfg(feature = "webhook")]
+pub use webhook::*;
 
 pub use anchor_client;
 
+/// Returns the actual balance of the client PDA — its lamports above the rent-exempt minimum.
+///
+/// This is the source of truth, while [`derived_balance_from_events`] only estimates it
+/// from events — compare the two to catch accounting drift.
 pub async fn client_balance<C: Deref<Target = impl Signer> + Clone>(
     orao_vrf: &anchor_client::Program<C>,
     client_addr: Pubkey,
@@ -30,3 +154,72 @@ pub async fn client_balance<C: Deref<Target = impl Signer> + Clone>(
         .await?;
     Ok(account.lamports.saturating_sub(rent))
 }
+
+/// Returns the number of requests the client balance (see [`client_balance`]) pays for,
+/// e.g. to warn users before the funds run out.
+///
+/// Every request costs the client [`NetworkConfiguration::request_fee`] plus the rent
+/// of the request account:
+///
+/// *   `request_rent` — the rent-exempt minimum of a new request account
+///     (see [`RequestAccount::expected_size`] — add 8 bytes of the discriminator),
+///     or zero to only account for the fee
+///
+/// Returns `u64::MAX` if requests are free.
+///
+/// [`NetworkConfiguration::request_fee`]: crate::state::network_state::NetworkConfiguration::request_fee
+/// [`RequestAccount::expected_size`]: crate::state::request::RequestAccount::expected_size
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn fundable_requests(
+    balance: u64,
+    config: &crate::state::network_state::NetworkConfiguration,
+    request_rent: u64,
+) -> u64 {
+    // the sum of two u64 never overflows u128
+    let cost = config.request_fee as u128 + request_rent as u128;
+    match cost {
+        0 => u64::MAX,
+        cost => (balance as u128 / cost) as u64,
+    }
+}
+
+/// Builds an instruction that deposits `amount` lamports to the client PDA.
+///
+/// Client PDA holds the prepaid balance that is used to pay for randomness requests
+/// (see [`client_balance`]), so this is the opposite of the [`Withdraw`] instruction.
+///
+/// [`Withdraw`]: crate::Withdraw
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn build_deposit(funder: Pubkey, client: Pubkey, amount: u64) -> Instruction {
+    system_instruction::transfer(&funder, &client, amount)
+}
+
+/// Tops up the client PDA so that its balance is at least `min_balance` lamports.
+///
+/// The deposit is paid by the program payer and is only sent if the current balance
+/// (see [`client_balance`]) is below the threshold.
+///
+/// Returns the resulting balance.
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub async fn ensure_funded<C: Deref<Target = impl Signer> + Clone>(
+    orao_vrf: &anchor_client::Program<C>,
+    client_addr: Pubkey,
+    min_balance: u64,
+) -> std::result::Result<u64, anchor_client::ClientError> {
+    let balance = client_balance(orao_vrf, client_addr).await?;
+    if balance >= min_balance {
+        return Ok(balance);
+    }
+
+    orao_vrf
+        .request()
+        .instruction(build_deposit(
+            orao_vrf.payer(),
+            client_addr,
+            min_balance - balance,
+        ))
+        .send()
+        .await?;
+
+    client_balance(orao_vrf, client_addr).await
+}
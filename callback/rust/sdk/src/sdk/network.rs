@@ -0,0 +1,224 @@
+use core::fmt;
+use std::io::{self, BufRead};
+
+use anchor_client::solana_client::{
+    nonblocking::pubsub_client::{PubsubClient, PubsubClientError},
+    rpc_response::{Response as RpcResponse, RpcBlockUpdate, RpcLogsResponse},
+};
+use anchor_client::solana_sdk::{commitment_config::CommitmentConfig, hash::Hash};
+#[cfg(feature = "blocking")]
+use anchor_client::{
+    solana_client::{client_error::ClientError, rpc_client::RpcClient},
+    solana_sdk::signature::Signature,
+};
+use anchor_lang::prelude::Pubkey;
+use futures::Stream;
+use solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, UiConfirmedBlock};
+
+use super::{
+    archive::program_events_in_log_lines,
+    parse_logs,
+    subscription::{program_events_in_logs_notification, subscribe_program_events},
+    transaction::{
+        program_events_in_block, program_events_in_block_update, program_events_in_transaction,
+        program_events_in_transaction_strict,
+    },
+    Event, ResolvedEvent, UnsubscribeFn, VrfProgramError,
+};
+
+/// Genesis hash of the mainnet-beta cluster.
+const MAINNET_GENESIS_HASH: &str = "5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dw2N9d";
+
+/// Genesis hash of the devnet cluster.
+const DEVNET_GENESIS_HASH: &str = "EtWTRABZaYq6iMfeYKouRu166VU2xqa1wcaWoxPkrZBG";
+
+/// A cluster the program is deployed to.
+///
+/// The program is deployed with the same id ([`crate::id`]) to both mainnet and devnet,
+/// and the events are the same, so there is a single discriminator set. What differs
+/// is the program id of a custom deployment (e.g. a fork or a local validator), so parse
+/// logs with [`Network::parse_logs`] to only accept events of the expected program —
+/// events logged by any other program are never parsed (see [`parse_logs`]).
+///
+/// The other parsing entrypoints have their counterparts here as well
+/// (e.g. [`Network::events_in_transaction`] or [`Network::subscribe_events`]) —
+/// the free functions are bound to the [`crate::id`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub enum Network {
+    Mainnet,
+    Devnet,
+    /// A deployment with the given program id.
+    Custom(Pubkey),
+}
+
+impl Network {
+    /// Returns the program id of this network.
+    pub fn program_id(&self) -> Pubkey {
+        match self {
+            Network::Mainnet | Network::Devnet => crate::id(),
+            Network::Custom(program_id) => *program_id,
+        }
+    }
+
+    /// Returns the network of the cluster with the given genesis hash
+    /// (see `RpcClient::get_genesis_hash`).
+    ///
+    /// Returns `None` for other clusters — their deployment is [`Network::Custom`].
+    pub fn from_genesis_hash(hash: &Hash) -> Option<Self> {
+        match hash.to_string().as_str() {
+            MAINNET_GENESIS_HASH => Some(Network::Mainnet),
+            DEVNET_GENESIS_HASH => Some(Network::Devnet),
+            _ => None,
+        }
+    }
+
+    /// Parses events emitted by the program of this network
+    /// (see [`parse_logs`]).
+    pub fn parse_logs<I>(&self, logs: I) -> impl Iterator<Item = io::Result<Event>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        parse_logs(&self.program_id(), logs)
+    }
+
+    /// Extracts the events emitted by the program of this network
+    /// (see [`events_in_transaction`]).
+    ///
+    /// # Errors
+    ///
+    /// See [`events_in_transaction`].
+    ///
+    /// [`events_in_transaction`]: super::events_in_transaction
+    pub fn events_in_transaction(
+        &self,
+        tx: &EncodedConfirmedTransactionWithStatusMeta,
+    ) -> io::Result<Vec<ResolvedEvent>> {
+        program_events_in_transaction(&self.program_id(), tx)
+    }
+
+    /// Same as [`Network::events_in_transaction`] but returns no events
+    /// if the transaction failed (see [`events_in_transaction_strict`]).
+    ///
+    /// # Errors
+    ///
+    /// See [`events_in_transaction`].
+    ///
+    /// [`events_in_transaction`]: super::events_in_transaction
+    /// [`events_in_transaction_strict`]: super::events_in_transaction_strict
+    pub fn events_in_transaction_strict(
+        &self,
+        tx: &EncodedConfirmedTransactionWithStatusMeta,
+    ) -> io::Result<Vec<ResolvedEvent>> {
+        program_events_in_transaction_strict(&self.program_id(), tx)
+    }
+
+    /// Extracts the events emitted by the program of this network
+    /// within the given block (see [`events_in_block`]).
+    ///
+    /// [`events_in_block`]: super::events_in_block
+    pub fn events_in_block<'a>(
+        &self,
+        slot: u64,
+        block: &'a UiConfirmedBlock,
+    ) -> impl Iterator<Item = io::Result<ResolvedEvent>> + 'a {
+        program_events_in_block(self.program_id(), slot, block)
+    }
+
+    /// Extracts the events emitted by the program of this network
+    /// within the given `blockSubscribe` notification (see [`events_in_block_update`]).
+    ///
+    /// [`events_in_block_update`]: super::events_in_block_update
+    pub fn events_in_block_update<'a>(
+        &self,
+        update: &'a RpcBlockUpdate,
+    ) -> impl Iterator<Item = io::Result<ResolvedEvent>> + 'a {
+        program_events_in_block_update(self.program_id(), update)
+    }
+
+    /// Extracts the events emitted by the program of this network
+    /// from the given `logsSubscribe` notification (see [`events_in_logs_notification`]).
+    ///
+    /// # Errors
+    ///
+    /// See [`events_in_logs_notification`].
+    ///
+    /// [`events_in_logs_notification`]: super::events_in_logs_notification
+    pub fn events_in_logs_notification(
+        &self,
+        notification: &RpcResponse<RpcLogsResponse>,
+    ) -> io::Result<Vec<ResolvedEvent>> {
+        program_events_in_logs_notification(&self.program_id(), notification)
+    }
+
+    /// Subscribes to the events of the program of this network
+    /// (see [`subscribe_events`]).
+    ///
+    /// [`subscribe_events`]: super::subscribe_events
+    pub async fn subscribe_events<'a>(
+        &self,
+        pubsub: &'a PubsubClient,
+        commitment: CommitmentConfig,
+    ) -> Result<
+        (
+            impl Stream<Item = io::Result<ResolvedEvent>> + 'a,
+            UnsubscribeFn,
+        ),
+        PubsubClientError,
+    > {
+        subscribe_program_events(self.program_id(), pubsub, commitment).await
+    }
+
+    /// Parses the events of the program of this network from a stream
+    /// of newline-delimited log records (see [`events_in_log_lines`]).
+    ///
+    /// [`events_in_log_lines`]: super::events_in_log_lines
+    pub fn events_in_log_lines<R: BufRead>(
+        &self,
+        reader: R,
+    ) -> impl Iterator<Item = io::Result<Event>> {
+        program_events_in_log_lines(&self.program_id(), reader)
+    }
+
+    /// Parses the error the program of this network failed with
+    /// from the transaction logs (see [`VrfProgramError::from_logs`]).
+    pub fn program_error_from_logs<I>(&self, logs: I) -> Option<VrfProgramError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        VrfProgramError::from_program_logs(&self.program_id(), logs)
+    }
+
+    /// Fetches the events of every successful transaction involving the program
+    /// of this network, oldest first (see [`fetch_all_events_blocking`]).
+    ///
+    /// # Errors
+    ///
+    /// See [`fetch_all_events_blocking`].
+    ///
+    /// [`fetch_all_events_blocking`]: super::fetch_all_events_blocking
+    #[cfg(feature = "blocking")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+    // the solana client error is returned as is (see `fetch_all_events_blocking`)
+    #[allow(clippy::result_large_err)]
+    pub fn fetch_all_events_blocking(
+        &self,
+        rpc: &RpcClient,
+        until: Option<Signature>,
+    ) -> Result<Vec<ResolvedEvent>, ClientError> {
+        super::blocking::fetch_all_program_events_blocking(&self.program_id(), rpc, until)
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Network::Mainnet => f.write_str("mainnet"),
+            Network::Devnet => f.write_str("devnet"),
+            Network::Custom(program_id) => write!(f, "custom ({program_id})"),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use opentelemetry::KeyValue;
+
+use super::{Event, Randomness};
+
+impl Event {
+    /// Returns OpenTelemetry attributes describing this event,
+    /// e.g. to attach a request lifecycle to a trace span.
+    ///
+    /// *   `vrf.event.kind` — the event name (see [`EventKind::name`])
+    /// *   `vrf.client` — the client PDA address (if any, see [`Event::client`])
+    /// *   `vrf.seed` — the request seed (for request events)
+    /// *   `vrf.authority` — the fulfill authority (for [`Responded`])
+    /// *   `vrf.program` — the client program (for [`Registered`] and [`CalledBack`])
+    /// *   `vrf.randomness` — the fulfilled randomness (for [`Fulfilled`])
+    ///
+    /// Addresses, seeds and randomness are base58-encoded.
+    ///
+    /// [`EventKind::name`]: super::EventKind::name
+    /// [`Responded`]: crate::events::Responded
+    /// [`Registered`]: crate::events::Registered
+    /// [`CalledBack`]: crate::events::CalledBack
+    /// [`Fulfilled`]: crate::events::Fulfilled
+    #[cfg_attr(docsrs, doc(cfg(feature = "otel")))]
+    pub fn otel_attributes(&self) -> Vec<KeyValue> {
+        let mut attributes = vec![KeyValue::new("vrf.event.kind", self.kind().name())];
+
+        if let Some(client) = self.client() {
+            attributes.push(KeyValue::new("vrf.client", client.to_string()));
+        }
+        if let Some((_, seed)) = self.request_key() {
+            attributes.push(KeyValue::new("vrf.seed", seed.to_string()));
+        }
+
+        match self {
+            Event::Responded(ev) => {
+                attributes.push(KeyValue::new("vrf.authority", ev.authority.to_string()));
+            }
+            Event::Registered(ev) => {
+                attributes.push(KeyValue::new("vrf.program", ev.program.to_string()));
+            }
+            Event::CalledBack(ev) => {
+                attributes.push(KeyValue::new("vrf.program", ev.program.to_string()));
+            }
+            Event::Fulfilled(ev) => {
+                attributes.push(KeyValue::new(
+                    "vrf.randomness",
+                    Randomness(ev.randomness).to_string(),
+                ));
+            }
+            _ => (),
+        }
+
+        attributes
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::BTreeMap;
+
+use anchor_lang::prelude::Pubkey;
+
+use super::{Event, ResolvedEvent};
+
+/// Returns the clients currently owned by the given key according to the
+/// [`Registered`] and [`Transferred`] events of a scan.
+///
+/// Events are applied in the slot order whatever the order they are given in
+/// (events of the same slot keep the given order — that is the emission order for events
+/// of a single transaction), so a client transferred away is not reported even if
+/// its `Registered` event comes last. The latest `Transferred` event wins even if
+/// the registration is not in the scan.
+///
+/// Clients are returned sorted by address.
+///
+/// [`Registered`]: crate::events::Registered
+/// [`Transferred`]: crate::events::Transferred
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn clients_for_owner<'a>(
+    events: impl IntoIterator<Item = &'a ResolvedEvent>,
+    owner: &Pubkey,
+) -> Vec<Pubkey> {
+    let mut events: Vec<_> = events.into_iter().collect();
+    events.sort_by_key(|x| x.slot);
+
+    let mut owners = BTreeMap::new();
+    for resolved in events {
+        match &resolved.event {
+            Event::Registered(ev) => {
+                owners.insert(ev.client, ev.owner);
+            }
+            Event::Transferred(ev) => {
+                owners.insert(ev.client, ev.new_owner);
+            }
+            _ => (),
+        }
+    }
+
+    owners
+        .into_iter()
+        .filter_map(|(client, x)| (x == *owner).then_some(client))
+        .collect()
+}
+
+/// Returns the slot of the earliest event of the given client in a scan,
+/// e.g. for "clients registered this week" cohort reports.
+///
+/// That is usually the [`Registered`] event, but any event naming the client counts
+/// (see [`Event::client`]), so a client registered before the scanned period
+/// is reported at its first activity within the period.
+///
+/// Returns `None` if there are no events of the client.
+///
+/// [`Registered`]: crate::events::Registered
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn first_seen_slot<'a>(
+    events: impl IntoIterator<Item = &'a ResolvedEvent>,
+    client: &Pubkey,
+) -> Option<u64> {
+    events
+        .into_iter()
+        .filter(|x| x.event.client().as_ref() == Some(client))
+        .map(|x| x.slot)
+        .min()
+}
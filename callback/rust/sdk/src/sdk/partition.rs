@@ -0,0 +1,102 @@
+use std::{
+    collections::HashMap,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use anchor_lang::prelude::Pubkey;
+use futures::{Stream, StreamExt};
+use tokio::{sync::mpsc, task::JoinHandle};
+
+use super::ResolvedEvent;
+
+/// Splits a stream of events into per-client substreams, e.g. to route the events
+/// of every tenant of a multi-tenant backend to a dedicated handler.
+///
+/// Yields `(client, events)` pairs as new clients appear — every substream gets
+/// the events of its client (see [`Event::client`]) in the original order.
+/// Events with no client are dropped.
+///
+/// Every substream buffers up to `buffer` events (at least one). Routing waits
+/// once a buffer is full, so a slow handler stalls the others (rather than
+/// growing the memory unboundedly) — consume the substreams concurrently.
+/// A dropped substream is re-yielded if more events of its client arrive.
+///
+/// The inner stream is driven by a background task that is stopped once
+/// the partitions stream is dropped, while the substreams end after the inner stream ends.
+/// Requires a Tokio runtime.
+///
+/// [`Event::client`]: super::Event::client
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn partition_by_client<S>(events: S, buffer: usize) -> ClientPartitions
+where
+    S: Stream<Item = ResolvedEvent> + Send + 'static,
+{
+    let buffer = buffer.max(1);
+    let (sender, receiver) = mpsc::unbounded_channel();
+
+    let task = tokio::spawn(async move {
+        let mut events = Box::pin(events);
+        let mut routes: HashMap<Pubkey, mpsc::Sender<ResolvedEvent>> = HashMap::new();
+
+        while let Some(resolved) = events.next().await {
+            let Some(client) = resolved.event.client() else {
+                continue;
+            };
+
+            let resolved = match routes.get(&client) {
+                Some(route) => match route.send(resolved).await {
+                    Ok(()) => continue,
+                    // The substream is dropped, so it is re-yielded.
+                    Err(mpsc::error::SendError(resolved)) => resolved,
+                },
+                None => resolved,
+            };
+
+            let (route, receiver) = mpsc::channel(buffer);
+            if sender.send((client, ClientEvents { receiver })).is_err() {
+                break;
+            }
+            // The receiver is alive, so the fresh channel has room.
+            let _ = route.try_send(resolved);
+            routes.insert(client, route);
+        }
+    });
+
+    ClientPartitions { receiver, task }
+}
+
+/// A stream of per-client substreams returned by [`partition_by_client`].
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct ClientPartitions {
+    receiver: mpsc::UnboundedReceiver<(Pubkey, ClientEvents)>,
+    task: JoinHandle<()>,
+}
+
+impl Stream for ClientPartitions {
+    type Item = (Pubkey, ClientEvents);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for ClientPartitions {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Events of a single client yielded by [`ClientPartitions`].
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct ClientEvents {
+    receiver: mpsc::Receiver<ResolvedEvent>,
+}
+
+impl Stream for ClientEvents {
+    type Item = ResolvedEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
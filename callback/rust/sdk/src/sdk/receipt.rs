@@ -0,0 +1,117 @@
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+
+use super::{Event, Randomness, RequestLifecycle, ResolvedEvent, Seed};
+
+/// A user-facing summary of a completed randomness request.
+///
+/// Built from a fulfilled [`RequestLifecycle`] (see [`RequestReceipt::from_lifecycle`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct RequestReceipt {
+    pub client: Pubkey,
+    pub seed: Seed,
+    /// Signature of the request transaction.
+    pub request_signature: Signature,
+    /// Signature of the fulfill transaction.
+    pub fulfill_signature: Signature,
+    /// The final randomness.
+    pub randomness: Randomness,
+    /// See [`RequestLifecycle::latency_slots`].
+    pub latency_slots: u64,
+    /// See [`RequestLifecycle::latency_seconds`].
+    pub latency_seconds: Option<u64>,
+    /// `true` if the callback was invoked (i.e. a [`CalledBack`] event was observed).
+    ///
+    /// [`CalledBack`]: crate::events::CalledBack
+    pub callback_invoked: bool,
+    /// All the events of the request in chronological order
+    /// (by slot, then by the order within the transaction).
+    pub events: Vec<ResolvedEvent>,
+}
+
+impl RequestReceipt {
+    /// Builds the receipt of the given request.
+    ///
+    /// Returns `None` if the request is not completed, i.e. either the request
+    /// or the fulfillment is not observed.
+    pub fn from_lifecycle(lifecycle: &RequestLifecycle) -> Option<Self> {
+        let requested = lifecycle.requested.as_ref()?;
+        let fulfilled = lifecycle.fulfilled.as_ref()?;
+
+        let mut events = lifecycle.events().cloned().collect::<Vec<_>>();
+        events.sort_by_key(|x| (x.slot, x.index));
+
+        Some(Self {
+            client: lifecycle.client,
+            seed: lifecycle.seed,
+            request_signature: requested.signature,
+            fulfill_signature: fulfilled.signature,
+            randomness: lifecycle.randomness()?,
+            latency_slots: lifecycle.latency_slots()?,
+            latency_seconds: lifecycle.latency_seconds(),
+            callback_invoked: lifecycle.called_back.is_some(),
+            events,
+        })
+    }
+
+    /// Adds the [`CallbackUpdated`] event that defined the client-level callback
+    /// used by this request (a lifecycle does not track client-level events).
+    ///
+    /// Pass the update history of the client one by one — only the latest update that
+    /// precedes the request is kept, i.e. it replaces the one added before if it is later.
+    /// An update precedes the request if it is observed at an earlier slot
+    /// or earlier within the request transaction. Updates of other transactions
+    /// of the request slot are ignored as their order is unknown.
+    ///
+    /// The event is ignored if it is not a [`CallbackUpdated`] of the same client
+    /// or if it does not precede the request.
+    ///
+    /// [`CallbackUpdated`]: crate::events::CallbackUpdated
+    pub fn with_callback_updated(mut self, resolved: ResolvedEvent) -> Self {
+        if !matches!(&resolved.event, Event::CallbackUpdated(ev) if ev.client == self.client) {
+            return self;
+        }
+        let Some(requested) = self
+            .events
+            .iter()
+            .find(|x| matches!(x.event, Event::Requested(_) | Event::RequestedAlt(_)))
+        else {
+            return self;
+        };
+        if !is_before(&resolved, requested) {
+            return self;
+        }
+
+        match self.callback_updated() {
+            Some(current) if is_before(current, &resolved) => self.events[0] = resolved,
+            Some(_) => {}
+            None => self.events.insert(0, resolved),
+        }
+        self
+    }
+
+    /// Returns the [`CallbackUpdated`] event (see [`RequestReceipt::with_callback_updated`]).
+    ///
+    /// [`CallbackUpdated`]: crate::events::CallbackUpdated
+    pub fn callback_updated(&self) -> Option<&ResolvedEvent> {
+        self.events
+            .first()
+            .filter(|x| matches!(x.event, Event::CallbackUpdated(_)))
+    }
+
+    /// Returns the number of oracle responses constituting the randomness.
+    pub fn num_responses(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|x| matches!(x.event, Event::Responded(_)))
+            .count()
+    }
+}
+
+/// Returns `true` if `a` is known to be emitted before `b` — at an earlier slot
+/// or earlier within the same transaction.
+fn is_before(a: &ResolvedEvent, b: &ResolvedEvent) -> bool {
+    a.slot < b.slot || (a.signature == b.signature && a.index < b.index)
+}
@@ -0,0 +1,190 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    pin::Pin,
+    time::Duration,
+};
+
+use anchor_client::solana_client::{
+    client_error::ClientError, nonblocking::rpc_client::RpcClient,
+    rpc_request::MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS,
+};
+use anchor_client::solana_sdk::{commitment_config::CommitmentConfig, signature::Signature};
+use futures::{
+    future::{self, Either},
+    stream::Fuse,
+    Stream, StreamExt,
+};
+use tokio::time::Instant;
+
+use super::ResolvedEvent;
+
+/// Returns events that are no longer backed by the chain.
+///
+/// This is meant for consumers working at the `processed` or `confirmed` commitment —
+/// an event received at these levels could be rolled back along with the fork
+/// that included its transaction. Call this function periodically for the recent events
+/// and roll back the state derived from the returned ones.
+///
+/// An event is considered dropped if its transaction
+///
+/// *   is not known to the cluster anymore
+/// *   failed (i.e. the program logs are not backed by the state changes)
+/// *   was processed at a slot other than [`ResolvedEvent::slot`] — this happens
+///     if the transaction landed again on another fork. Note that while the event
+///     itself is the same, it will be delivered again for the new slot.
+///
+/// Transaction statuses are fetched in batches with the transaction history search enabled,
+/// so old events are reconciled as well. Dropped events are returned in the given order.
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub async fn reconcile_against_chain(
+    rpc: &RpcClient,
+    events: &[ResolvedEvent],
+) -> Result<Vec<ResolvedEvent>, ClientError> {
+    let mut signatures = events.iter().map(|x| x.signature).collect::<Vec<_>>();
+    signatures.sort_unstable();
+    signatures.dedup();
+
+    let mut landed_at = HashMap::<Signature, Option<u64>>::with_capacity(signatures.len());
+    for chunk in signatures.chunks(MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS) {
+        let statuses = rpc.get_signature_statuses_with_history(chunk).await?.value;
+        for (signature, status) in chunk.iter().zip(statuses) {
+            let slot = status.filter(|x| x.err.is_none()).map(|x| x.slot);
+            landed_at.insert(*signature, slot);
+        }
+    }
+
+    Ok(events
+        .iter()
+        .filter(|x| landed_at.get(&x.signature).copied().flatten() != Some(x.slot))
+        .cloned()
+        .collect())
+}
+
+/// Holds the events of a `processed` or `confirmed` stream until they are finalized
+/// and only then yields them, dropping the events rolled back by a reorg.
+///
+/// This gives the finality guarantees of the `finalized` commitment, while the events
+/// are still observed early (e.g. to show them as pending). Every `poll_interval`
+/// the buffered events are checked against the chain:
+///
+/// *   events above the finalized slot are kept in the buffer
+/// *   an event is yielded once its transaction is finalized at [`ResolvedEvent::slot`]
+/// *   an event at or below the finalized slot is dropped if its transaction is unknown,
+///     failed or finalized at another slot (see [`reconcile_against_chain`] — the event
+///     is expected to be delivered again for the new slot)
+///
+/// Events are yielded in the given order among the ones finalized by the same check.
+/// A failed check is yielded as an error, and the events are checked again
+/// on the next poll. Once the inner stream ends, the remaining events are checked
+/// until the buffer is empty. Requires a Tokio runtime.
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn finalized_events<'a, S>(
+    rpc: &'a RpcClient,
+    events: S,
+    poll_interval: Duration,
+) -> impl Stream<Item = Result<ResolvedEvent, ClientError>> + 'a
+where
+    S: Stream<Item = ResolvedEvent> + 'a,
+{
+    let state = State {
+        events: Box::pin(events).fuse(),
+        pending: Vec::new(),
+        ready: VecDeque::new(),
+        next_check: Instant::now(),
+    };
+
+    futures::stream::unfold(state, move |mut state| async move {
+        loop {
+            if let Some(event) = state.ready.pop_front() {
+                return Some((Ok(event), state));
+            }
+            if state.events.is_done() && state.pending.is_empty() {
+                return None;
+            }
+
+            // the check is due `poll_interval` after the previous one
+            // however often the events arrive
+            let tick = tokio::time::sleep_until(state.next_check);
+            let next = if state.events.is_done() {
+                tick.await;
+                None
+            } else if state.pending.is_empty() {
+                let next = state.events.next().await;
+                state.next_check = Instant::now() + poll_interval;
+                next
+            } else {
+                match future::select(state.events.next(), Box::pin(tick)).await {
+                    Either::Left((next, _)) => next,
+                    Either::Right(_) => None,
+                }
+            };
+            if let Some(event) = next {
+                state.pending.push(event);
+                continue;
+            }
+            if Instant::now() < state.next_check {
+                // the inner stream has just ended
+                continue;
+            }
+
+            state.next_check = Instant::now() + poll_interval;
+            match take_finalized(rpc, &mut state.pending).await {
+                Ok(finalized) => state.ready = finalized.into(),
+                Err(err) => return Some((Err(err), state)),
+            }
+        }
+    })
+}
+
+struct State<S> {
+    events: Fuse<Pin<Box<S>>>,
+    pending: Vec<ResolvedEvent>,
+    ready: VecDeque<ResolvedEvent>,
+    next_check: Instant,
+}
+
+/// Removes the finalized and the dropped events from `pending` (see [`finalized_events`])
+/// and returns the former.
+async fn take_finalized(
+    rpc: &RpcClient,
+    pending: &mut Vec<ResolvedEvent>,
+) -> Result<Vec<ResolvedEvent>, ClientError> {
+    let root = rpc
+        .get_slot_with_commitment(CommitmentConfig::finalized())
+        .await?;
+
+    let mut signatures = pending
+        .iter()
+        .filter(|x| x.slot <= root)
+        .map(|x| x.signature)
+        .collect::<Vec<_>>();
+    signatures.sort_unstable();
+    signatures.dedup();
+
+    // `None` if the transaction is not finalized yet
+    let mut finalized_at = HashMap::<Signature, Option<Option<u64>>>::new();
+    for chunk in signatures.chunks(MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS) {
+        let statuses = rpc.get_signature_statuses_with_history(chunk).await?.value;
+        for (signature, status) in chunk.iter().zip(statuses) {
+            let slot = match status {
+                Some(x) if !x.satisfies_commitment(CommitmentConfig::finalized()) => None,
+                Some(x) => Some(x.err.is_none().then_some(x.slot)),
+                None => Some(None),
+            };
+            finalized_at.insert(*signature, slot);
+        }
+    }
+
+    let mut finalized = Vec::new();
+    pending.retain(|x| match finalized_at.get(&x.signature) {
+        // above the root or not finalized yet
+        None | Some(None) => true,
+        Some(Some(slot)) => {
+            if *slot == Some(x.slot) {
+                finalized.push(x.clone());
+            }
+            false
+        }
+    });
+    Ok(finalized)
+}
@@ -0,0 +1,142 @@
+use std::{future::Future, time::Duration};
+
+use anchor_client::solana_client::{client_error::ClientError, nonblocking::rpc_client::RpcClient};
+use anchor_lang::prelude::Pubkey;
+
+use super::{
+    fetch_request_statuses, PreparedRequest, RequestParamsBuilder, RequestParamsError,
+    RequestStatus, Seed,
+};
+use crate::events::Fulfilled;
+
+/// The interval between request account polls of [`submit_with_retry`].
+pub const RETRY_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// An error returned by [`submit_with_retry`].
+#[derive(Debug, thiserror::Error)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub enum SubmitRetryError<E> {
+    #[error(transparent)]
+    Params(#[from] RequestParamsError),
+    /// The submission callback failed.
+    #[error("failed to submit the request: {0}")]
+    Submit(E),
+    #[error(transparent)]
+    Client(Box<ClientError>),
+    /// Every attempt expired. The requests are still pending and could
+    /// be fulfilled later.
+    #[error("no request is fulfilled after {} attempts", seeds.len())]
+    Expired {
+        /// Seeds of the submitted requests in the submission order.
+        seeds: Vec<Seed>,
+    },
+}
+
+impl<E> From<ClientError> for SubmitRetryError<E> {
+    fn from(value: ClientError) -> Self {
+        Self::Client(Box::new(value))
+    }
+}
+
+/// The result of [`submit_with_retry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct RetriedRequest {
+    /// The fulfillment to use.
+    pub fulfilled: Fulfilled,
+    /// The number of submitted requests.
+    pub attempts: usize,
+    /// Seeds of the other submitted requests — they are still pending,
+    /// or fulfilled along with the returned one, so ignore their fulfillments.
+    pub superseded: Vec<Seed>,
+}
+
+/// Submits a request and resubmits it if it expires, until one of the requests
+/// is fulfilled or `max_attempts` requests expire (pass `usize::MAX` to retry
+/// until fulfilled).
+///
+/// *   `client` — the client PDA
+/// *   `params` — settings of the request; the given seed (if any) is used
+///     for the first attempt, and every resubmission gets a random seed,
+///     as the request account of an expired request still exists
+/// *   `expiry_slots` — a request expires if it is not fulfilled within this number of slots
+///     since the submission (see [`detect_expired_requests`]), including a request
+///     whose transaction has not landed
+/// *   `vrf_id` — the VRF program id (usually the [`crate::id`])
+/// *   `submit` — sends the client program transaction making the request
+///     (requests are made via CPI, see [`RequestParamsBuilder`])
+///
+/// Statuses of all the submitted requests are polled every [`RETRY_POLL_INTERVAL`]
+/// (see [`fetch_request_statuses`]), so an expired request fulfilled after a resubmission
+/// still completes the call. Only one fulfillment is returned — the one of the earliest
+/// attempt if several are fulfilled at once — and the rest are [`RetriedRequest::superseded`].
+/// The RPC client commitment is used.
+///
+/// # Errors
+///
+/// *   [`SubmitRetryError::Params`] — if `params` fail to build
+/// *   [`SubmitRetryError::Submit`] — on the first failed submission
+/// *   [`SubmitRetryError::Client`] — on the first failed RPC call
+/// *   [`SubmitRetryError::Expired`] — if every attempt expires
+///     (including a `max_attempts` of zero)
+///
+/// [`detect_expired_requests`]: super::detect_expired_requests
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub async fn submit_with_retry<F, Fut, E>(
+    rpc: &RpcClient,
+    client: &Pubkey,
+    params: RequestParamsBuilder,
+    max_attempts: usize,
+    expiry_slots: u64,
+    vrf_id: &Pubkey,
+    mut submit: F,
+) -> Result<RetriedRequest, SubmitRetryError<E>>
+where
+    F: FnMut(PreparedRequest) -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+{
+    let mut pairs = Vec::new();
+
+    while pairs.len() < max_attempts {
+        let params = match pairs.is_empty() {
+            true => params.clone(),
+            false => params.clone().with_seed(rand::random()),
+        };
+        let prepared = params.build()?;
+        let seed = prepared.seed();
+        submit(prepared).await.map_err(SubmitRetryError::Submit)?;
+        let submitted = rpc.get_slot().await?;
+        pairs.push((*client, seed));
+
+        loop {
+            let statuses = fetch_request_statuses(rpc, &pairs, vrf_id).await?;
+            if let Some((index, randomness)) =
+                statuses
+                    .into_iter()
+                    .enumerate()
+                    .find_map(|(index, status)| match status {
+                        RequestStatus::Fulfilled { randomness } => Some((index, randomness)),
+                        _ => None,
+                    })
+            {
+                let (_, seed) = pairs.remove(index);
+                return Ok(RetriedRequest {
+                    fulfilled: Fulfilled::new(*client, seed.0, randomness.0),
+                    attempts: pairs.len() + 1,
+                    superseded: pairs.into_iter().map(|(_, seed)| seed).collect(),
+                });
+            }
+
+            if rpc.get_slot().await?.saturating_sub(submitted) > expiry_slots {
+                break;
+            }
+            tokio::time::sleep(RETRY_POLL_INTERVAL).await;
+        }
+    }
+
+    Err(SubmitRetryError::Expired {
+        seeds: pairs.into_iter().map(|(_, seed)| seed).collect(),
+    })
+}
@@ -0,0 +1,94 @@
+use std::collections::BTreeSet;
+
+use anchor_client::solana_client::{client_error::ClientError, nonblocking::rpc_client::RpcClient};
+use anchor_lang::prelude::Pubkey;
+
+use super::Event;
+use crate::state::{request::RequestAccount, request_alt::RequestAltAccount};
+
+/// An error returned by [`check_seed_unused`].
+#[derive(Debug, thiserror::Error)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub enum SeedCheckError {
+    /// The request account for this seed already exists, so a request
+    /// with this seed would fail.
+    #[error("seed is already used by the request account {request}")]
+    Used { request: Pubkey },
+    #[error(transparent)]
+    Client(Box<ClientError>),
+}
+
+impl From<ClientError> for SeedCheckError {
+    fn from(value: ClientError) -> Self {
+        Self::Client(Box::new(value))
+    }
+}
+
+/// Checks that the seed is not used by the client yet, i.e. that neither
+/// [`RequestAccount`] nor [`RequestAltAccount`] exist for it.
+///
+/// Call it before submitting a request to avoid wasting a transaction that fails
+/// on the request account initialization. Both accounts are fetched with a single
+/// RPC call using the RPC client commitment.
+///
+/// Note that the check is racy — the seed could be used right after it.
+///
+/// # Errors
+///
+/// *   [`SeedCheckError::Used`] — if any of the request accounts exists
+/// *   [`SeedCheckError::Client`] — if the RPC call fails
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub async fn check_seed_unused(
+    rpc: &RpcClient,
+    client: &Pubkey,
+    seed: &[u8; 32],
+    vrf_id: &Pubkey,
+) -> Result<(), SeedCheckError> {
+    let addresses = [
+        RequestAccount::find_address(client, seed, vrf_id).0,
+        RequestAltAccount::find_address(client, seed, vrf_id).0,
+    ];
+    let accounts = rpc.get_multiple_accounts(&addresses).await?;
+
+    match addresses
+        .into_iter()
+        .zip(accounts)
+        .find(|(_, account)| account.is_some())
+    {
+        Some((request, _)) => Err(SeedCheckError::Used { request }),
+        None => Ok(()),
+    }
+}
+
+/// Returns the nonces missing from the client's sequence of requests in a scan,
+/// e.g. to detect dropped requests of a client deriving seeds from a counter.
+///
+/// `nonce_fn` maps a seed back to its nonce — this only works for deterministic
+/// seed schemes where the nonce is recoverable from the seed (e.g. the seed is
+/// the little-endian counter). Seeds it returns `None` for are ignored.
+///
+/// A nonce is seen if any event of its request is in the scan (see [`Event::request_key`]),
+/// and the gaps are searched between the lowest and the highest seen nonce,
+/// so requests missing from both ends of the sequence are not reported.
+///
+/// Returns the missing nonces in ascending order — note that every nonce between
+/// the seen ones is returned, so `nonce_fn` must reject seeds of other schemes.
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn detect_nonce_gaps<'a>(
+    client: &Pubkey,
+    events: impl IntoIterator<Item = &'a Event>,
+    nonce_fn: impl Fn(&[u8; 32]) -> Option<u64>,
+) -> Vec<u64> {
+    let seen = events
+        .into_iter()
+        .filter_map(Event::request_key)
+        .filter(|(x, _)| x == client)
+        .filter_map(|(_, seed)| nonce_fn(seed.as_bytes()))
+        .collect::<BTreeSet<_>>();
+
+    let (Some(&first), Some(&last)) = (seen.first(), seen.last()) else {
+        return Vec::new();
+    };
+    (first..=last).filter(|x| !seen.contains(x)).collect()
+}
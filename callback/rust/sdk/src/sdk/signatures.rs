@@ -0,0 +1,155 @@
+use anchor_client::solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    nonblocking::rpc_client::RpcClient,
+    rpc_client::GetConfirmedSignaturesForAddress2Config,
+    rpc_response::RpcConfirmedTransactionStatusWithSignature,
+};
+use anchor_client::solana_sdk::{
+    commitment_config::CommitmentConfig,
+    signature::{ParseSignatureError, Signature},
+};
+use anchor_lang::prelude::Pubkey;
+use futures::Stream;
+
+/// State machine behind [`signature_pages`].
+///
+/// It is exposed to be driven by custom transports: call [`SignaturePager::next_config`]
+/// to get the parameters of the next `getSignaturesForAddress` call and feed its
+/// response to [`SignaturePager::push_page`] until the former returns `None`.
+///
+/// Pages are walked from the newest signature backwards using the last signature
+/// of the previous page as the (exclusive) `before` cursor:
+///
+/// *   a page shorter than the limit is the last one
+/// *   a signature equal to the cursor is skipped
+///     (some RPC providers return the boundary signature inclusively)
+/// *   `until` is exclusive, i.e. the `until` signature itself is never yielded
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct SignaturePager {
+    before: Option<Signature>,
+    until: Option<Signature>,
+    limit: usize,
+    done: bool,
+}
+
+impl SignaturePager {
+    /// The maximum page size allowed by the `getSignaturesForAddress` RPC method.
+    pub const MAX_LIMIT: usize = 1000;
+
+    /// Creates a pager that walks signatures down to the `until` signature (exclusive),
+    /// or to the very first signature if `None`.
+    pub fn new(until: Option<Signature>) -> Self {
+        Self {
+            before: None,
+            until,
+            limit: Self::MAX_LIMIT,
+            done: false,
+        }
+    }
+
+    /// Starts the walk below the given signature (exclusive) rather than from the newest one.
+    pub fn with_before(mut self, before: Signature) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    /// Defines the page size (clamped to `1..=MAX_LIMIT`, defaults to [`Self::MAX_LIMIT`]).
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit.clamp(1, Self::MAX_LIMIT);
+        self
+    }
+
+    /// Returns `true` if there are no more pages.
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Returns the configuration of the next RPC call or `None` if there are no more pages.
+    pub fn next_config(
+        &self,
+        commitment: Option<CommitmentConfig>,
+    ) -> Option<GetConfirmedSignaturesForAddress2Config> {
+        (!self.done).then_some(GetConfirmedSignaturesForAddress2Config {
+            before: self.before,
+            until: self.until,
+            limit: Some(self.limit),
+            commitment,
+        })
+    }
+
+    /// Accepts the RPC response for the configuration returned by [`Self::next_config`].
+    ///
+    /// Returns the page to yield, or `None` if nothing new is in the response.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the response contains a malformed signature
+    /// (the pager is done in this case).
+    pub fn push_page(
+        &mut self,
+        mut page: Vec<RpcConfirmedTransactionStatusWithSignature>,
+    ) -> Result<Option<Vec<RpcConfirmedTransactionStatusWithSignature>>, ParseSignatureError> {
+        if page.len() < self.limit {
+            self.done = true;
+        }
+
+        let Some(last) = page.last() else {
+            self.done = true;
+            return Ok(None);
+        };
+        let last = last.signature.parse::<Signature>().inspect_err(|_| {
+            self.done = true;
+        })?;
+
+        let previous = self.before.replace(last);
+        if previous == Some(last) {
+            // The cursor did not move, so the next call would return the same page.
+            self.done = true;
+        }
+
+        if let Some(previous) = previous {
+            let previous = previous.to_string();
+            page.retain(|x| x.signature != previous);
+        }
+        if let Some(until) = self.until {
+            let until = until.to_string();
+            if let Some(position) = page.iter().position(|x| x.signature == until) {
+                page.truncate(position);
+                self.done = true;
+            }
+        }
+
+        Ok((!page.is_empty()).then_some(page))
+    }
+}
+
+/// Streams signatures of transactions involving the given address, newest first.
+///
+/// Signatures are fetched in pages of up to [`SignaturePager::MAX_LIMIT`] using the
+/// RPC client commitment, empty pages are never yielded (see [`SignaturePager`]
+/// for the paging rules).
+///
+/// *   `until` — stops at this signature (exclusive), e.g. the last one processed
+///     by the previous backfill; walks the whole history if `None`.
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn signature_pages(
+    rpc: &RpcClient,
+    address: Pubkey,
+    until: Option<Signature>,
+) -> impl Stream<Item = Result<Vec<RpcConfirmedTransactionStatusWithSignature>, ClientError>> + '_ {
+    futures::stream::try_unfold(SignaturePager::new(until), move |mut pager| async move {
+        while let Some(config) = pager.next_config(Some(rpc.commitment())) {
+            let page = rpc
+                .get_signatures_for_address_with_config(&address, config)
+                .await?;
+            let page = pager
+                .push_page(page)
+                .map_err(|err| ClientErrorKind::Custom(format!("malformed signature: {err}")))?;
+            if let Some(page) = page {
+                return Ok(Some((page, pager)));
+            }
+        }
+        Ok(None)
+    })
+}
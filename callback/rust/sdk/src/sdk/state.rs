@@ -1,9 +1,15 @@
 use std::sync::LazyLock;
 
-use anchor_client::solana_client::rpc_filter::{Memcmp, RpcFilterType};
-use anchor_lang::Discriminator;
+use anchor_client::solana_client::{
+    nonblocking::rpc_client::RpcClient,
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use anchor_client::ClientError;
+use anchor_lang::{prelude::Pubkey, AccountDeserialize, Discriminator};
 
-use crate::state::{request::RequestAccount, request_alt::RequestAltAccount};
+use crate::state::{
+    network_state::NetworkState, request::RequestAccount, request_alt::RequestAltAccount,
+};
 
 impl RequestAccount {
     pub fn pending_filters() -> Vec<RpcFilterType> {
@@ -40,3 +46,29 @@ impl RequestAltAccount {
         FILTER.clone()
     }
 }
+
+/// Fetches the [`NetworkState`] — the global program configuration
+/// (request fee, treasury and fulfill authorities, see [`NetworkConfiguration`]).
+///
+/// * `vrf_id` — the VRF program id (usually the [`crate::id`])
+///
+/// # Errors
+///
+/// *   [`ClientError::AccountNotFound`] — the program is not initialized
+/// *   [`ClientError::AnchorError`] — the account data is not a valid [`NetworkState`]
+///
+/// [`NetworkConfiguration`]: crate::state::network_state::NetworkConfiguration
+#[doc(alias = "fetch_global_config")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub async fn fetch_network_state(
+    rpc: &RpcClient,
+    vrf_id: &Pubkey,
+) -> Result<NetworkState, ClientError> {
+    let address = NetworkState::find_address(vrf_id).0;
+    let account = rpc
+        .get_account_with_commitment(&address, rpc.commitment())
+        .await?
+        .value
+        .ok_or(ClientError::AccountNotFound)?;
+    Ok(NetworkState::try_deserialize(&mut &account.data[..])?)
+}
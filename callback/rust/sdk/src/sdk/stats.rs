@@ -0,0 +1,313 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    time::Duration,
+};
+
+use anchor_lang::prelude::Pubkey;
+
+use super::{Event, EventKind, ResolvedEvent, Seed};
+
+/// Aggregate statistics over a collection of events.
+///
+/// ```ignore
+/// let stats: EventStats = events.into_iter().collect();
+/// ```
+///
+/// Duplicated events are counted as many times as they are given,
+/// except for the request completion (see [`EventStats::completion_rate`]).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct EventStats {
+    counts: BTreeMap<EventKind, usize>,
+    clients: HashSet<Pubkey>,
+    withdrawn: u64,
+    requested: HashSet<(Pubkey, Seed)>,
+    fulfilled: HashSet<(Pubkey, Seed)>,
+}
+
+impl EventStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the given event.
+    pub fn push(&mut self, event: &Event) {
+        *self.counts.entry(event.kind()).or_default() += 1;
+        if let Some(client) = event.client() {
+            self.clients.insert(client);
+        }
+        match event {
+            Event::Withdrawn(ev) => self.withdrawn = self.withdrawn.saturating_add(ev.amount),
+            Event::Requested(_) | Event::RequestedAlt(_) => {
+                self.requested.extend(event.request_key());
+            }
+            Event::Fulfilled(_) => {
+                self.fulfilled.extend(event.request_key());
+            }
+            _ => (),
+        }
+    }
+
+    /// Returns the number of events of the given kind.
+    pub fn count(&self, kind: EventKind) -> usize {
+        self.counts.get(&kind).copied().unwrap_or_default()
+    }
+
+    /// Returns the number of events per kind (kinds without events are omitted).
+    pub fn counts(&self) -> &BTreeMap<EventKind, usize> {
+        &self.counts
+    }
+
+    /// Returns the total number of events.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Returns the number of distinct clients referenced by the events
+    /// (see [`Event::client`]).
+    pub fn unique_clients(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Returns the total amount withdrawn in lamports (see [`Withdrawn::amount`]).
+    ///
+    /// [`Withdrawn::amount`]: crate::events::Withdrawn::amount
+    pub fn total_withdrawn(&self) -> u64 {
+        self.withdrawn
+    }
+
+    /// Returns the share of the observed requests that are fulfilled (`0.0..=1.0`).
+    ///
+    /// Only requests with an observed [`Requested`] (or [`RequestedAlt`]) event are considered,
+    /// so a fulfillment of a request made before the observed period does not count.
+    /// Requests are identified by the `(client, seed)` pair, so duplicates count once.
+    ///
+    /// Returns `None` if there are no requests.
+    ///
+    /// [`Requested`]: crate::events::Requested
+    /// [`RequestedAlt`]: crate::events::RequestedAlt
+    pub fn completion_rate(&self) -> Option<f64> {
+        if self.requested.is_empty() {
+            return None;
+        }
+        let completed = self.requested.intersection(&self.fulfilled).count();
+        Some(completed as f64 / self.requested.len() as f64)
+    }
+}
+
+impl Extend<Event> for EventStats {
+    fn extend<T: IntoIterator<Item = Event>>(&mut self, iter: T) {
+        for event in iter {
+            self.push(&event);
+        }
+    }
+}
+
+impl<'a> Extend<&'a Event> for EventStats {
+    fn extend<T: IntoIterator<Item = &'a Event>>(&mut self, iter: T) {
+        for event in iter {
+            self.push(event);
+        }
+    }
+}
+
+impl FromIterator<Event> for EventStats {
+    fn from_iter<T: IntoIterator<Item = Event>>(iter: T) -> Self {
+        let mut stats = Self::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+impl<'a> FromIterator<&'a Event> for EventStats {
+    fn from_iter<T: IntoIterator<Item = &'a Event>>(iter: T) -> Self {
+        let mut stats = Self::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+/// Distribution of the withdrawals per client (see [`Withdrawn`]), for operator reporting.
+///
+/// Amounts are in lamports. The average and the median are exact — computed with integers
+/// and rounded down (the median of an even number of withdrawals is the average
+/// of the two middle ones).
+///
+/// Note that every withdrawal amount is kept (8 bytes per [`Withdrawn`] event) because
+/// the median requires all the values — use [`EventStats::total_withdrawn`] if only
+/// the total is needed.
+///
+/// [`Withdrawn`]: crate::events::Withdrawn
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct WithdrawalStats {
+    /// Sorted amounts per client.
+    amounts: BTreeMap<Pubkey, Vec<u64>>,
+}
+
+impl WithdrawalStats {
+    /// Collects the withdrawals of the given events (other events are ignored).
+    pub fn from_events<'a, I>(events: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Event>,
+    {
+        let mut amounts = BTreeMap::<_, Vec<_>>::new();
+        for event in events {
+            if let Event::Withdrawn(ev) = event {
+                amounts.entry(ev.client).or_default().push(ev.amount);
+            }
+        }
+        for client_amounts in amounts.values_mut() {
+            client_amounts.sort_unstable();
+        }
+        Self { amounts }
+    }
+
+    /// Returns the clients with at least one withdrawal, sorted by the address.
+    pub fn clients(&self) -> impl Iterator<Item = &Pubkey> {
+        self.amounts.keys()
+    }
+
+    /// Returns the number of withdrawals of the given client.
+    pub fn count(&self, client: &Pubkey) -> usize {
+        self.amounts.get(client).map(Vec::len).unwrap_or_default()
+    }
+
+    /// Returns the total amount withdrawn by the given client.
+    ///
+    /// It is a `u128`, so it never overflows.
+    pub fn total(&self, client: &Pubkey) -> u128 {
+        self.amounts
+            .get(client)
+            .map(|x| x.iter().map(|x| *x as u128).sum())
+            .unwrap_or_default()
+    }
+
+    /// Returns the average withdrawal of the given client rounded down
+    /// (`None` if there are no withdrawals).
+    pub fn average(&self, client: &Pubkey) -> Option<u64> {
+        let count = self.count(client);
+        if count == 0 {
+            return None;
+        }
+        // the average is at most the maximum amount, so it fits
+        Some((self.total(client) / count as u128) as u64)
+    }
+
+    /// Returns the median withdrawal of the given client rounded down
+    /// (`None` if there are no withdrawals).
+    pub fn median(&self, client: &Pubkey) -> Option<u64> {
+        let amounts = self.amounts.get(client)?;
+        let middle = amounts.len() / 2;
+        if amounts.len() % 2 == 1 {
+            return Some(amounts[middle]);
+        }
+        let (low, high) = (amounts[middle - 1], amounts[middle]);
+        Some(low + (high - low) / 2)
+    }
+}
+
+/// Estimates the client balance (see [`client_balance`]) from its events, e.g. to reconcile
+/// the operator records against the actual balance.
+///
+/// *   `deposited` — the total amount deposited to the client so far. Deposits are plain
+///     transfers to the client PDA (see [`build_deposit`]), so there are no deposit events,
+///     and the amount comes from the operator records
+/// *   `request_fee` — the per-request fee (see [`NetworkConfiguration::request_fee`])
+///
+/// The estimate is `deposited - withdrawn - request_fee * requests` computed with exact
+/// integers over the [`Withdrawn`], [`Requested`] and [`RequestedAlt`] events of the client
+/// (events of other clients are ignored). It is an `i128` as it is negative if the events
+/// overstate the spending.
+///
+/// The estimate diverges from the actual balance if:
+///
+/// *   events are missing (e.g. the period before the indexing started)
+///     or there are direct transfers not in the `deposited` records
+/// *   the request fee changed over the given period
+/// *   there are pending requests — the client also pays the request account rent
+///     that is reimbursed upon the fulfill
+///
+/// [`client_balance`]: super::client_balance
+/// [`build_deposit`]: super::build_deposit
+/// [`NetworkConfiguration::request_fee`]: crate::state::network_state::NetworkConfiguration::request_fee
+/// [`Withdrawn`]: crate::events::Withdrawn
+/// [`Requested`]: crate::events::Requested
+/// [`RequestedAlt`]: crate::events::RequestedAlt
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn derived_balance_from_events<'a, I>(
+    client: &Pubkey,
+    deposited: u64,
+    request_fee: u64,
+    events: I,
+) -> i128
+where
+    I: IntoIterator<Item = &'a Event>,
+{
+    let mut balance = deposited as i128;
+    for event in events {
+        if event.client().as_ref() != Some(client) {
+            continue;
+        }
+        match event {
+            Event::Withdrawn(ev) => balance -= ev.amount as i128,
+            Event::Requested(_) | Event::RequestedAlt(_) => balance -= request_fee as i128,
+            _ => (),
+        }
+    }
+    balance
+}
+
+/// Counts events of the given kind per time window, e.g. requests (or fulfillments)
+/// over time for a dashboard.
+///
+/// Returns `(bucket start, count)` pairs sorted by the bucket start (a unix timestamp):
+///
+/// *   buckets are aligned to the multiples of the window since the unix epoch,
+///     so the results of separate calls line up
+/// *   a bucket covers `[start, start + window)`, i.e. an event at the very edge
+///     is counted in the next bucket
+/// *   empty buckets between the first and the last event are included with a zero count
+///     (so the series could be charted as is), while no buckets are returned
+///     if there are no events
+///
+/// Events without the block time are skipped. Note that [`EventKind::Requested`] doesn't
+/// include [`EventKind::RequestedAlt`] — call it for both kinds to count all the requests.
+///
+/// # Panics
+///
+/// Panics if the window is shorter than a second (block times have the second resolution).
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn throughput<'a>(
+    events: impl IntoIterator<Item = &'a ResolvedEvent>,
+    kind: EventKind,
+    window: Duration,
+) -> Vec<(i64, u64)> {
+    let window = i64::try_from(window.as_secs()).unwrap_or(i64::MAX);
+    assert!(window > 0, "window must be at least a second");
+
+    let mut buckets = BTreeMap::<i64, u64>::new();
+    for resolved in events {
+        if resolved.event.kind() != kind {
+            continue;
+        }
+        let Some(time) = resolved.block_time else {
+            continue;
+        };
+        *buckets.entry(time.div_euclid(window) * window).or_default() += 1;
+    }
+
+    let (Some(&first), Some(&last)) = (buckets.keys().next(), buckets.keys().next_back()) else {
+        return Vec::new();
+    };
+    let mut series = Vec::new();
+    let mut start = first;
+    loop {
+        series.push((start, buckets.get(&start).copied().unwrap_or_default()));
+        if start >= last {
+            break;
+        }
+        start += window;
+    }
+    series
+}
@@ -0,0 +1,244 @@
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use anchor_client::solana_account_decoder::{UiAccount, UiAccountEncoding};
+use anchor_client::solana_client::{
+    client_error::ClientError,
+    nonblocking::{
+        pubsub_client::{PubsubClient, PubsubClientError},
+        rpc_client::RpcClient,
+    },
+    rpc_config::RpcAccountInfoConfig,
+    rpc_request::MAX_MULTIPLE_ACCOUNTS,
+};
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_lang::{prelude::Pubkey, AccountDeserialize, Discriminator};
+use futures::{Stream, StreamExt};
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+};
+
+use super::{Randomness, Seed};
+use crate::state::{request::RequestAccount, request_alt::RequestAltAccount};
+
+/// The state of a randomness request as seen in its request account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub enum RequestStatus {
+    /// The request account does not exist.
+    NotFound,
+    /// The request awaits fulfillment.
+    Pending {
+        /// The number of oracle responses collected so far.
+        responses: usize,
+    },
+    /// The request is fulfilled.
+    Fulfilled { randomness: Randomness },
+}
+
+impl RequestStatus {
+    /// Returns `true` if the request is fulfilled.
+    pub fn is_fulfilled(&self) -> bool {
+        matches!(self, RequestStatus::Fulfilled { .. })
+    }
+
+    /// Decodes the status from the raw account data.
+    ///
+    /// Both [`RequestAccount`] and [`RequestAltAccount`] are supported.
+    /// Empty data means the account does not exist (e.g. it is closed).
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`io::ErrorKind::InvalidData`] if the data is not a request account.
+    pub fn from_account_data(mut data: &[u8]) -> io::Result<Self> {
+        if data.is_empty() {
+            return Ok(RequestStatus::NotFound);
+        }
+
+        let invalid = |e| io::Error::new(io::ErrorKind::InvalidData, e);
+        // The exact discriminator is compared (not a prefix of the data).
+        let is_alt =
+            data.first_chunk::<8>().map(|x| &x[..]) == Some(RequestAltAccount::DISCRIMINATOR);
+        let (responses, randomness) = if is_alt {
+            let account = RequestAltAccount::try_deserialize(&mut data).map_err(invalid)?;
+            (
+                account.pending().map(|x| x.responses.len()),
+                account.fulfilled().map(|x| x.randomness),
+            )
+        } else {
+            let account = RequestAccount::try_deserialize(&mut data).map_err(invalid)?;
+            (
+                account.pending().map(|x| x.responses.len()),
+                account.fulfilled().map(|x| x.randomness),
+            )
+        };
+
+        Ok(match (responses, randomness) {
+            (Some(responses), _) => RequestStatus::Pending { responses },
+            (None, Some(randomness)) => RequestStatus::Fulfilled {
+                randomness: Randomness(randomness),
+            },
+            (None, None) => unreachable!("request is either pending or fulfilled"),
+        })
+    }
+
+    /// Decodes the status from an `accountSubscribe` notification.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`io::ErrorKind::InvalidData`] if the account data is not decodable
+    /// or is not a request account (see [`RequestStatus::from_account_data`]).
+    pub fn from_ui_account(account: &UiAccount) -> io::Result<Self> {
+        if account.lamports == 0 {
+            return Ok(RequestStatus::NotFound);
+        }
+        let data = account.data.decode().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "unsupported account encoding")
+        })?;
+        Self::from_account_data(&data)
+    }
+}
+
+/// Fetches the statuses of many requests using `getMultipleAccounts` in batches,
+/// e.g. for a dashboard of pending requests — far fewer RPC calls than one per request.
+///
+/// Both [`RequestAccount`] and [`RequestAltAccount`] PDAs are derived for every
+/// `(client, seed)` pair (the kind of a request is not known beforehand), so a batch
+/// of [`MAX_MULTIPLE_ACCOUNTS`] accounts covers half as many requests. A request is
+/// [`RequestStatus::NotFound`] if neither account exists.
+///
+/// Statuses are returned in the order of `pairs`. The RPC client commitment is used.
+///
+/// # Errors
+///
+/// Errors on the first failed RPC call or if any of the accounts is not a request account
+/// (see [`RequestStatus::from_account_data`]).
+///
+/// [`MAX_MULTIPLE_ACCOUNTS`]: anchor_client::solana_client::rpc_request::MAX_MULTIPLE_ACCOUNTS
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub async fn fetch_request_statuses(
+    rpc: &RpcClient,
+    pairs: &[(Pubkey, Seed)],
+    vrf_id: &Pubkey,
+) -> Result<Vec<RequestStatus>, ClientError> {
+    let mut statuses = Vec::with_capacity(pairs.len());
+
+    for chunk in pairs.chunks(MAX_MULTIPLE_ACCOUNTS / 2) {
+        let addresses = chunk
+            .iter()
+            .flat_map(|(client, seed)| {
+                [
+                    RequestAccount::find_address(client, seed.as_bytes(), vrf_id).0,
+                    RequestAltAccount::find_address(client, seed.as_bytes(), vrf_id).0,
+                ]
+            })
+            .collect::<Vec<_>>();
+        let accounts = rpc.get_multiple_accounts(&addresses).await?;
+
+        for pair in accounts.chunks(2) {
+            let account = pair.iter().flatten().next();
+            let data = account.map(|x| &x.data[..]).unwrap_or_default();
+            statuses.push(RequestStatus::from_account_data(data)?);
+        }
+    }
+
+    Ok(statuses)
+}
+
+/// Watches the request account and yields its status on every update.
+///
+/// This is an alternative to waiting for the [`Fulfilled`] event (see [`subscribe_events`]):
+/// it is an `accountSubscribe` subscription on the request PDA,
+/// so no transaction logs are involved, but the account is decoded on every update.
+///
+/// *   `request` — the request PDA (see [`RequestAccount::find_address`]
+///     and [`RequestAltAccount::find_address`])
+///
+/// Only changes are reported, so fetch the account beforehand if the request
+/// might be fulfilled already. The stream ends after the fulfillment is observed
+/// or if the connection is lost. Updates that fail to decode are skipped.
+///
+/// Dropping the stream closes the connection. Requires a Tokio runtime.
+///
+/// [`Fulfilled`]: crate::events::Fulfilled
+/// [`subscribe_events`]: super::subscribe_events
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub async fn watch_request_account(
+    ws_url: impl Into<String>,
+    request: Pubkey,
+    commitment: CommitmentConfig,
+) -> Result<impl Stream<Item = RequestStatus>, PubsubClientError> {
+    let ws_url = ws_url.into();
+    let (ready_sender, ready) = oneshot::channel();
+    let (sender, receiver) = mpsc::unbounded_channel();
+
+    let task = tokio::spawn(async move {
+        let pubsub = match PubsubClient::new(&ws_url).await {
+            Ok(pubsub) => pubsub,
+            Err(err) => {
+                let _ = ready_sender.send(Err(err));
+                return;
+            }
+        };
+        let config = RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(commitment),
+            ..Default::default()
+        };
+        let (mut updates, unsubscribe) =
+            match pubsub.account_subscribe(&request, Some(config)).await {
+                Ok(x) => x,
+                Err(err) => {
+                    let _ = ready_sender.send(Err(err));
+                    return;
+                }
+            };
+        let _ = ready_sender.send(Ok(()));
+
+        while let Some(update) = updates.next().await {
+            let Ok(status) = RequestStatus::from_ui_account(&update.value) else {
+                continue;
+            };
+            let fulfilled = status.is_fulfilled();
+            if sender.send(status).is_err() || fulfilled {
+                break;
+            }
+        }
+
+        drop(updates);
+        unsubscribe().await;
+        let _ = pubsub.shutdown().await;
+    });
+
+    match ready.await {
+        Ok(Ok(())) => Ok(RequestWatch { receiver, task }),
+        Ok(Err(err)) => Err(err),
+        Err(_) => Err(PubsubClientError::ConnectionClosed(
+            "subscription task terminated".into(),
+        )),
+    }
+}
+
+struct RequestWatch {
+    receiver: mpsc::UnboundedReceiver<RequestStatus>,
+    task: JoinHandle<()>,
+}
+
+impl Stream for RequestWatch {
+    type Item = RequestStatus;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for RequestWatch {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,129 @@
+use std::io;
+
+use anchor_client::solana_client::{
+    nonblocking::pubsub_client::{PubsubClient, PubsubClientError},
+    rpc_config::{RpcTransactionLogsConfig, RpcTransactionLogsFilter},
+    rpc_response::{Response as RpcResponse, RpcLogsResponse},
+};
+use anchor_client::solana_sdk::{commitment_config::CommitmentConfig, signature::Signature};
+use anchor_lang::prelude::Pubkey;
+use futures::{future::BoxFuture, Stream, StreamExt};
+
+use super::{logs::parse_logs_indexed, ResolvedEvent};
+
+/// A function that cancels the subscription (see [`subscribe_events`]).
+pub type UnsubscribeFn = Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send>;
+
+/// Extracts the VRF events from a `logsSubscribe` notification.
+///
+/// The notification contains all the logs of a matching transaction, so the
+/// invocation-frame-aware extractor is used (see [`parse_logs`]).
+///
+/// A failed transaction is reverted along with its events,
+/// so an empty list is returned if the notification reports an error.
+///
+/// [`ResolvedEvent::block_time`] is always `None` here
+/// because notifications do not include it.
+///
+/// # Errors
+///
+/// *   errors with [`io::ErrorKind::InvalidData`] if the signature is malformed
+/// *   errors if any of the VRF events fails to parse (see [`Event::try_from_bytes`])
+///
+/// [`parse_logs`]: super::parse_logs
+/// [`Event::try_from_bytes`]: super::Event::try_from_bytes
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn events_in_logs_notification(
+    notification: &RpcResponse<RpcLogsResponse>,
+) -> io::Result<Vec<ResolvedEvent>> {
+    program_events_in_logs_notification(&crate::id(), notification)
+}
+
+/// Same as [`events_in_logs_notification`] but for the given program id
+/// (see [`Network::events_in_logs_notification`]).
+///
+/// [`Network::events_in_logs_notification`]: super::Network::events_in_logs_notification
+pub(crate) fn program_events_in_logs_notification(
+    program_id: &Pubkey,
+    notification: &RpcResponse<RpcLogsResponse>,
+) -> io::Result<Vec<ResolvedEvent>> {
+    if notification.value.err.is_some() {
+        return Ok(Vec::new());
+    }
+
+    let signature = notification
+        .value
+        .signature
+        .parse::<Signature>()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let slot = notification.context.slot;
+    parse_logs_indexed(program_id, &notification.value.logs)
+        .enumerate()
+        .map(|(index, (log_index, event))| {
+            event.map(|event| ResolvedEvent::new(signature, slot, None, index, log_index, event))
+        })
+        .collect()
+}
+
+/// Subscribes to the VRF events of successful transactions.
+///
+/// This is a `logsSubscribe` subscription with the `mentions` filter
+/// on the VRF program id, every notification is handled with
+/// [`events_in_logs_notification`] — a notification that fails to parse
+/// is yielded as a single error.
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub async fn subscribe_events(
+    pubsub: &PubsubClient,
+    commitment: CommitmentConfig,
+) -> Result<
+    (
+        impl Stream<Item = io::Result<ResolvedEvent>> + '_,
+        UnsubscribeFn,
+    ),
+    PubsubClientError,
+> {
+    subscribe_program_events(crate::id(), pubsub, commitment).await
+}
+
+/// Same as [`subscribe_events`] but for the given program id
+/// (see [`Network::subscribe_events`]).
+///
+/// [`Network::subscribe_events`]: super::Network::subscribe_events
+pub(crate) async fn subscribe_program_events(
+    program_id: Pubkey,
+    pubsub: &PubsubClient,
+    commitment: CommitmentConfig,
+) -> Result<
+    (
+        impl Stream<Item = io::Result<ResolvedEvent>> + '_,
+        UnsubscribeFn,
+    ),
+    PubsubClientError,
+> {
+    let (filter, config) = logs_subscribe_args(&program_id, commitment);
+    let (notifications, unsubscribe) = pubsub.logs_subscribe(filter, config).await?;
+
+    let events = notifications.flat_map(move |notification| {
+        let events = match program_events_in_logs_notification(&program_id, &notification) {
+            Ok(events) => events.into_iter().map(Ok).collect(),
+            Err(err) => vec![Err(err)],
+        };
+        futures::stream::iter(events)
+    });
+
+    Ok((events, unsubscribe))
+}
+
+/// Returns `logsSubscribe` arguments that select transactions mentioning the given program.
+pub(crate) fn logs_subscribe_args(
+    program_id: &Pubkey,
+    commitment: CommitmentConfig,
+) -> (RpcTransactionLogsFilter, RpcTransactionLogsConfig) {
+    (
+        RpcTransactionLogsFilter::Mentions(vec![program_id.to_string()]),
+        RpcTransactionLogsConfig {
+            commitment: Some(commitment),
+        },
+    )
+}
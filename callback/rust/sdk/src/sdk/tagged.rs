@@ -0,0 +1,77 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{CustomEvent, Event};
+use crate::events::{
+    CallbackUpdated, CalledBack, Fulfilled, Registered, Requested, RequestedAlt, Responded,
+    Transferred, Withdrawn,
+};
+
+/// A wrapper that (de)serializes [`Event`] in the internally tagged form.
+///
+/// [`Event`] itself is externally tagged (`{"Fulfilled": {"client": ...}}`),
+/// while this wrapper gives the flat form common to JSON event streams:
+///
+/// ```json
+/// {"type": "Fulfilled", "client": ..., "seed": ..., "randomness": ...}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct TaggedEvent(pub Event);
+
+impl From<Event> for TaggedEvent {
+    fn from(value: Event) -> Self {
+        Self(value)
+    }
+}
+
+impl From<TaggedEvent> for Event {
+    fn from(value: TaggedEvent) -> Self {
+        value.0
+    }
+}
+
+macro_rules! tagged_repr {
+    ($($name:ident($ty:ty),)+) => {
+        #[derive(Serialize)]
+        #[serde(tag = "type")]
+        enum TaggedRef<'a> {
+            $($name(&'a $ty),)+
+        }
+
+        #[derive(Deserialize)]
+        #[serde(tag = "type")]
+        enum TaggedOwned {
+            $($name($ty),)+
+        }
+
+        impl Serialize for TaggedEvent {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match &self.0 {
+                    $(Event::$name(ev) => TaggedRef::$name(ev),)+
+                }
+                .serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for TaggedEvent {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok(Self(match TaggedOwned::deserialize(deserializer)? {
+                    $(TaggedOwned::$name(ev) => Event::$name(ev),)+
+                }))
+            }
+        }
+    };
+}
+
+tagged_repr!(
+    CallbackUpdated(CallbackUpdated),
+    CalledBack(CalledBack),
+    Fulfilled(Fulfilled),
+    Registered(Registered),
+    Requested(Requested),
+    RequestedAlt(RequestedAlt),
+    Responded(Responded),
+    Transferred(Transferred),
+    Withdrawn(Withdrawn),
+    Custom(CustomEvent),
+);
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use anchor_client::solana_sdk::native_token::LAMPORTS_PER_SOL;
+
+use super::{Event, EventKind, Randomness, Seed};
+
+/// Per-kind format strings used by [`Event::format_with`].
+///
+/// A template is an arbitrary string with named placeholders, e.g.
+/// `"{seed} is fulfilled with {randomness}"`. Use `{{` and `}}` to get literal braces.
+/// Unknown placeholders are kept as is.
+///
+/// Placeholders available for every event:
+///
+/// *   `{kind}` — the event name (see [`EventKind::name`])
+///
+/// Specific placeholders (addresses, seeds and randomness are base58-encoded):
+///
+/// *   [`EventKind::CallbackUpdated`] — `{client}`, `{owner}`, `{action}` (`set` or `unset`)
+/// *   [`EventKind::CalledBack`] — `{program}`
+/// *   [`EventKind::Fulfilled`] — `{client}`, `{seed}`, `{randomness}`
+/// *   [`EventKind::Registered`] — `{client}`, `{program}`, `{state}`, `{owner}`
+/// *   [`EventKind::Requested`] and [`EventKind::RequestedAlt`] — `{client}`, `{seed}`
+/// *   [`EventKind::Responded`] — `{client}`, `{seed}`, `{randomness}`, `{authority}`
+/// *   [`EventKind::Transferred`] — `{client}`, `{owner}`, `{new_owner}`
+/// *   [`EventKind::Withdrawn`] — `{client}`, `{owner}`, `{amount}` (lamports), `{sol}`
+/// *   [`EventKind::Custom`] — none
+///
+/// Events of kinds without a template are formatted using the [`Display`] implementation.
+///
+/// [`Display`]: std::fmt::Display
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub struct EventTemplates {
+    templates: HashMap<EventKind, String>,
+}
+
+impl EventTemplates {
+    /// Creates an empty set of templates, i.e. [`Display`] is used for every event.
+    ///
+    /// [`Display`]: std::fmt::Display
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines the template for the given event kind.
+    pub fn with(mut self, kind: EventKind, template: impl Into<String>) -> Self {
+        self.templates.insert(kind, template.into());
+        self
+    }
+
+    /// Returns the template for the given event kind (if defined).
+    pub fn get(&self, kind: EventKind) -> Option<&str> {
+        self.templates.get(&kind).map(String::as_str)
+    }
+}
+
+impl Event {
+    /// Formats this event using the given templates (see [`EventTemplates`]).
+    ///
+    /// Falls back to the [`Display`] implementation if there is no template
+    /// for the event kind.
+    ///
+    /// [`Display`]: std::fmt::Display
+    #[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+    pub fn format_with(&self, templates: &EventTemplates) -> String {
+        match templates.get(self.kind()) {
+            Some(template) => render(template, &self.placeholders()),
+            None => self.to_string(),
+        }
+    }
+
+    fn placeholders(&self) -> Vec<(&'static str, String)> {
+        let mut placeholders = vec![("kind", self.kind().name().to_string())];
+        match self {
+            Event::CallbackUpdated(ev) => placeholders.extend([
+                ("client", ev.client.to_string()),
+                ("owner", ev.owner.to_string()),
+                ("action", if ev.defined { "set" } else { "unset" }.into()),
+            ]),
+            Event::CalledBack(ev) => placeholders.push(("program", ev.program.to_string())),
+            Event::Fulfilled(ev) => placeholders.extend([
+                ("client", ev.client.to_string()),
+                ("seed", Seed(ev.seed).to_string()),
+                ("randomness", Randomness(ev.randomness).to_string()),
+            ]),
+            Event::Registered(ev) => placeholders.extend([
+                ("client", ev.client.to_string()),
+                ("program", ev.program.to_string()),
+                ("state", ev.state.to_string()),
+                ("owner", ev.owner.to_string()),
+            ]),
+            Event::Requested(ev) => placeholders.extend([
+                ("client", ev.client.to_string()),
+                ("seed", Seed(ev.seed).to_string()),
+            ]),
+            Event::RequestedAlt(ev) => placeholders.extend([
+                ("client", ev.client.to_string()),
+                ("seed", Seed(ev.seed).to_string()),
+            ]),
+            Event::Responded(ev) => placeholders.extend([
+                ("client", ev.client.to_string()),
+                ("seed", Seed(ev.seed).to_string()),
+                ("randomness", Randomness(ev.randomness).to_string()),
+                ("authority", ev.authority.to_string()),
+            ]),
+            Event::Transferred(ev) => placeholders.extend([
+                ("client", ev.client.to_string()),
+                ("owner", ev.owner.to_string()),
+                ("new_owner", ev.new_owner.to_string()),
+            ]),
+            Event::Withdrawn(ev) => placeholders.extend([
+                ("client", ev.client.to_string()),
+                ("owner", ev.owner.to_string()),
+                ("amount", ev.amount.to_string()),
+                (
+                    "sol",
+                    (ev.amount as f64 / LAMPORTS_PER_SOL as f64).to_string(),
+                ),
+            ]),
+            Event::Custom(_) => (),
+        }
+        placeholders
+    }
+}
+
+/// Substitutes `{name}` placeholders (`{{` and `}}` are escapes).
+fn render(template: &str, placeholders: &[(&str, String)]) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(pos) = rest.find(['{', '}']) {
+        output.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            output.push_str(&rest[..1]);
+            rest = &rest[2..];
+            continue;
+        }
+
+        let value = rest.strip_prefix('{').and_then(|x| {
+            let (name, _) = x.split_once('}')?;
+            let (_, value) = placeholders.iter().find(|(x, _)| *x == name)?;
+            Some((name.len() + 2, value))
+        });
+        match value {
+            Some((len, value)) => {
+                output.push_str(value);
+                rest = &rest[len..];
+            }
+            None => {
+                output.push_str(&rest[..1]);
+                rest = &rest[1..];
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
@@ -0,0 +1,73 @@
+//! Local VRF evaluation for tests.
+//!
+//! **For testing only.** These helpers take fulfill authority secret keys,
+//! so they are meant for test suites that run against their own (e.g. local validator)
+//! oracle keys. Never pass the secret key of a real oracle or load it in production code.
+//!
+//! The results are what the program would produce given responses of these authorities,
+//! so downstream logic could be tested deterministically without a live oracle:
+//!
+//! ```
+//! # use orao_solana_vrf_cb::sdk::{test_vrf, verify_response};
+//! # use anchor_lang::prelude::Pubkey;
+//! let secret_key = [7; 32];
+//! let authority = test_vrf::authority(&secret_key);
+//! let client = Pubkey::new_unique();
+//!
+//! let randomness = test_vrf::evaluate(&secret_key, &client, &[1; 32]);
+//! assert!(verify_response(&authority, &client, &[1; 32], &randomness.0));
+//! ```
+
+use anchor_lang::prelude::Pubkey;
+use ed25519_dalek::{ExpandedSecretKey, PublicKey, SecretKey};
+
+use super::{verify::response_message, Randomness};
+use crate::xor_array;
+
+/// Returns the fulfill authority (public key) of the given ed25519 secret key.
+#[cfg_attr(docsrs, doc(cfg(feature = "test-vrf")))]
+pub fn authority(secret_key: &[u8; 32]) -> Pubkey {
+    Pubkey::new_from_array(PublicKey::from(&secret(secret_key)).to_bytes())
+}
+
+/// Computes the response of the fulfill authority for the given request,
+/// i.e. the [`Responded`] event randomness (see [`verify_response`]).
+///
+/// *   `secret_key` — the 32-byte ed25519 secret key (the first half of a Solana keypair)
+///
+/// [`Responded`]: crate::events::Responded
+/// [`verify_response`]: super::verify_response
+#[cfg_attr(docsrs, doc(cfg(feature = "test-vrf")))]
+pub fn evaluate(secret_key: &[u8; 32], client: &Pubkey, seed: &[u8; 32]) -> Randomness {
+    let secret = secret(secret_key);
+    let signature = ExpandedSecretKey::from(&secret)
+        .sign(&response_message(client, seed), &PublicKey::from(&secret));
+    Randomness(signature.to_bytes())
+}
+
+/// Computes the fulfilled randomness of the request given the secret keys
+/// of the responding quorum, i.e. the [`Fulfilled`] event randomness.
+///
+/// It is the XOR of the individual responses (see [`evaluate`]), so the order
+/// of the keys does not matter. Pass exactly the authorities that
+/// respond before the request is fulfilled — the majority of the configured ones
+/// (see [`majority`]).
+///
+/// [`Fulfilled`]: crate::events::Fulfilled
+/// [`majority`]: crate::majority
+#[cfg_attr(docsrs, doc(cfg(feature = "test-vrf")))]
+pub fn evaluate_quorum<'a>(
+    secret_keys: impl IntoIterator<Item = &'a [u8; 32]>,
+    client: &Pubkey,
+    seed: &[u8; 32],
+) -> Randomness {
+    let mut randomness = [0_u8; 64];
+    for secret_key in secret_keys {
+        xor_array(&mut randomness, &evaluate(secret_key, client, seed).0);
+    }
+    Randomness(randomness)
+}
+
+fn secret(secret_key: &[u8; 32]) -> SecretKey {
+    SecretKey::from_bytes(secret_key).expect("secret key is 32 bytes long")
+}
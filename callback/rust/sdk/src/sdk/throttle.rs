@@ -0,0 +1,33 @@
+use std::time::Duration;
+
+use futures::{Stream, StreamExt};
+use tokio::time::Instant;
+
+/// Paces a stream to at most `max_per_sec` items per second, e.g. to protect
+/// a slow database or webhook endpoint from the bursts of a backfill.
+///
+/// Unlike sampling, no items are dropped — the inner stream is simply polled
+/// no faster than the rate, so a burst waits in the inner stream (or its source).
+/// It is a token bucket holding one token, refilled every `1 / max_per_sec`:
+/// consecutive items are never yielded closer than that, while an item arriving
+/// after a pause is yielded right away.
+///
+/// A `max_per_sec` of zero disables throttling. Requires a Tokio runtime.
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn throttle<S: Stream>(events: S, max_per_sec: u32) -> impl Stream<Item = S::Item> {
+    let interval = match max_per_sec {
+        0 => Duration::ZERO,
+        max_per_sec => Duration::from_secs(1) / max_per_sec,
+    };
+    let state = (Box::pin(events), None::<Instant>);
+
+    futures::stream::unfold(state, move |(mut events, next)| async move {
+        // a timer rounds up to its resolution, so it is not armed once the token is there
+        if let Some(next) = next.filter(|x| *x > Instant::now()) {
+            tokio::time::sleep_until(next).await;
+        }
+        let item = events.next().await?;
+        // the token is taken when the item is yielded, not when the wait started
+        Some((item, (events, Some(Instant::now() + interval))))
+    })
+}
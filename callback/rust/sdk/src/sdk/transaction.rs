@@ -0,0 +1,649 @@
+use std::io;
+
+use anchor_client::solana_client::{
+    rpc_config::RpcTransactionConfig, rpc_response::RpcBlockUpdate,
+};
+use anchor_client::solana_sdk::{
+    bs58, commitment_config::CommitmentConfig, ed25519_program, secp256k1_program,
+    signature::Signature,
+};
+use anchor_lang::{prelude::Pubkey, solana_program::hash::hashv};
+use solana_transaction_status::{
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction,
+    EncodedTransactionWithStatusMeta, UiConfirmedBlock, UiInstruction, UiMessage,
+    UiParsedInstruction,
+};
+
+use super::{logs::parse_logs_indexed, Event, Seed};
+
+/// An event along with the transaction context it was emitted in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct ResolvedEvent {
+    /// Signature of the transaction.
+    pub signature: Signature,
+    /// The slot the transaction was processed at.
+    pub slot: u64,
+    /// Estimated production time of the block (unix timestamp) if available.
+    pub block_time: Option<i64>,
+    /// Index of the event within the transaction (`0` for the first VRF event emitted).
+    ///
+    /// Events of a transaction are ordered by this index (as well as by `log_index`).
+    pub index: usize,
+    /// Index of the `Program data:` record within the transaction logs.
+    pub log_index: usize,
+    /// The event itself.
+    pub event: Event,
+}
+
+impl ResolvedEvent {
+    pub fn new(
+        signature: Signature,
+        slot: u64,
+        block_time: Option<i64>,
+        index: usize,
+        log_index: usize,
+        event: Event,
+    ) -> Self {
+        Self {
+            signature,
+            slot,
+            block_time,
+            index,
+            log_index,
+            event,
+        }
+    }
+
+    /// Returns a stable identifier of this event, e.g. an idempotency key
+    /// for webhook or queue consumers.
+    ///
+    /// It is the hex-encoded SHA-256 of the transaction signature, [`Self::log_index`]
+    /// and the event kind, so it is the same however many times the transaction
+    /// is fetched and parsed, and it never collides in practice. It doesn't depend
+    /// on the slot, so an event of a transaction that is re-included into another
+    /// block after a fork keeps its id.
+    pub fn event_id(&self) -> String {
+        let hash = hashv(&[
+            self.signature.as_ref(),
+            &(self.log_index as u64).to_le_bytes(),
+            self.event.kind().name().as_bytes(),
+        ]);
+        hash.to_bytes().iter().map(|x| format!("{x:02x}")).collect()
+    }
+}
+
+/// Extracts the VRF events emitted by the given confirmed transaction.
+///
+/// Events are returned in the emission order (see [`parse_logs`])
+/// that is also reflected by [`ResolvedEvent::index`].
+///
+/// Note that events of a failed transaction are extracted as well —
+/// use [`events_in_transaction_strict`] unless that is intended.
+///
+/// # Errors
+///
+/// *   errors with [`io::ErrorKind::InvalidData`] if the transaction has no signature
+/// *   errors if any of the VRF events fails to parse (see [`Event::try_from_bytes`])
+///
+/// [`parse_logs`]: super::parse_logs
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn events_in_transaction(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> io::Result<Vec<ResolvedEvent>> {
+    program_events_in_transaction(&crate::id(), tx)
+}
+
+/// Same as [`events_in_transaction`] but for the given program id
+/// (see [`Network::events_in_transaction`]).
+///
+/// [`Network::events_in_transaction`]: super::Network::events_in_transaction
+pub(crate) fn program_events_in_transaction(
+    program_id: &Pubkey,
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> io::Result<Vec<ResolvedEvent>> {
+    events_in_encoded_transaction(program_id, &tx.transaction, tx.slot, tx.block_time)
+}
+
+/// Same as [`events_in_transaction`] but returns no events if the transaction failed.
+///
+/// This is the recommended way to extract events. A failed transaction is reverted,
+/// yet its logs are kept — including the `Program data:` records written before
+/// the failure. So events of a failed transaction never took effect, and treating
+/// them as real (e.g. crediting a fulfillment that was rolled back) leads to
+/// phantom events.
+///
+/// # Errors
+///
+/// See [`events_in_transaction`] (a failed transaction is not an error).
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn events_in_transaction_strict(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> io::Result<Vec<ResolvedEvent>> {
+    program_events_in_transaction_strict(&crate::id(), tx)
+}
+
+/// Same as [`events_in_transaction_strict`] but for the given program id
+/// (see [`Network::events_in_transaction_strict`]).
+///
+/// [`Network::events_in_transaction_strict`]: super::Network::events_in_transaction_strict
+pub(crate) fn program_events_in_transaction_strict(
+    program_id: &Pubkey,
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> io::Result<Vec<ResolvedEvent>> {
+    let failed = tx
+        .transaction
+        .meta
+        .as_ref()
+        .is_some_and(|meta| meta.err.is_some());
+    if failed {
+        return Ok(Vec::new());
+    }
+    program_events_in_transaction(program_id, tx)
+}
+
+/// Extracts the VRF events emitted by the transactions of the given block,
+/// e.g. of a `blockSubscribe` notification (see [`events_in_block_update`])
+/// or of a `getBlock` response.
+///
+/// Transactions are visited in the block order and failed ones are skipped.
+/// Events of every transaction are yielded in the emission order
+/// (see [`events_in_transaction`]), with the block `slot` and time attached.
+///
+/// The block must be fetched with the `full` transaction details — there are
+/// no events if the transactions are omitted (the rewards are irrelevant).
+///
+/// If any of the transaction events fails to parse (or the transaction has no signature),
+/// the error is yielded in place of the transaction events.
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn events_in_block(
+    slot: u64,
+    block: &UiConfirmedBlock,
+) -> impl Iterator<Item = io::Result<ResolvedEvent>> + '_ {
+    program_events_in_block(crate::id(), slot, block)
+}
+
+/// Same as [`events_in_block`] but for the given program id
+/// (see [`Network::events_in_block`]).
+///
+/// [`Network::events_in_block`]: super::Network::events_in_block
+pub(crate) fn program_events_in_block(
+    program_id: Pubkey,
+    slot: u64,
+    block: &UiConfirmedBlock,
+) -> impl Iterator<Item = io::Result<ResolvedEvent>> + '_ {
+    block
+        .transactions
+        .iter()
+        .flatten()
+        .filter(|tx| tx.meta.as_ref().is_some_and(|meta| meta.err.is_none()))
+        .flat_map(move |tx| {
+            match events_in_encoded_transaction(&program_id, tx, slot, block.block_time) {
+                Ok(events) => events.into_iter().map(Ok).collect::<Vec<_>>(),
+                Err(err) => vec![Err(err)],
+            }
+        })
+}
+
+/// Extracts the VRF events of a `blockSubscribe` notification (see [`events_in_block`]).
+///
+/// Notifications without a block (e.g. reporting an error) contain no events.
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn events_in_block_update(
+    update: &RpcBlockUpdate,
+) -> impl Iterator<Item = io::Result<ResolvedEvent>> + '_ {
+    program_events_in_block_update(crate::id(), update)
+}
+
+/// Same as [`events_in_block_update`] but for the given program id
+/// (see [`Network::events_in_block_update`]).
+///
+/// [`Network::events_in_block_update`]: super::Network::events_in_block_update
+pub(crate) fn program_events_in_block_update(
+    program_id: Pubkey,
+    update: &RpcBlockUpdate,
+) -> impl Iterator<Item = io::Result<ResolvedEvent>> + '_ {
+    update
+        .block
+        .iter()
+        .flat_map(move |block| program_events_in_block(program_id, update.slot, block))
+}
+
+fn events_in_encoded_transaction(
+    program_id: &Pubkey,
+    tx: &EncodedTransactionWithStatusMeta,
+    slot: u64,
+    block_time: Option<i64>,
+) -> io::Result<Vec<ResolvedEvent>> {
+    let signature = transaction_signature(&tx.transaction).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "transaction has no signature")
+    })?;
+
+    let Some(logs) = tx
+        .meta
+        .as_ref()
+        .and_then(|meta| Option::<&Vec<String>>::from(meta.log_messages.as_ref()))
+    else {
+        return Ok(Vec::new());
+    };
+
+    parse_logs_indexed(program_id, logs)
+        .enumerate()
+        .map(|(index, (log_index, event))| {
+            event.map(|event| {
+                ResolvedEvent::new(signature, slot, block_time, index, log_index, event)
+            })
+        })
+        .collect()
+}
+
+/// Extracts the VRF events of the given transaction along with its fee payer
+/// (see [`events_in_transaction`]), e.g. to attribute [`Requested`] events
+/// for rate-limiting or abuse detection.
+///
+/// The payer is not a part of the events — it is the first account
+/// of the transaction message. Note that it is not necessarily the client owner,
+/// as requests are made via CPI by the client program.
+///
+/// # Errors
+///
+/// *   errors with [`io::ErrorKind::InvalidData`] if the transaction has no fee payer
+///     (e.g. it is fetched with the `signatures` transaction details)
+/// *   see [`events_in_transaction`]
+///
+/// [`Requested`]: crate::events::Requested
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn events_with_fee_payer(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> io::Result<(Pubkey, Vec<ResolvedEvent>)> {
+    let payer = transaction_fee_payer(&tx.transaction.transaction).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "transaction has no fee payer")
+    })?;
+    Ok((payer, events_in_transaction(tx)?))
+}
+
+/// Returns the [`Fulfilled`] events of the given transaction along with the fulfill authority
+/// whose response completed the request.
+///
+/// [`Fulfilled`] does not name the authority. It comes from the [`Responded`] event
+/// of the same request emitted earlier in the same transaction — the program emits
+/// [`Fulfilled`] right after the response that reaches the quorum, and the authority
+/// is the signer of that transaction. The authority is `None` if there is no such
+/// [`Responded`] event (e.g. the logs are truncated).
+///
+/// Note that with a multi-oracle quorum the other authorities responded in
+/// the preceding transactions — collect their [`Responded`] events to attribute
+/// all of the responses.
+///
+/// # Errors
+///
+/// See [`events_in_transaction`].
+///
+/// [`Fulfilled`]: crate::events::Fulfilled
+/// [`Responded`]: crate::events::Responded
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn fulfillments_with_authority(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> io::Result<Vec<(Option<Pubkey>, ResolvedEvent)>> {
+    let events = events_in_transaction(tx)?;
+    let mut fulfillments = Vec::new();
+
+    for (i, resolved) in events.iter().enumerate() {
+        let Event::Fulfilled(fulfilled) = &resolved.event else {
+            continue;
+        };
+        let authority = events[..i].iter().rev().find_map(|x| match &x.event {
+            Event::Responded(responded)
+                if responded.client == fulfilled.client && responded.seed == fulfilled.seed =>
+            {
+                Some(responded.authority)
+            }
+            _ => None,
+        });
+        fulfillments.push((authority, resolved.clone()));
+    }
+
+    Ok(fulfillments)
+}
+
+/// Lamports charged per transaction signature — the base fee of a transaction.
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+/// The cost of a fulfillment transaction (see [`fulfillment_cost`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct FulfillmentCost {
+    /// The [`Fulfilled`] events of the transaction — the cost is per transaction,
+    /// so split it if there are several (or there are none for a response that
+    /// has not reached the quorum).
+    ///
+    /// [`Fulfilled`]: crate::events::Fulfilled
+    pub fulfilled: Vec<ResolvedEvent>,
+    /// The total fee paid (the `fee` of the transaction meta).
+    pub fee: Option<u64>,
+    /// The prioritization fee — the total fee less the base fee
+    /// of [`LAMPORTS_PER_SIGNATURE`] per signature, that is per transaction
+    /// signature and per signature verified by the Ed25519 and Secp256k1 precompiles
+    /// (a fulfillment carries the Ed25519 instruction verifying the oracle signature).
+    pub priority_fee: Option<u64>,
+    /// Compute units consumed by the transaction (the `computeUnitsConsumed`
+    /// of the transaction meta).
+    pub compute_units_consumed: Option<u64>,
+}
+
+/// Returns the cost of the given fulfillment transaction, for oracle cost analysis.
+///
+/// The costs come from the transaction meta, so they are `None` if there is no meta,
+/// and `compute_units_consumed` is `None` if the node does not report it
+/// (e.g. for transactions older than the field). The priority fee is `None`
+/// if the instructions are not available (the `accounts` transaction encoding).
+///
+/// # Errors
+///
+/// See [`events_in_transaction`].
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn fulfillment_cost(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> io::Result<FulfillmentCost> {
+    let fulfilled = events_in_transaction(tx)?
+        .into_iter()
+        .filter(|x| matches!(x.event, Event::Fulfilled(_)))
+        .collect();
+
+    let meta = tx.transaction.meta.as_ref();
+    let fee = meta.map(|meta| meta.fee);
+    let signatures = transaction_signature_count(&tx.transaction.transaction)
+        .zip(precompile_signature_count(&tx.transaction.transaction))
+        .map(|(signatures, verified)| signatures as u64 + verified);
+    let priority_fee = fee
+        .zip(signatures)
+        .map(|(fee, signatures)| fee.saturating_sub(LAMPORTS_PER_SIGNATURE * signatures));
+    let compute_units_consumed = meta
+        .and_then(|meta| Option::<&u64>::from(meta.compute_units_consumed.as_ref()))
+        .copied();
+
+    Ok(FulfillmentCost {
+        fulfilled,
+        fee,
+        priority_fee,
+        compute_units_consumed,
+    })
+}
+
+/// Returns all the `(client, seed)` pairs referenced by the events of the given transaction.
+///
+/// Pairs are deduplicated and returned in the order of the first appearance.
+///
+/// # Errors
+///
+/// See [`events_in_transaction`].
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn seeds_in_transaction(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> io::Result<Vec<(Pubkey, Seed)>> {
+    let mut pairs = Vec::new();
+
+    for resolved in events_in_transaction(tx)? {
+        let Some(pair) = resolved.event.request_key() else {
+            continue;
+        };
+        if !pairs.contains(&pair) {
+            pairs.push(pair);
+        }
+    }
+
+    Ok(pairs)
+}
+
+/// The instruction that emitted an event (see [`instruction_for_event`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct InstructionLocation {
+    /// The program executing the instruction.
+    pub program_id: Pubkey,
+    /// Index of the top-level instruction within the transaction message
+    /// (of the one that made the CPI for an inner instruction).
+    pub instruction_index: usize,
+    /// Index of the inner instruction within the inner instructions of the top-level one
+    /// (in the order of the transaction meta), `None` for a top-level instruction.
+    pub inner_instruction_index: Option<usize>,
+    /// Invocation depth of the instruction (`1` for a top-level instruction).
+    pub stack_height: usize,
+}
+
+/// Returns the instruction that emitted the event at the given log record
+/// (see [`ResolvedEvent::log_index`]), e.g. to tie a CPI event to the instruction
+/// of the calling program.
+///
+/// The instruction is the innermost invocation frame of the record (see [`parse_logs`]).
+/// Top-level frames are matched to the message instructions by program id, as precompiles
+/// (e.g. Ed25519 signature verification) do not log invocations. If the message is not
+/// available (e.g. the `accounts` encoding) top-level frames are counted instead,
+/// so the index is off if there are precompile instructions.
+///
+/// Returns `None` if there are no logs, the index is out of range,
+/// or the record is not written within an invocation. Note that the location
+/// is unreliable if the logs are truncated before the record.
+///
+/// [`parse_logs`]: super::parse_logs
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn instruction_for_event(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    log_index: usize,
+) -> Option<InstructionLocation> {
+    let logs = Option::<&Vec<String>>::from(tx.transaction.meta.as_ref()?.log_messages.as_ref())?;
+    if log_index >= logs.len() {
+        return None;
+    }
+    let program_ids = transaction_instruction_programs(&tx.transaction.transaction);
+
+    let mut stack: Vec<InstructionLocation> = Vec::new();
+    let mut top_level: Option<usize> = None;
+    let mut inner_count = 0;
+
+    for (index, line) in logs[..=log_index].iter().enumerate() {
+        let Some((id, status)) = line
+            .strip_prefix("Program ")
+            .and_then(|x| x.split_once(' '))
+        else {
+            continue;
+        };
+
+        if let Some(depth) = status.strip_prefix("invoke [") {
+            // The depth resyncs the stack the same way `parse_logs` does.
+            if let Some(depth) = depth
+                .strip_suffix(']')
+                .and_then(|x| x.parse::<usize>().ok())
+            {
+                stack.truncate(depth.saturating_sub(1));
+            }
+            let program_id = id.parse().ok()?;
+
+            let location = match stack.first() {
+                Some(top) => {
+                    inner_count += 1;
+                    InstructionLocation {
+                        program_id,
+                        instruction_index: top.instruction_index,
+                        inner_instruction_index: Some(inner_count - 1),
+                        stack_height: stack.len() + 1,
+                    }
+                }
+                None => {
+                    let next = top_level.map_or(0, |x| x + 1);
+                    let instruction_index = program_ids
+                        .as_ref()
+                        .and_then(|ids| {
+                            ids.iter()
+                                .skip(next)
+                                .position(|x| *x == program_id)
+                                .map(|x| next + x)
+                        })
+                        .unwrap_or(next);
+                    top_level = Some(instruction_index);
+                    inner_count = 0;
+                    InstructionLocation {
+                        program_id,
+                        instruction_index,
+                        inner_instruction_index: None,
+                        stack_height: 1,
+                    }
+                }
+            };
+            stack.push(location);
+        } else if index < log_index && (status == "success" || status.starts_with("failed")) {
+            // The frame of the record itself is still executing.
+            stack.pop();
+        }
+    }
+
+    stack.last().copied()
+}
+
+/// Returns the fee payer of the given transaction — the first account of the message.
+pub(crate) fn transaction_fee_payer(tx: &EncodedTransaction) -> Option<Pubkey> {
+    match tx {
+        EncodedTransaction::Json(ui) => match &ui.message {
+            UiMessage::Raw(message) => message.account_keys.first()?.parse().ok(),
+            UiMessage::Parsed(message) => message.account_keys.first()?.pubkey.parse().ok(),
+        },
+        EncodedTransaction::Accounts(list) => list.account_keys.first()?.pubkey.parse().ok(),
+        _ => tx.decode()?.message.static_account_keys().first().copied(),
+    }
+}
+
+/// Returns the first (fee payer's) signature of the given transaction.
+pub(crate) fn transaction_signature(tx: &EncodedTransaction) -> Option<Signature> {
+    match tx {
+        EncodedTransaction::Json(ui) => ui.signatures.first()?.parse().ok(),
+        EncodedTransaction::Accounts(list) => list.signatures.first()?.parse().ok(),
+        _ => tx.decode()?.signatures.first().copied(),
+    }
+}
+
+/// Returns the number of signatures of the given transaction.
+fn transaction_signature_count(tx: &EncodedTransaction) -> Option<usize> {
+    match tx {
+        EncodedTransaction::Json(ui) => Some(ui.signatures.len()),
+        EncodedTransaction::Accounts(list) => Some(list.signatures.len()),
+        _ => Some(tx.decode()?.signatures.len()),
+    }
+}
+
+/// Returns the number of signatures verified by the Ed25519 and Secp256k1 precompiles
+/// that are charged as transaction signatures (`None` if the message is not available).
+fn precompile_signature_count(tx: &EncodedTransaction) -> Option<u64> {
+    // the first byte of a precompile instruction is the number of signatures
+    let count = |program_id: &Pubkey, data: &[u8]| {
+        if *program_id == ed25519_program::ID || *program_id == secp256k1_program::ID {
+            data.first().copied().map_or(0, u64::from)
+        } else {
+            0
+        }
+    };
+    let count_base58 = |program_id: &str, data: &str| {
+        let program_id = program_id.parse().ok()?;
+        Some(count(&program_id, &bs58::decode(data).into_vec().ok()?))
+    };
+
+    match tx {
+        EncodedTransaction::Json(ui) => match &ui.message {
+            UiMessage::Raw(message) => message
+                .instructions
+                .iter()
+                .map(|x| {
+                    let program_id = message.account_keys.get(usize::from(x.program_id_index))?;
+                    count_base58(program_id, &x.data)
+                })
+                .sum(),
+            UiMessage::Parsed(message) => message
+                .instructions
+                .iter()
+                .map(|x| match x {
+                    UiInstruction::Compiled(x) => {
+                        let program_id =
+                            &message.account_keys.get(usize::from(x.program_id_index))?;
+                        count_base58(&program_id.pubkey, &x.data)
+                    }
+                    UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(x)) => {
+                        count_base58(&x.program_id, &x.data)
+                    }
+                    // precompile instructions are never parsed
+                    UiInstruction::Parsed(UiParsedInstruction::Parsed(_)) => Some(0),
+                })
+                .sum(),
+        },
+        EncodedTransaction::Accounts(_) => None,
+        _ => {
+            let message = tx.decode()?.message;
+            let keys = message.static_account_keys();
+            message
+                .instructions()
+                .iter()
+                .map(|x| Some(count(keys.get(usize::from(x.program_id_index))?, &x.data)))
+                .sum()
+        }
+    }
+}
+
+/// Returns the program ids of the top-level instructions of the given transaction
+/// (`None` if the message is not available).
+fn transaction_instruction_programs(tx: &EncodedTransaction) -> Option<Vec<Pubkey>> {
+    match tx {
+        EncodedTransaction::Json(ui) => match &ui.message {
+            UiMessage::Raw(message) => message
+                .instructions
+                .iter()
+                .map(|x| {
+                    message
+                        .account_keys
+                        .get(usize::from(x.program_id_index))?
+                        .parse()
+                        .ok()
+                })
+                .collect(),
+            UiMessage::Parsed(message) => message
+                .instructions
+                .iter()
+                .map(|x| {
+                    let program_id = match x {
+                        UiInstruction::Compiled(x) => {
+                            &message
+                                .account_keys
+                                .get(usize::from(x.program_id_index))?
+                                .pubkey
+                        }
+                        UiInstruction::Parsed(UiParsedInstruction::Parsed(x)) => &x.program_id,
+                        UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(x)) => {
+                            &x.program_id
+                        }
+                    };
+                    program_id.parse().ok()
+                })
+                .collect(),
+        },
+        EncodedTransaction::Accounts(_) => None,
+        _ => {
+            let message = tx.decode()?.message;
+            let keys = message.static_account_keys();
+            message
+                .instructions()
+                .iter()
+                .map(|x| keys.get(usize::from(x.program_id_index)).copied())
+                .collect()
+        }
+    }
+}
+
+/// Configuration of the `getTransaction` calls that fetch transactions for
+/// [`events_in_transaction`] (versioned transactions included).
+pub(crate) fn transaction_config(commitment: CommitmentConfig) -> RpcTransactionConfig {
+    RpcTransactionConfig {
+        commitment: Some(commitment),
+        max_supported_transaction_version: Some(0),
+        ..Default::default()
+    }
+}
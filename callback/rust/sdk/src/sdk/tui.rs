@@ -0,0 +1,69 @@
+use super::EventKind;
+
+/// A terminal color of an [`EventKind::style`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "tui")))]
+#[non_exhaustive]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    /// Returns the ANSI SGR code of the foreground color (e.g. `32` for green).
+    pub const fn ansi_code(&self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+        }
+    }
+
+    /// Wraps the text into the ANSI escape sequences of this color
+    /// (the attributes are reset afterwards).
+    pub fn paint(&self, text: &str) -> String {
+        format!("\x1b[{}m{text}\x1b[0m", self.ansi_code())
+    }
+}
+
+impl EventKind {
+    /// Returns the color and the glyph of this event kind for terminal dashboards,
+    /// so that tools built on this crate share the visual conventions:
+    ///
+    /// | Kind                           | Color   | Glyph |
+    /// |--------------------------------|---------|-------|
+    /// | `Requested`, `RequestedAlt`    | yellow  | ⏳    |
+    /// | `Responded`                    | cyan    | 📨    |
+    /// | `Fulfilled`                    | green   | ✅    |
+    /// | `CalledBack`                   | blue    | 📞    |
+    /// | `Registered`                   | magenta | 🆕    |
+    /// | `CallbackUpdated`              | magenta | 🔧    |
+    /// | `Transferred`                  | magenta | 🔀    |
+    /// | `Withdrawn`                    | red     | 💸    |
+    /// | `Custom`                       | white   | ❔    |
+    ///
+    /// Glyphs are emoji, so every one of them takes two terminal columns.
+    #[cfg_attr(docsrs, doc(cfg(feature = "tui")))]
+    pub const fn style(&self) -> (Color, &'static str) {
+        match self {
+            EventKind::Requested | EventKind::RequestedAlt => (Color::Yellow, "⏳"),
+            EventKind::Responded => (Color::Cyan, "📨"),
+            EventKind::Fulfilled => (Color::Green, "✅"),
+            EventKind::CalledBack => (Color::Blue, "📞"),
+            EventKind::Registered => (Color::Magenta, "🆕"),
+            EventKind::CallbackUpdated => (Color::Magenta, "🔧"),
+            EventKind::Transferred => (Color::Magenta, "🔀"),
+            EventKind::Withdrawn => (Color::Red, "💸"),
+            EventKind::Custom => (Color::White, "❔"),
+        }
+    }
+}
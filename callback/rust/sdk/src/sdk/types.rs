@@ -0,0 +1,145 @@
+use core::fmt;
+use std::str::FromStr;
+
+use anchor_client::solana_sdk::bs58;
+
+/// Number of leading and trailing base58 characters kept by the alternate
+/// (`{:#}`) format of [`Seed`] and [`Randomness`].
+pub const ABBREVIATED_LEN: usize = 6;
+
+/// Maximum base58 length of the newtypes (that of [`Randomness`]).
+const MAX_BASE58_LEN: usize = 88;
+
+/// Appends the base58 encoding of the given bytes to the buffer.
+///
+/// Unlike `bs58::encode(bytes).into_string()` it does not allocate an intermediate
+/// string, so a buffer reused for formatting many values only allocates as it grows.
+/// The [`Seed`] and [`Randomness`] [`Display`] impls encode onto the stack and do not
+/// allocate at all.
+///
+/// [`Display`]: fmt::Display
+pub fn encode_base58_into(buf: &mut String, bytes: &[u8]) {
+    bs58::encode(bytes)
+        .onto(buf)
+        .expect("the string grows as needed");
+}
+
+/// An error returned when parsing [`Seed`] or [`Randomness`] from a string.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ParseBytesError {
+    #[error(transparent)]
+    Base58(#[from] bs58::decode::Error),
+    #[error("expected {expected} bytes, found {actual}")]
+    Length { expected: usize, actual: usize },
+}
+
+macro_rules! bytes_newtype {
+    ($(#[$meta:meta])* $name:ident, $len:literal) => {
+        $(#[$meta])*
+        ///
+        /// Displayed as base58 — use the alternate format (`{:#}`) to get it
+        /// abbreviated (see [`ABBREVIATED_LEN`]).
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(pub [u8; $len]);
+
+        impl $name {
+            pub const LEN: usize = $len;
+
+            /// Returns the underlying bytes.
+            pub const fn as_bytes(&self) -> &[u8; $len] {
+                &self.0
+            }
+
+            /// Encodes the bytes as base58 using the given alphabet
+            /// (e.g. [`bs58::Alphabet::RIPPLE`] or [`bs58::Alphabet::FLICKR`]).
+            ///
+            /// Only meant for interoperability with non-Solana tooling —
+            /// [`Display`] and [`FromStr`] always use the standard Solana (Bitcoin) alphabet,
+            /// and so does everything on-chain.
+            ///
+            /// [`Display`]: fmt::Display
+            pub fn to_base58_with(&self, alphabet: &bs58::Alphabet) -> String {
+                bs58::encode(&self.0).with_alphabet(alphabet).into_string()
+            }
+
+            /// Parses base58 encoded with the given alphabet
+            /// (see [`Self::to_base58_with`]).
+            pub fn from_base58_with(
+                s: &str,
+                alphabet: &bs58::Alphabet,
+            ) -> Result<Self, ParseBytesError> {
+                Self::try_from(bs58::decode(s).with_alphabet(alphabet).into_vec()?.as_slice())
+            }
+        }
+
+        impl From<[u8; $len]> for $name {
+            fn from(value: [u8; $len]) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for [u8; $len] {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = ParseBytesError;
+
+            fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+                value.try_into().map(Self).map_err(|_| ParseBytesError::Length {
+                    expected: $len,
+                    actual: value.len(),
+                })
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut buf = [0; MAX_BASE58_LEN];
+                let len = bs58::encode(&self.0)
+                    .onto(&mut buf[..])
+                    .expect("the buffer fits the encoding");
+                let encoded = core::str::from_utf8(&buf[..len]).expect("base58 is ASCII");
+                if f.alternate() && encoded.len() > 2 * ABBREVIATED_LEN {
+                    write!(
+                        f,
+                        "{}…{}",
+                        &encoded[..ABBREVIATED_LEN],
+                        &encoded[encoded.len() - ABBREVIATED_LEN..]
+                    )
+                } else {
+                    f.write_str(&encoded)
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseBytesError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::try_from(bs58::decode(s).into_vec()?.as_slice())
+            }
+        }
+    };
+}
+
+bytes_newtype!(
+    /// A randomness request seed.
+    Seed,
+    32
+);
+
+bytes_newtype!(
+    /// A generated randomness.
+    Randomness,
+    64
+);
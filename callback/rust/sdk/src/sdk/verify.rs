@@ -0,0 +1,372 @@
+use anchor_client::solana_client::{client_error::ClientError, nonblocking::rpc_client::RpcClient};
+use anchor_client::solana_sdk::bs58;
+use anchor_lang::prelude::Pubkey;
+use ed25519_dalek::{PublicKey, Signature};
+use serde::{Deserialize, Serialize};
+
+use super::{fetch_request_statuses, Event, Randomness, RequestStatus, Seed};
+use crate::{
+    events::{Fulfilled, Responded},
+    majority, xor_array,
+};
+
+/// Verifies that `randomness` is a valid oracle response for the given request.
+///
+/// Every response is an ed25519 signature of `client || seed` made by the fulfill
+/// authority, so it could be checked against the authority public key
+/// (see [`NetworkConfiguration::fulfill_authorities`]).
+///
+/// [`NetworkConfiguration::fulfill_authorities`]: crate::state::network_state::NetworkConfiguration::fulfill_authorities
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn verify_response(
+    authority: &Pubkey,
+    client: &Pubkey,
+    seed: &[u8; 32],
+    randomness: &[u8; 64],
+) -> bool {
+    let Ok(public_key) = PublicKey::from_bytes(authority.as_ref()) else {
+        return false;
+    };
+    let Ok(signature) = Signature::from_bytes(randomness) else {
+        return false;
+    };
+
+    public_key
+        .verify_strict(&response_message(client, seed), &signature)
+        .is_ok()
+}
+
+/// Returns the message signed by the fulfill authorities — `client || seed`.
+pub(crate) fn response_message(client: &Pubkey, seed: &[u8; 32]) -> [u8; 64] {
+    let mut message = [0_u8; 64];
+    message[..32].copy_from_slice(client.as_ref());
+    message[32..].copy_from_slice(seed);
+    message
+}
+
+/// An inconsistency found by [`check_misbehavior`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub enum Misbehavior {
+    /// The response is given by an authority that is not a fulfill authority.
+    UnknownAuthority { authority: Pubkey },
+    /// The response signature does not verify against the authority public key.
+    InvalidResponse { authority: Pubkey },
+    /// The authority responded more than once with different randomness.
+    ConflictingResponses { authority: Pubkey },
+    /// The request is fulfilled before the majority of valid responses is collected.
+    FulfilledWithoutQuorum { responses: usize, required: usize },
+    /// The fulfilled randomness differs from the one derived from the valid responses.
+    RandomnessMismatch {
+        expected: Randomness,
+        actual: Randomness,
+    },
+    /// The request is fulfilled more than once.
+    MultipleFulfillments,
+}
+
+/// The result of the [`check_misbehavior`] call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct MisbehaviorReport {
+    pub client: Pubkey,
+    pub seed: Seed,
+    /// Inconsistencies in the order of discovery.
+    pub inconsistencies: Vec<Misbehavior>,
+}
+
+impl MisbehaviorReport {
+    pub fn new(client: Pubkey, seed: Seed) -> Self {
+        Self {
+            client,
+            seed,
+            inconsistencies: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if no inconsistencies were found.
+    pub fn is_clean(&self) -> bool {
+        self.inconsistencies.is_empty()
+    }
+}
+
+/// Checks the oracle behavior for the given request.
+///
+/// *   `events` — all the events observed for the request in the emission order
+///     (events of other requests are ignored)
+/// *   `fulfill_authorities` — published oracle public keys
+///     (see [`NetworkConfiguration::fulfill_authorities`])
+///
+/// Every [`Responded`] event must verify against its authority (see [`verify_response`]),
+/// and every [`Fulfilled`] event must be preceded by the majority of valid responses
+/// and must carry the XOR of those responses.
+///
+/// [`NetworkConfiguration::fulfill_authorities`]: crate::state::network_state::NetworkConfiguration::fulfill_authorities
+/// [`Responded`]: crate::events::Responded
+/// [`Fulfilled`]: crate::events::Fulfilled
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub fn check_misbehavior<'a>(
+    client: &Pubkey,
+    seed: &[u8; 32],
+    events: impl IntoIterator<Item = &'a Event>,
+    fulfill_authorities: &[Pubkey],
+) -> MisbehaviorReport {
+    let mut report = MisbehaviorReport::new(*client, Seed(*seed));
+    let required = majority(fulfill_authorities.len());
+    let mut responses: Vec<(Pubkey, [u8; 64])> = Vec::new();
+    let mut fulfilled = false;
+
+    for event in events {
+        match event {
+            Event::Responded(ev) if ev.client == *client && ev.seed == *seed => {
+                if !fulfill_authorities.contains(&ev.authority) {
+                    report.inconsistencies.push(Misbehavior::UnknownAuthority {
+                        authority: ev.authority,
+                    });
+                } else if !verify_response(&ev.authority, client, seed, &ev.randomness) {
+                    report.inconsistencies.push(Misbehavior::InvalidResponse {
+                        authority: ev.authority,
+                    });
+                } else if let Some((_, randomness)) =
+                    responses.iter().find(|(x, _)| *x == ev.authority)
+                {
+                    if *randomness != ev.randomness {
+                        report
+                            .inconsistencies
+                            .push(Misbehavior::ConflictingResponses {
+                                authority: ev.authority,
+                            });
+                    }
+                } else {
+                    responses.push((ev.authority, ev.randomness));
+                }
+            }
+            Event::Fulfilled(ev) if ev.client == *client && ev.seed == *seed => {
+                if fulfilled {
+                    report
+                        .inconsistencies
+                        .push(Misbehavior::MultipleFulfillments);
+                    continue;
+                }
+                fulfilled = true;
+
+                if responses.len() < required {
+                    report
+                        .inconsistencies
+                        .push(Misbehavior::FulfilledWithoutQuorum {
+                            responses: responses.len(),
+                            required,
+                        });
+                    continue;
+                }
+
+                let mut expected = [0_u8; 64];
+                for (_, randomness) in &responses {
+                    xor_array(&mut expected, randomness);
+                }
+                if expected != ev.randomness {
+                    report
+                        .inconsistencies
+                        .push(Misbehavior::RandomnessMismatch {
+                            expected: Randomness(expected),
+                            actual: Randomness(ev.randomness),
+                        });
+                }
+            }
+            _ => (),
+        }
+    }
+
+    report
+}
+
+/// A mismatch between a [`Fulfilled`] event and the request account
+/// found by [`verify_against_account`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub enum AccountMismatch {
+    /// Neither request account exists.
+    NotFound,
+    /// The request is not fulfilled on-chain.
+    NotFulfilled { responses: usize },
+    /// The event randomness differs from the one stored in the account.
+    RandomnessMismatch {
+        /// The on-chain randomness.
+        expected: Randomness,
+        /// The event randomness.
+        actual: Randomness,
+    },
+}
+
+/// Cross-checks a [`Fulfilled`] event against the request account,
+/// e.g. to catch indexing bugs or spoofed logs.
+///
+/// Both [`RequestAccount`] and [`RequestAltAccount`] PDAs are looked up
+/// (see [`fetch_request_statuses`]) using the RPC client commitment — it should be
+/// at least the commitment the event is observed with, or a fresh fulfillment
+/// is reported as [`AccountMismatch::NotFulfilled`].
+///
+/// Returns `None` if the account is consistent with the event.
+///
+/// # Errors
+///
+/// Errors if the RPC call fails or the account is not a request account.
+///
+/// [`RequestAccount`]: crate::state::request::RequestAccount
+/// [`RequestAltAccount`]: crate::state::request_alt::RequestAltAccount
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub async fn verify_against_account(
+    rpc: &RpcClient,
+    fulfilled: &Fulfilled,
+    vrf_id: &Pubkey,
+) -> Result<Option<AccountMismatch>, ClientError> {
+    let statuses =
+        fetch_request_statuses(rpc, &[(fulfilled.client, Seed(fulfilled.seed))], vrf_id).await?;
+    let status = statuses
+        .into_iter()
+        .next()
+        .unwrap_or(RequestStatus::NotFound);
+
+    Ok(match status {
+        RequestStatus::NotFound => Some(AccountMismatch::NotFound),
+        RequestStatus::Pending { responses } => Some(AccountMismatch::NotFulfilled { responses }),
+        RequestStatus::Fulfilled { randomness } if randomness.0 != fulfilled.randomness => {
+            Some(AccountMismatch::RandomnessMismatch {
+                expected: randomness,
+                actual: Randomness(fulfilled.randomness),
+            })
+        }
+        RequestStatus::Fulfilled { .. } => None,
+    })
+}
+
+/// An oracle response of a [`VerificationPayload`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct VerificationResponse {
+    /// The fulfill authority (its ed25519 public key).
+    pub authority: String,
+    /// The response — the authority signature of `client || seed`.
+    pub signature: String,
+}
+
+/// Everything a third party needs to verify a fulfilled randomness independently,
+/// e.g. to publish on a transparency page (see [`Fulfilled::verification_payload`]).
+///
+/// The randomness is the XOR of the oracle responses, and every response is an ed25519
+/// signature of `client || seed` made by a fulfill authority (see [`verify_response`]),
+/// so the payload is self-checking with [`VerificationPayload::verify`].
+/// Addresses, the seed, the randomness and the signatures are base58 strings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+#[non_exhaustive]
+pub struct VerificationPayload {
+    pub client: String,
+    pub seed: String,
+    pub randomness: String,
+    /// Responses of the quorum that fulfilled the request.
+    pub responses: Vec<VerificationResponse>,
+}
+
+impl VerificationPayload {
+    /// Verifies the payload: every response is a valid signature of its authority,
+    /// the authorities are distinct, and the XOR of the responses is the randomness.
+    ///
+    /// This does not prove the authorities are the oracle ones —
+    /// see [`VerificationPayload::verify_quorum`].
+    pub fn verify(&self) -> bool {
+        self.decode()
+            .is_some_and(|(client, seed, randomness, responses)| {
+                let mut expected = [0_u8; 64];
+                for (i, (authority, signature)) in responses.iter().enumerate() {
+                    if responses[..i].iter().any(|(x, _)| x == authority)
+                        || !verify_response(authority, &client, &seed, signature)
+                    {
+                        return false;
+                    }
+                    xor_array(&mut expected, signature);
+                }
+                !responses.is_empty() && expected == randomness
+            })
+    }
+
+    /// Same as [`VerificationPayload::verify`], but also checks that the responses
+    /// are given by the majority of the published fulfill authorities
+    /// (see [`NetworkConfiguration::fulfill_authorities`]).
+    ///
+    /// [`NetworkConfiguration::fulfill_authorities`]: crate::state::network_state::NetworkConfiguration::fulfill_authorities
+    pub fn verify_quorum(&self, fulfill_authorities: &[Pubkey]) -> bool {
+        let Some((_, _, _, responses)) = self.decode() else {
+            return false;
+        };
+        self.verify()
+            && responses.len() >= majority(fulfill_authorities.len())
+            && responses
+                .iter()
+                .all(|(x, _)| fulfill_authorities.contains(x))
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn decode(&self) -> Option<(Pubkey, [u8; 32], [u8; 64], Vec<(Pubkey, [u8; 64])>)> {
+        fn bytes<const N: usize>(s: &str) -> Option<[u8; N]> {
+            bs58::decode(s).into_vec().ok()?.try_into().ok()
+        }
+
+        let responses = self
+            .responses
+            .iter()
+            .map(|x| {
+                Some((
+                    Pubkey::new_from_array(bytes(&x.authority)?),
+                    bytes(&x.signature)?,
+                ))
+            })
+            .collect::<Option<_>>()?;
+        Some((
+            Pubkey::new_from_array(bytes(&self.client)?),
+            bytes(&self.seed)?,
+            bytes(&self.randomness)?,
+            responses,
+        ))
+    }
+}
+
+impl Fulfilled {
+    /// Bundles this fulfillment with the oracle responses into a [`VerificationPayload`].
+    ///
+    /// *   `responses` — the [`Responded`] events of the request (events of other
+    ///     requests are ignored, as well as repeated responses of an authority)
+    ///
+    /// The payload is not checked here — call [`VerificationPayload::verify`].
+    #[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+    pub fn verification_payload<'a>(
+        &self,
+        responses: impl IntoIterator<Item = &'a Responded>,
+    ) -> VerificationPayload {
+        let mut seen = Vec::new();
+        let responses = responses
+            .into_iter()
+            .filter(|x| x.client == self.client && x.seed == self.seed)
+            .filter(|x| {
+                let new = !seen.contains(&x.authority);
+                seen.push(x.authority);
+                new
+            })
+            .map(|x| VerificationResponse {
+                authority: x.authority.to_string(),
+                signature: Randomness(x.randomness).to_string(),
+            })
+            .collect();
+
+        VerificationPayload {
+            client: self.client.to_string(),
+            seed: Seed(self.seed).to_string(),
+            randomness: Randomness(self.randomness).to_string(),
+            responses,
+        }
+    }
+}
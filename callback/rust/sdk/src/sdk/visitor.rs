@@ -0,0 +1,61 @@
+use super::{CustomEvent, Event};
+use crate::events::{
+    CallbackUpdated, CalledBack, Fulfilled, Registered, Requested, RequestedAlt, Responded,
+    Transferred, Withdrawn,
+};
+
+/// A handler of events dispatched by [`Event::accept`].
+///
+/// Every method is a no-op by default, so implement only the handlers you care about —
+/// unlike an exhaustive `match`, a visitor keeps compiling as events are added.
+///
+/// ```
+/// # use orao_solana_vrf_cb::{events::Fulfilled, sdk::{Event, EventVisitor}};
+/// #[derive(Default)]
+/// struct Fulfillments(usize);
+///
+/// impl EventVisitor for Fulfillments {
+///     fn visit_fulfilled(&mut self, _: &Fulfilled) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// # let events: Vec<Event> = vec![];
+/// let mut fulfillments = Fulfillments::default();
+/// for event in &events {
+///     event.accept(&mut fulfillments);
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "sdk")))]
+pub trait EventVisitor {
+    fn visit_callback_updated(&mut self, _event: &CallbackUpdated) {}
+    fn visit_called_back(&mut self, _event: &CalledBack) {}
+    fn visit_fulfilled(&mut self, _event: &Fulfilled) {}
+    fn visit_registered(&mut self, _event: &Registered) {}
+    fn visit_requested(&mut self, _event: &Requested) {}
+    fn visit_requested_alt(&mut self, _event: &RequestedAlt) {}
+    fn visit_responded(&mut self, _event: &Responded) {}
+    fn visit_transferred(&mut self, _event: &Transferred) {}
+    fn visit_withdrawn(&mut self, _event: &Withdrawn) {}
+    /// Visits an event unknown to the program — an event of a program fork
+    /// (see [`register_custom_event`](super::register_custom_event)).
+    fn visit_custom(&mut self, _event: &CustomEvent) {}
+}
+
+impl Event {
+    /// Dispatches this event to the corresponding [`EventVisitor`] method.
+    pub fn accept(&self, visitor: &mut impl EventVisitor) {
+        match self {
+            Event::CallbackUpdated(ev) => visitor.visit_callback_updated(ev),
+            Event::CalledBack(ev) => visitor.visit_called_back(ev),
+            Event::Fulfilled(ev) => visitor.visit_fulfilled(ev),
+            Event::Registered(ev) => visitor.visit_registered(ev),
+            Event::Requested(ev) => visitor.visit_requested(ev),
+            Event::RequestedAlt(ev) => visitor.visit_requested_alt(ev),
+            Event::Responded(ev) => visitor.visit_responded(ev),
+            Event::Transferred(ev) => visitor.visit_transferred(ev),
+            Event::Withdrawn(ev) => visitor.visit_withdrawn(ev),
+            Event::Custom(ev) => visitor.visit_custom(ev),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use super::{Event, TaggedEvent};
+
+/// The recommended HTTP header name for [`WebhookPayload::signature`].
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Orao-Vrf-Signature";
+
+/// Prefix of the [`WebhookPayload::signature`] value.
+const SIGNATURE_PREFIX: &str = "sha256=";
+
+/// An event prepared to be pushed to a webhook.
+///
+/// The signing scheme:
+///
+/// *   the body is the event JSON in the internally tagged form (see [`TaggedEvent`])
+/// *   the signature is HMAC-SHA256 of the exact body bytes keyed with the shared secret,
+///     given as `sha256=<lowercase hex>` (see [`WEBHOOK_SIGNATURE_HEADER`])
+///
+/// The receiver must verify the signature against the raw body before parsing it
+/// (see [`verify_webhook_signature`]). Note that the scheme does not protect against
+/// replays — deduplicate deliveries if that matters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "webhook")))]
+#[non_exhaustive]
+pub struct WebhookPayload {
+    /// JSON body of the request.
+    pub body: String,
+    /// The signature header value.
+    pub signature: String,
+}
+
+impl WebhookPayload {
+    /// Serializes and signs the given event.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the event fails to serialize.
+    pub fn new(event: &Event, secret: &[u8]) -> serde_json::Result<Self> {
+        let body = serde_json::to_string(&TaggedEvent(event.clone()))?;
+        let signature = format!(
+            "{SIGNATURE_PREFIX}{}",
+            hex(&mac(secret, body.as_bytes()).finalize().into_bytes())
+        );
+        Ok(Self { body, signature })
+    }
+}
+
+/// Verifies the signature header value of a webhook request (see [`WebhookPayload`]).
+///
+/// The comparison is constant-time.
+#[cfg_attr(docsrs, doc(cfg(feature = "webhook")))]
+pub fn verify_webhook_signature(body: &[u8], signature: &str, secret: &[u8]) -> bool {
+    let Some(expected) = signature
+        .strip_prefix(SIGNATURE_PREFIX)
+        .and_then(|x| unhex(x.trim()))
+    else {
+        return false;
+    };
+    mac(secret, body).verify_slice(&expected).is_ok()
+}
+
+fn mac(secret: &[u8], body: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any size");
+    mac.update(body);
+    mac
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|x| format!("{x:02x}")).collect()
+}
+
+fn unhex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) || !s.is_ascii() {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
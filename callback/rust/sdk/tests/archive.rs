@@ -0,0 +1,81 @@
+//! Tests reading events from (compressed) log archives.
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Responded},
+    sdk::{events_in_log_lines, Event},
+};
+
+fn events() -> Vec<Event> {
+    let client = Pubkey::new_unique();
+    let seed = [1; 32];
+    vec![
+        Responded::new(Pubkey::new_unique(), client, seed, [2; 64]).into(),
+        Fulfilled::new(client, seed, [2; 64]).into(),
+        Fulfilled::new(Pubkey::new_unique(), [3; 32], [4; 64]).into(),
+    ]
+}
+
+/// Logs of two transactions, the first one is truncated.
+fn archive(events: &[Event]) -> Vec<u8> {
+    let vrf = orao_solana_vrf_cb::id();
+    let foreign = Event::from(Fulfilled::new(Pubkey::new_unique(), [5; 32], [6; 64]));
+    let lines = [
+        format!("Program {vrf} invoke [1]"),
+        events[0].to_program_data_log(),
+        events[1].to_program_data_log(),
+        "Log truncated".to_string(),
+        String::new(),
+        "Program 11111111111111111111111111111111 invoke [1]".to_string(),
+        foreign.to_program_data_log(),
+        format!("Program {vrf} invoke [2]"),
+        events[2].to_program_data_log(),
+        format!("Program {vrf} success"),
+        "Program 11111111111111111111111111111111 success".to_string(),
+    ];
+    lines.join("\n").into_bytes()
+}
+
+fn parse(iter: impl Iterator<Item = std::io::Result<Event>>) -> Vec<Event> {
+    iter.collect::<Result<_, _>>().unwrap()
+}
+
+#[test]
+fn plain_lines() {
+    let events = events();
+    assert_eq!(parse(events_in_log_lines(&archive(&events)[..])), events);
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn zstd_archive() {
+    let events = events();
+    let compressed = zstd::encode_all(&archive(&events)[..], 0).unwrap();
+    assert_eq!(
+        parse(orao_solana_vrf_cb::sdk::events_in_zstd_archive(&compressed[..]).unwrap()),
+        events
+    );
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn gzip_archive() {
+    use std::io::Write;
+
+    let events = events();
+    let archive = archive(&events);
+    let mut compressed = Vec::new();
+    // a multi-member archive
+    for part in archive.chunks(archive.len() / 2 + 1) {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(part).unwrap();
+        compressed.extend(encoder.finish().unwrap());
+    }
+    assert_eq!(
+        parse(orao_solana_vrf_cb::sdk::events_in_gzip_archive(
+            &compressed[..]
+        )),
+        events
+    );
+}
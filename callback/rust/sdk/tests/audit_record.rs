@@ -0,0 +1,106 @@
+//! Tests of the [`AuditRecord`] built from a tracked lifecycle.
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{CalledBack, Fulfilled, Requested, Responded},
+    sdk::{AuditRecord, Event, LifecycleTracker, Randomness, ResolvedEvent, Seed},
+};
+
+fn resolved(signature: Signature, slot: u64, index: usize, event: Event) -> ResolvedEvent {
+    ResolvedEvent::new(signature, slot, Some(slot as i64 * 2), index, index, event)
+}
+
+#[test]
+fn record_of_fulfilled_request() {
+    let client = Pubkey::new_unique();
+    let seed = [1; 32];
+    let request_tx = Signature::new_unique();
+    let response_tx = Signature::new_unique();
+    let fulfill_tx = Signature::new_unique();
+
+    let mut tracker = LifecycleTracker::new();
+    tracker.extend([
+        resolved(
+            request_tx,
+            10,
+            0,
+            Requested::new(client, seed, None, true).into(),
+        ),
+        resolved(
+            response_tx,
+            15,
+            0,
+            Responded::new(Pubkey::new_unique(), client, seed, [2; 64]).into(),
+        ),
+        resolved(
+            fulfill_tx,
+            20,
+            0,
+            Responded::new(Pubkey::new_unique(), client, seed, [3; 64]).into(),
+        ),
+        resolved(
+            fulfill_tx,
+            20,
+            1,
+            Fulfilled::new(client, seed, [4; 64]).into(),
+        ),
+        resolved(
+            fulfill_tx,
+            20,
+            2,
+            CalledBack::new(Pubkey::new_unique()).into(),
+        ),
+    ]);
+
+    let record = tracker.get(&client, &Seed(seed)).unwrap().to_audit_record();
+    assert_eq!(record.schema_version, AuditRecord::SCHEMA_VERSION);
+    assert_eq!(record.client, client.to_string());
+    assert_eq!(record.seed, Seed(seed).to_string());
+    assert_eq!(record.randomness, Some(Randomness([4; 64]).to_string()));
+    assert_eq!(record.request_signature, Some(request_tx.to_string()));
+    assert_eq!(record.request_slot, Some(10));
+    assert_eq!(record.request_time, Some(20));
+    assert_eq!(record.response_count, 2);
+    assert_eq!(record.first_response_slot, Some(15));
+    assert_eq!(record.last_response_slot, Some(20));
+    assert_eq!(record.fulfill_signature, Some(fulfill_tx.to_string()));
+    assert_eq!(record.fulfill_slot, Some(20));
+    assert_eq!(record.fulfill_time, Some(40));
+    assert_eq!(record.callback_defined, Some(false));
+    assert_eq!(record.callback_override, Some(true));
+    assert!(record.called_back);
+    assert_eq!(record.called_back_slot, Some(20));
+    assert_eq!(record.callback_outcome.as_deref(), Some("invoked"));
+
+    let json = serde_json::to_value(&record).unwrap();
+    assert!(json
+        .as_object()
+        .unwrap()
+        .values()
+        .all(|x| !x.is_object() && !x.is_array()));
+    assert_eq!(serde_json::from_value::<AuditRecord>(json).unwrap(), record);
+}
+
+#[test]
+fn record_of_pending_request() {
+    let client = Pubkey::new_unique();
+    let mut tracker = LifecycleTracker::new();
+    tracker.extend([resolved(
+        Signature::new_unique(),
+        10,
+        0,
+        Requested::new(client, [1; 32], None, false).into(),
+    )]);
+
+    let record = tracker
+        .get(&client, &Seed([1; 32]))
+        .unwrap()
+        .to_audit_record();
+    assert_eq!(record.randomness, None);
+    assert_eq!(record.response_count, 0);
+    assert_eq!(record.fulfill_slot, None);
+    assert!(!record.called_back);
+    assert_eq!(record.callback_outcome, None);
+}
@@ -0,0 +1,33 @@
+//! Tests of the base58 encoding of [`Seed`] and [`Randomness`].
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::bs58;
+use orao_solana_vrf_cb::sdk::{encode_base58_into, Randomness, Seed, ABBREVIATED_LEN};
+
+#[test]
+fn display_matches_bs58() {
+    for bytes in [[0; 64], [0xff; 64], core::array::from_fn(|i| i as u8)] {
+        let randomness = Randomness(bytes);
+        let expected = bs58::encode(bytes).into_string();
+        assert_eq!(randomness.to_string(), expected);
+        assert_eq!(
+            format!("{randomness:#}"),
+            format!(
+                "{}…{}",
+                &expected[..ABBREVIATED_LEN],
+                &expected[expected.len() - ABBREVIATED_LEN..]
+            )
+        );
+    }
+    assert_eq!(
+        Seed([0xff; 32]).to_string(),
+        bs58::encode([0xff; 32]).into_string()
+    );
+}
+
+#[test]
+fn encode_into_appends() {
+    let mut buf = String::from("seed: ");
+    encode_base58_into(&mut buf, &[0xff; 32]);
+    assert_eq!(buf, format!("seed: {}", Seed([0xff; 32])));
+}
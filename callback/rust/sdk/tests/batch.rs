@@ -0,0 +1,59 @@
+//! Tests of the [`batch_by_slot`] stream adapter.
+#![cfg(feature = "sdk")]
+
+use std::time::Duration;
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use futures::StreamExt;
+use orao_solana_vrf_cb::{events, sdk::*};
+
+fn resolved(slot: u64) -> ResolvedEvent {
+    let event = Event::from(events::Requested::new(
+        Pubkey::new_unique(),
+        [1; 32],
+        None,
+        false,
+    ));
+    ResolvedEvent::new(Signature::new_unique(), slot, None, 0, 0, event)
+}
+
+fn slots(batches: &[Vec<ResolvedEvent>]) -> Vec<Vec<u64>> {
+    batches
+        .iter()
+        .map(|x| x.iter().map(|x| x.slot).collect())
+        .collect()
+}
+
+#[tokio::test]
+async fn batched_by_slot() {
+    let events = [1, 1, 2, 3, 3, 3].map(resolved);
+    let batches = batch_by_slot(futures::stream::iter(events), None)
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(slots(&batches), vec![vec![1, 1], vec![2], vec![3, 3, 3]]);
+}
+
+#[tokio::test]
+async fn empty_stream() {
+    let batches = batch_by_slot(futures::stream::empty(), None)
+        .collect::<Vec<_>>()
+        .await;
+    assert!(batches.is_empty());
+}
+
+#[tokio::test]
+async fn flushed_on_timeout() {
+    let (sender, receiver) = futures::channel::mpsc::unbounded();
+    let mut batches = Box::pin(batch_by_slot(receiver, Some(Duration::from_millis(50))));
+
+    sender.unbounded_send(resolved(1)).unwrap();
+    sender.unbounded_send(resolved(1)).unwrap();
+    assert_eq!(slots(&[batches.next().await.unwrap()]), vec![vec![1, 1]]);
+
+    sender.unbounded_send(resolved(1)).unwrap();
+    drop(sender);
+    assert_eq!(slots(&[batches.next().await.unwrap()]), vec![vec![1]]);
+    assert!(batches.next().await.is_none());
+}
@@ -0,0 +1,114 @@
+//! Tests [`events_in_block`] on a `blockSubscribe` notification.
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_client::rpc_response::RpcBlockUpdate;
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested},
+    sdk::{events_in_block_update, Event},
+};
+use serde_json::{json, Value};
+
+const SLOT: u64 = 42;
+
+fn transaction(signature: Signature, failed: bool, events: &[Event]) -> Value {
+    let vrf = orao_solana_vrf_cb::id();
+    let mut logs = vec![];
+    for event in events {
+        logs.push(format!("Program {vrf} invoke [1]"));
+        logs.push(event.to_program_data_log());
+        logs.push(format!("Program {vrf} success"));
+    }
+    let err = failed.then(|| json!({"InstructionError": [0, {"Custom": 1}]}));
+    json!({
+        "transaction": {
+            "signatures": [signature.to_string()],
+            "message": {
+                "header": {
+                    "numRequiredSignatures": 1,
+                    "numReadonlySignedAccounts": 0,
+                    "numReadonlyUnsignedAccounts": 0
+                },
+                "accountKeys": [],
+                "recentBlockhash": "11111111111111111111111111111111",
+                "instructions": []
+            }
+        },
+        "meta": {
+            "err": err,
+            "status": if failed { json!({"Err": err}) } else { json!({"Ok": null}) },
+            "fee": 5000,
+            "preBalances": [],
+            "postBalances": [],
+            "logMessages": logs
+        }
+    })
+}
+
+fn update(block: Value) -> RpcBlockUpdate {
+    serde_json::from_value(json!({ "slot": SLOT, "block": block, "err": null })).unwrap()
+}
+
+#[test]
+fn skips_failed_transactions() {
+    let client = Pubkey::new_unique();
+    let requested: Event = Requested::new(client, [1; 32], None, false).into();
+    let fulfilled: Event = Fulfilled::new(client, [2; 32], [3; 64]).into();
+    let (first, failed, second) = (
+        Signature::new_unique(),
+        Signature::new_unique(),
+        Signature::new_unique(),
+    );
+
+    let update = update(json!({
+        "previousBlockhash": "11111111111111111111111111111111",
+        "blockhash": "11111111111111111111111111111111",
+        "parentSlot": SLOT - 1,
+        "transactions": [
+            transaction(first, false, &[requested.clone(), fulfilled.clone()]),
+            transaction(failed, true, std::slice::from_ref(&requested)),
+            transaction(second, false, std::slice::from_ref(&fulfilled)),
+        ],
+        "blockTime": 1_700_000_000,
+        "blockHeight": null
+    }));
+
+    let events = events_in_block_update(&update)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        events
+            .iter()
+            .map(|x| (x.signature, x.index, x.event.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (first, 0, requested),
+            (first, 1, fulfilled.clone()),
+            (second, 0, fulfilled),
+        ]
+    );
+    assert!(events
+        .iter()
+        .all(|x| x.slot == SLOT && x.block_time == Some(1_700_000_000)));
+}
+
+#[test]
+fn block_without_transactions() {
+    let update = update(json!({
+        "previousBlockhash": "11111111111111111111111111111111",
+        "blockhash": "11111111111111111111111111111111",
+        "parentSlot": SLOT - 1,
+        "blockTime": null,
+        "blockHeight": null
+    }));
+    assert_eq!(events_in_block_update(&update).count(), 0);
+
+    let update = serde_json::from_value::<RpcBlockUpdate>(json!({
+        "slot": SLOT,
+        "block": null,
+        "err": "BlockStoreError"
+    }))
+    .unwrap();
+    assert_eq!(events_in_block_update(&update).count(), 0);
+}
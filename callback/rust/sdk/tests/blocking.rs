@@ -0,0 +1,82 @@
+//! Tests of the blocking helpers using the mock RPC.
+#![cfg(feature = "blocking")]
+
+use std::{collections::HashMap, time::Duration};
+
+use anchor_client::solana_client::{rpc_client::RpcClient, rpc_request::RpcRequest};
+use anchor_lang::{prelude::Pubkey, AccountSerialize};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use orao_solana_vrf_cb::{
+    sdk::{
+        fetch_all_events_blocking, fetch_request_status_blocking, wait_for_fulfillment_blocking,
+        Randomness, RequestStatus,
+    },
+    state::request::{Fulfilled, RequestAccount, RequestState},
+};
+
+fn rpc_with_account(account: &impl AccountSerialize) -> RpcClient {
+    let mut data = vec![];
+    account.try_serialize(&mut data).unwrap();
+    let response = serde_json::json!({
+        "context": { "slot": 1 },
+        "value": {
+            "lamports": 1_000_000,
+            "data": [STANDARD.encode(&data), "base64"],
+            "owner": orao_solana_vrf_cb::id().to_string(),
+            "executable": false,
+            "rentEpoch": 0,
+            "space": data.len(),
+        },
+    });
+    RpcClient::new_mock_with_mocks(
+        "succeeds".to_string(),
+        HashMap::from([(RpcRequest::GetAccountInfo, response)]),
+    )
+}
+
+#[test]
+fn fulfilled_request() {
+    let state = RequestState::Fulfilled(Fulfilled::new([3; 64], None));
+    let account = RequestAccount::new(255, 1, Pubkey::new_unique(), [2; 32], state);
+    let request = Pubkey::new_unique();
+
+    // Mocked responses are consumed by the first call.
+    assert!(
+        fetch_request_status_blocking(&rpc_with_account(&account), &request)
+            .unwrap()
+            .is_fulfilled()
+    );
+    assert_eq!(
+        wait_for_fulfillment_blocking(&rpc_with_account(&account), &request, Duration::ZERO)
+            .unwrap(),
+        Some(Randomness([3; 64]))
+    );
+}
+
+#[test]
+fn missing_request_times_out() {
+    let rpc = RpcClient::new_mock("succeeds".to_string());
+    let request = Pubkey::new_unique();
+
+    assert_eq!(
+        fetch_request_status_blocking(&rpc, &request).unwrap(),
+        RequestStatus::NotFound
+    );
+    assert_eq!(
+        wait_for_fulfillment_blocking(&rpc, &request, Duration::from_millis(10)).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn transactions_without_logs_have_no_events() {
+    let rpc = RpcClient::new_mock("succeeds".to_string());
+    assert!(fetch_all_events_blocking(&rpc, None).unwrap().is_empty());
+}
+
+#[test]
+fn rpc_errors_are_propagated() {
+    let rpc = RpcClient::new_mock("fails".to_string());
+    assert!(fetch_all_events_blocking(&rpc, None).is_err());
+    assert!(fetch_request_status_blocking(&rpc, &Pubkey::new_unique()).is_err());
+}
@@ -0,0 +1,99 @@
+//! Tests of the [`CallbackInfo`] accessors.
+#![cfg(feature = "sdk")]
+
+use anchor_lang::{prelude::Pubkey, AnchorDeserialize, AnchorSerialize};
+use orao_solana_vrf_cb::{
+    events::{Requested, RequestedAlt},
+    sdk::{CallbackAccount, CallbackInfo},
+    state::client::{ValidatedCallback, ValidatedCallbackAlt},
+};
+
+#[test]
+fn requested_callback() {
+    let program = Pubkey::new_unique();
+    let writable = Pubkey::new_unique();
+    let readonly = Pubkey::new_unique();
+
+    let mut data = vec![];
+    (
+        vec![(writable, true), (readonly, false)],
+        vec![0xab_u8, 0x01],
+    )
+        .serialize(&mut data)
+        .unwrap();
+    let callback = ValidatedCallback::try_from_slice(&data).unwrap();
+    let requested = Requested::new(Pubkey::new_unique(), [1; 32], Some(callback), true);
+
+    let info = requested.callback_info(program).unwrap();
+    assert_eq!(info.program, program);
+    assert_eq!(info.data, vec![0xab, 0x01]);
+    assert_eq!(
+        info.accounts,
+        vec![
+            CallbackAccount::Plain {
+                pubkey: writable,
+                is_writable: true
+            },
+            CallbackAccount::Plain {
+                pubkey: readonly,
+                is_writable: false
+            },
+        ]
+    );
+    assert_eq!(
+        info.to_string(),
+        format!(
+            "calls {program} with data 0xab01 and remaining accounts: \
+             {writable} (writable), {readonly} (read-only)"
+        )
+    );
+    assert_eq!(
+        format!("{info:#}"),
+        format!(
+            "calls {program} with data 0xab01 and remaining accounts:\
+             \n  {writable} (writable)\n  {readonly} (read-only)"
+        )
+    );
+}
+
+#[test]
+fn no_callback() {
+    let requested = Requested::new(Pubkey::new_unique(), [1; 32], None, false);
+    assert!(requested.callback_info(Pubkey::new_unique()).is_none());
+}
+
+#[test]
+fn alt_callback() {
+    let table = Pubkey::new_unique();
+
+    // accounts_hash, [Lookup { table_index, address_index, is_writable }], data
+    let mut data = [7_u8; 32].to_vec();
+    data.extend(2_u32.to_le_bytes());
+    data.extend([1, 0, 3, 1]);
+    data.extend([1, 5, 6, 0]);
+    data.extend(0_u32.to_le_bytes());
+    let callback = ValidatedCallbackAlt::try_from_slice(&data).unwrap();
+    let requested = RequestedAlt::new(Pubkey::new_unique(), [1; 32], Some(callback), vec![table]);
+
+    let info: CallbackInfo = requested.callback_info(Pubkey::new_unique()).unwrap();
+    assert_eq!(
+        info.accounts,
+        vec![
+            CallbackAccount::Lookup {
+                table: Some(table),
+                table_index: 0,
+                address_index: 3,
+                is_writable: true
+            },
+            CallbackAccount::Lookup {
+                table: None,
+                table_index: 5,
+                address_index: 6,
+                is_writable: false
+            },
+        ]
+    );
+    assert!(info.to_string().ends_with(&format!(
+        "and remaining accounts: {table}[3] (writable), <table #5>[6] (read-only)"
+    )));
+}
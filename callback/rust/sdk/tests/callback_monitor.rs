@@ -0,0 +1,110 @@
+//! Tests of [`CallbackProgramMonitor`].
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{CalledBack, Fulfilled, Registered},
+    sdk::{CallbackProgramMonitor, Event, ResolvedEvent},
+};
+
+fn resolved(
+    signature: Signature,
+    slot: u64,
+    index: usize,
+    event: impl Into<Event>,
+) -> ResolvedEvent {
+    ResolvedEvent::new(signature, slot, None, index, index, event.into())
+}
+
+#[test]
+fn reports_changes() {
+    let client = Pubkey::new_unique();
+    let (program, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let registered =
+        |program| Registered::new(Pubkey::new_unique(), program, Pubkey::new_unique(), client);
+
+    let mut monitor = CallbackProgramMonitor::new();
+    assert_eq!(
+        monitor.push(&resolved(
+            Signature::new_unique(),
+            1,
+            0,
+            registered(program)
+        )),
+        None
+    );
+    assert_eq!(monitor.program(&client), Some(program));
+
+    // the callback calls the registered program
+    let fulfill = Signature::new_unique();
+    assert_eq!(
+        monitor.push(&resolved(
+            fulfill,
+            2,
+            0,
+            Fulfilled::new(client, [1; 32], [0; 64])
+        )),
+        None
+    );
+    assert_eq!(
+        monitor.push(&resolved(fulfill, 2, 1, CalledBack::new(program))),
+        None
+    );
+
+    // the callback calls another program
+    let fulfill = Signature::new_unique();
+    monitor.push(&resolved(
+        fulfill,
+        3,
+        0,
+        Fulfilled::new(client, [2; 32], [0; 64]),
+    ));
+    let changed = monitor
+        .push(&resolved(fulfill, 3, 1, CalledBack::new(other)))
+        .unwrap();
+    assert_eq!(
+        (
+            changed.client,
+            changed.old,
+            changed.new,
+            changed.slot,
+            changed.signature
+        ),
+        (client, program, other, 3, fulfill)
+    );
+    assert_eq!(monitor.program(&client), Some(other));
+
+    // a re-registration with the original program
+    let signature = Signature::new_unique();
+    let changed = monitor
+        .push(&resolved(signature, 4, 0, registered(program)))
+        .unwrap();
+    assert_eq!(
+        (changed.old, changed.new, changed.slot),
+        (other, program, 4)
+    );
+}
+
+#[test]
+fn unattributed_callbacks_are_ignored() {
+    let client = Pubkey::new_unique();
+    let program = Pubkey::new_unique();
+    let mut monitor = CallbackProgramMonitor::new().with_program(client, program);
+
+    monitor.push(&resolved(
+        Signature::new_unique(),
+        1,
+        0,
+        Fulfilled::new(client, [1; 32], [0; 64]),
+    ));
+    // a CalledBack event of another transaction
+    let callback = resolved(
+        Signature::new_unique(),
+        1,
+        0,
+        CalledBack::new(Pubkey::new_unique()),
+    );
+    assert_eq!(monitor.push(&callback), None);
+    assert_eq!(monitor.program(&client), Some(program));
+}
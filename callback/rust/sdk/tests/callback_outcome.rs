@@ -0,0 +1,75 @@
+//! Tests of the [`RequestLifecycle::callback_outcome`].
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::{prelude::Pubkey, AnchorDeserialize, AnchorSerialize};
+use orao_solana_vrf_cb::{
+    events::{CalledBack, Fulfilled, Requested},
+    sdk::{CallbackOutcome, Event, LifecycleTracker, ResolvedEvent, Seed},
+    state::client::ValidatedCallback,
+};
+
+fn resolved(signature: Signature, index: usize, event: Event) -> ResolvedEvent {
+    ResolvedEvent::new(signature, 1, None, index, index, event)
+}
+
+fn outcome(with_callback: bool, called_back: bool) -> Option<CallbackOutcome> {
+    let client = Pubkey::new_unique();
+    let seed = [1; 32];
+    let callback = with_callback.then(|| {
+        let mut data = vec![];
+        (Vec::<(Pubkey, bool)>::new(), vec![1_u8])
+            .serialize(&mut data)
+            .unwrap();
+        ValidatedCallback::try_from_slice(&data).unwrap()
+    });
+
+    let mut tracker = LifecycleTracker::new();
+    tracker.push(resolved(
+        Signature::new_unique(),
+        0,
+        Requested::new(client, seed, callback, false).into(),
+    ));
+    let fulfill_tx = Signature::new_unique();
+    tracker.push(resolved(
+        fulfill_tx,
+        0,
+        Fulfilled::new(client, seed, [2; 64]).into(),
+    ));
+    if called_back {
+        tracker.push(resolved(
+            fulfill_tx,
+            1,
+            CalledBack::new(Pubkey::new_unique()).into(),
+        ));
+    }
+
+    tracker
+        .get(&client, &Seed(seed))
+        .unwrap()
+        .callback_outcome()
+}
+
+#[test]
+fn callback_outcomes() {
+    assert_eq!(outcome(false, false), Some(CallbackOutcome::NotDefined));
+    assert_eq!(outcome(true, true), Some(CallbackOutcome::Invoked));
+    assert_eq!(outcome(true, false), Some(CallbackOutcome::Skipped));
+}
+
+#[test]
+fn pending_request_has_no_outcome() {
+    let client = Pubkey::new_unique();
+    let tracker = LifecycleTracker::from_iter([resolved(
+        Signature::new_unique(),
+        0,
+        Requested::new(client, [1; 32], None, false).into(),
+    )]);
+    assert_eq!(
+        tracker
+            .get(&client, &Seed([1; 32]))
+            .unwrap()
+            .callback_outcome(),
+        None
+    );
+}
@@ -0,0 +1,25 @@
+//! Tests the [`Event::callback_override`] semantics of both request kinds.
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested, RequestedAlt},
+    sdk::Event,
+};
+
+#[test]
+fn request_kinds() {
+    let client = Pubkey::new_unique();
+    let requested =
+        |callback_override| Event::from(Requested::new(client, [1; 32], None, callback_override));
+    assert_eq!(requested(false).callback_override(), Some(false));
+    assert_eq!(requested(true).callback_override(), Some(true));
+
+    // ALT requests never get the client-level callback.
+    let alt = RequestedAlt::new(client, [1; 32], None, vec![]);
+    assert!(alt.callback_override());
+    assert_eq!(Event::from(alt).callback_override(), Some(true));
+
+    let fulfilled = Event::from(Fulfilled::new(client, [1; 32], [2; 64]));
+    assert_eq!(fulfilled.callback_override(), None);
+}
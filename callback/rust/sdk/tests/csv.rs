@@ -0,0 +1,75 @@
+//! Tests of the CSV export.
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, RequestedAlt, Withdrawn},
+    sdk::{write_csv, Event, EventRow, Randomness, ResolvedEvent, Seed},
+};
+
+fn resolved(signature: Signature, index: usize, event: impl Into<Event>) -> ResolvedEvent {
+    ResolvedEvent::new(
+        signature,
+        42,
+        Some(1_700_000_000),
+        index,
+        index,
+        event.into(),
+    )
+}
+
+#[test]
+fn header_and_rows() {
+    let (owner, client, table) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let signature = Signature::new_unique();
+    let events = [
+        resolved(signature, 0, Withdrawn::new(owner, client, 1_500)),
+        resolved(signature, 1, Fulfilled::new(client, [1; 32], [2; 64])),
+        resolved(
+            signature,
+            2,
+            RequestedAlt::new(client, [1; 32], None, vec![table]),
+        ),
+    ];
+
+    let mut out = vec![];
+    write_csv(&events, &mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    let lines = out.split_terminator("\r\n").collect::<Vec<_>>();
+
+    assert_eq!(lines[0], EventRow::COLUMNS.join(","));
+    assert_eq!(
+        lines[0],
+        "signature,slot,block_time,index,kind,client,seed,owner,new_owner,program,state,\
+         authority,randomness,amount,callback_defined,callback_override,lookup_tables"
+    );
+    assert_eq!(
+        lines[1],
+        format!("{signature},42,1700000000,0,Withdrawn,{client},,{owner},,,,,,1500,,,")
+    );
+    assert_eq!(
+        lines[2],
+        format!(
+            "{signature},42,1700000000,1,Fulfilled,{client},{},,,,,,{},,,,",
+            Seed([1; 32]),
+            Randomness([2; 64])
+        )
+    );
+    assert_eq!(
+        lines[3],
+        format!(
+            "{signature},42,1700000000,2,RequestedAlt,{client},{},,,,,,,,false,true,{table}",
+            Seed([1; 32])
+        )
+    );
+    assert_eq!(lines.len(), 4);
+
+    for line in &lines {
+        assert_eq!(line.split(',').count(), EventRow::COLUMNS.len());
+    }
+}
@@ -0,0 +1,60 @@
+//! Tests [`register_custom_event`] with an event of a program fork.
+#![cfg(feature = "sdk")]
+
+use std::io;
+
+use orao_solana_vrf_cb::sdk::{
+    register_custom_event, CustomEventError, Event, EventKind, TaggedEvent, FULFILLED_DISCRIMINATOR,
+};
+
+const DISCRIMINATOR: [u8; 8] = *b"forkevnt";
+
+#[derive(Debug, PartialEq, Eq)]
+struct Jackpot {
+    amount: u64,
+}
+
+fn parse_jackpot(payload: &[u8]) -> io::Result<Box<dyn std::any::Any + Send + Sync>> {
+    let amount = payload
+        .try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(Box::new(Jackpot { amount }))
+}
+
+fn jackpot_bytes(amount: u64) -> Vec<u8> {
+    [&DISCRIMINATOR[..], &amount.to_le_bytes()].concat()
+}
+
+#[test]
+fn custom_events() {
+    let bytes = jackpot_bytes(42);
+    assert!(Event::try_from_bytes(&bytes).is_err());
+
+    register_custom_event(DISCRIMINATOR, parse_jackpot).unwrap();
+    assert_eq!(
+        register_custom_event(DISCRIMINATOR, parse_jackpot),
+        Err(CustomEventError::AlreadyRegistered)
+    );
+    assert_eq!(
+        register_custom_event(FULFILLED_DISCRIMINATOR, parse_jackpot),
+        Err(CustomEventError::BuiltIn)
+    );
+
+    let event = Event::try_from_bytes(&bytes).unwrap();
+    assert_eq!(event.kind(), EventKind::Custom);
+    let Event::Custom(custom) = &event else {
+        panic!("expected a custom event");
+    };
+    assert_eq!(
+        custom.downcast_ref::<Jackpot>(),
+        Some(&Jackpot { amount: 42 })
+    );
+    assert_eq!(event.to_bytes(), bytes);
+
+    // malformed payload of a registered event
+    assert!(Event::try_from_bytes(&bytes[..10]).is_err());
+
+    let json = serde_json::to_string(&TaggedEvent(event.clone())).unwrap();
+    assert_eq!(serde_json::from_str::<TaggedEvent>(&json).unwrap().0, event);
+}
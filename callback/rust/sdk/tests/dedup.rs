@@ -0,0 +1,67 @@
+//! Tests of [`dedup_by_signature`].
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use futures::StreamExt;
+use orao_solana_vrf_cb::{events::Withdrawn, sdk::*};
+
+fn resolved(signature: Signature, log_index: usize) -> ResolvedEvent {
+    let event = Withdrawn::new(Pubkey::new_unique(), Pubkey::new_unique(), 1);
+    ResolvedEvent::new(signature, 1, None, log_index, log_index, event.into())
+}
+
+#[tokio::test]
+async fn drops_replayed_notifications() {
+    let (a, b, c) = (
+        Signature::new_unique(),
+        Signature::new_unique(),
+        Signature::new_unique(),
+    );
+    let events = [
+        resolved(a, 0),
+        resolved(a, 1),
+        resolved(b, 0),
+        // a reconnection replays the last notifications
+        resolved(a, 1),
+        resolved(b, 0),
+        resolved(c, 0),
+    ];
+    let deduped = dedup_by_signature(futures::stream::iter(events.clone()), 3)
+        .map(|x| (x.signature, x.log_index))
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(deduped, vec![(a, 0), (a, 1), (b, 0), (c, 0)]);
+}
+
+#[tokio::test]
+async fn repeats_older_than_the_window_pass() {
+    let (a, b) = (Signature::new_unique(), Signature::new_unique());
+    let events = [
+        resolved(a, 0),
+        resolved(b, 0),
+        resolved(a, 0),
+        resolved(a, 0),
+    ];
+
+    let deduped = dedup_by_signature(futures::stream::iter(events.clone()), 1)
+        .map(|x| x.signature)
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(deduped, vec![a, b, a]);
+
+    let all = dedup_by_signature(futures::stream::iter(events), 0)
+        .count()
+        .await;
+    assert_eq!(all, 4);
+}
+
+#[tokio::test]
+async fn unbounded_window() {
+    let a = Signature::new_unique();
+    let events = [resolved(a, 0), resolved(a, 1), resolved(a, 0)];
+    let deduped = dedup_by_signature(futures::stream::iter(events), usize::MAX)
+        .count()
+        .await;
+    assert_eq!(deduped, 2);
+}
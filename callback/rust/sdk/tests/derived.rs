@@ -0,0 +1,55 @@
+//! Tests of the [`with_derived`] adapters.
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use futures::StreamExt;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested},
+    sdk::{with_derived, with_derived_stream, DerivedEvent, Event},
+};
+
+fn events() -> Vec<Event> {
+    let client = Pubkey::new_unique();
+    vec![
+        Requested::new(client, [1; 32], None, false).into(),
+        Fulfilled::new(client, [1; 32], [2; 64]).into(),
+        Requested::new(client, [3; 32], None, false).into(),
+        Fulfilled::new(client, [3; 32], [4; 64]).into(),
+    ]
+}
+
+#[test]
+fn fulfillments_are_enriched() {
+    let events = events();
+    let derived: Vec<_> = with_derived(events.clone(), |ev| ev.randomness[0]).collect();
+
+    assert_eq!(
+        derived
+            .iter()
+            .map(|x| x.value().copied())
+            .collect::<Vec<_>>(),
+        vec![None, Some(2), None, Some(4)]
+    );
+    assert!(matches!(&derived[1], DerivedEvent::Fulfilled(ev, 2) if ev.seed == [1; 32]));
+    assert_eq!(
+        derived
+            .into_iter()
+            .map(DerivedEvent::into_event)
+            .collect::<Vec<_>>(),
+        events
+    );
+}
+
+#[tokio::test]
+async fn stream_is_enriched() {
+    let mut calls = 0;
+    let derived: Vec<_> = with_derived_stream(futures::stream::iter(events()), |ev| {
+        calls += 1;
+        ev.weighted_choice(&[1, 1])
+    })
+    .collect()
+    .await;
+
+    assert_eq!(calls, 2);
+    assert_eq!(derived.iter().filter_map(DerivedEvent::value).count(), 2);
+}
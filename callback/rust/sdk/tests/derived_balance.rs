@@ -0,0 +1,43 @@
+//! Tests [`derived_balance_from_events`].
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested, RequestedAlt, Withdrawn},
+    sdk::{derived_balance_from_events, Event},
+};
+
+#[test]
+fn deducts_withdrawals_and_fees() {
+    let owner = Pubkey::new_unique();
+    let client = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let events: Vec<Event> = vec![
+        Requested::new(client, [1; 32], None, false).into(),
+        Fulfilled::new(client, [1; 32], [2; 64]).into(),
+        RequestedAlt::new(client, [2; 32], None, vec![]).into(),
+        Withdrawn::new(owner, client, 300).into(),
+        // another client
+        Requested::new(other, [3; 32], None, false).into(),
+        Withdrawn::new(owner, other, 5_000).into(),
+    ];
+
+    assert_eq!(
+        derived_balance_from_events(&client, 1_000, 100, &events),
+        1_000 - 300 - 2 * 100
+    );
+}
+
+#[test]
+fn exact_and_signed() {
+    let client = Pubkey::new_unique();
+    let events: Vec<Event> = vec![
+        Withdrawn::new(client, client, u64::MAX).into(),
+        Withdrawn::new(client, client, u64::MAX).into(),
+    ];
+
+    assert_eq!(
+        derived_balance_from_events(&client, 0, 0, &events),
+        -2 * u64::MAX as i128
+    );
+}
@@ -0,0 +1,133 @@
+//! Tests that the `const` discriminators match the ones used by [`Event::try_from_bytes`].
+#![cfg(feature = "sdk")]
+
+use anchor_lang::{prelude::Pubkey, Discriminator};
+use orao_solana_vrf_cb::{events, sdk::*};
+
+#[test]
+fn discriminators_match() {
+    let pairs: [(&[u8], [u8; 8]); 9] = [
+        (
+            events::CallbackUpdated::DISCRIMINATOR,
+            CALLBACK_UPDATED_DISCRIMINATOR,
+        ),
+        (events::CalledBack::DISCRIMINATOR, CALLED_BACK_DISCRIMINATOR),
+        (events::Fulfilled::DISCRIMINATOR, FULFILLED_DISCRIMINATOR),
+        (events::Registered::DISCRIMINATOR, REGISTERED_DISCRIMINATOR),
+        (events::Requested::DISCRIMINATOR, REQUESTED_DISCRIMINATOR),
+        (
+            events::RequestedAlt::DISCRIMINATOR,
+            REQUESTED_ALT_DISCRIMINATOR,
+        ),
+        (events::Responded::DISCRIMINATOR, RESPONDED_DISCRIMINATOR),
+        (
+            events::Transferred::DISCRIMINATOR,
+            TRANSFERRED_DISCRIMINATOR,
+        ),
+        (events::Withdrawn::DISCRIMINATOR, WITHDRAWN_DISCRIMINATOR),
+    ];
+    for (expected, actual) in pairs {
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn discriminators_are_8_bytes() {
+    let discriminators: [(&str, &[u8]); 9] = [
+        ("CallbackUpdated", events::CallbackUpdated::DISCRIMINATOR),
+        ("CalledBack", events::CalledBack::DISCRIMINATOR),
+        ("Fulfilled", events::Fulfilled::DISCRIMINATOR),
+        ("Registered", events::Registered::DISCRIMINATOR),
+        ("Requested", events::Requested::DISCRIMINATOR),
+        ("RequestedAlt", events::RequestedAlt::DISCRIMINATOR),
+        ("Responded", events::Responded::DISCRIMINATOR),
+        ("Transferred", events::Transferred::DISCRIMINATOR),
+        ("Withdrawn", events::Withdrawn::DISCRIMINATOR),
+    ];
+    for (name, discriminator) in discriminators {
+        // `Event::try_from_bytes` reads the payload at this offset
+        assert_eq!(discriminator.len(), 8, "{name}");
+    }
+}
+
+#[test]
+fn parsed_by_const_discriminator() {
+    let event = Event::from(events::Fulfilled::new(
+        Pubkey::new_unique(),
+        [1; 32],
+        [2; 64],
+    ));
+    let bytes = event.to_bytes();
+
+    assert_eq!(bytes[..8], FULFILLED_DISCRIMINATOR);
+    assert_eq!(Event::try_from_bytes(&bytes).unwrap(), event);
+}
+
+#[test]
+fn discriminators_are_distinct() {
+    let names = [
+        "CallbackUpdated",
+        "CalledBack",
+        "Fulfilled",
+        "Registered",
+        "Requested",
+        "RequestedAlt",
+        "Responded",
+        "Transferred",
+        "Withdrawn",
+    ];
+    let discriminators: Vec<&[u8]> = names
+        .iter()
+        .map(|name| discriminator_for_name(name).unwrap())
+        .collect();
+
+    for (i, a) in discriminators.iter().enumerate() {
+        assert_eq!(a.len(), 8, "{}", names[i]);
+        for (j, b) in discriminators.iter().enumerate().skip(i + 1) {
+            // Neither is a prefix of another, thus no event is misrouted.
+            assert!(
+                !a.starts_with(b) && !b.starts_with(a),
+                "{} and {} share a discriminator",
+                names[i],
+                names[j]
+            );
+        }
+    }
+}
+
+#[test]
+fn discriminator_for_name_matches_events() {
+    assert_eq!(
+        discriminator_for_name("Fulfilled"),
+        Some(events::Fulfilled::DISCRIMINATOR)
+    );
+    assert_eq!(
+        discriminator_for_name("RequestedAlt"),
+        Some(events::RequestedAlt::DISCRIMINATOR)
+    );
+    // names are case-sensitive
+    assert_eq!(discriminator_for_name("fulfilled"), None);
+    assert_eq!(discriminator_for_name(""), None);
+}
+
+#[test]
+fn split_discriminator_splits_at_8_bytes() {
+    let bytes = Event::from(events::Fulfilled::new(
+        Pubkey::new_unique(),
+        [1; 32],
+        [2; 64],
+    ))
+    .to_bytes();
+
+    let (discriminator, payload) = split_discriminator(&bytes).unwrap();
+    assert_eq!(*discriminator, FULFILLED_DISCRIMINATOR);
+    assert_eq!(payload, &bytes[8..]);
+
+    // a bare discriminator has an empty payload
+    let (discriminator, payload) = split_discriminator(&bytes[..8]).unwrap();
+    assert_eq!(*discriminator, FULFILLED_DISCRIMINATOR);
+    assert!(payload.is_empty());
+
+    assert_eq!(split_discriminator(&bytes[..7]), None);
+    assert_eq!(split_discriminator(&[]), None);
+}
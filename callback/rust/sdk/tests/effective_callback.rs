@@ -0,0 +1,112 @@
+//! Tests of [`resolve_effective_callback`].
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::{prelude::Pubkey, AnchorDeserialize, AnchorSerialize};
+use orao_solana_vrf_cb::{
+    events::{CallbackUpdated, Requested, RequestedAlt},
+    sdk::{resolve_effective_callback, EffectiveCallback, Event, ResolvedEvent},
+    state::client::{ValidatedCallback, ValidatedCallbackAlt},
+};
+
+fn resolved(slot: u64, event: impl Into<Event>) -> ResolvedEvent {
+    ResolvedEvent::new(Signature::new_unique(), slot, None, 0, 0, event.into())
+}
+
+fn callback() -> ValidatedCallback {
+    let mut data = vec![];
+    (Vec::<(Pubkey, bool)>::new(), vec![1_u8])
+        .serialize(&mut data)
+        .unwrap();
+    ValidatedCallback::try_from_slice(&data).unwrap()
+}
+
+fn callback_alt() -> ValidatedCallbackAlt {
+    let mut data = vec![];
+    ([7_u8; 32], Vec::<(Pubkey, bool)>::new(), vec![1_u8])
+        .serialize(&mut data)
+        .unwrap();
+    ValidatedCallbackAlt::try_from_slice(&data).unwrap()
+}
+
+fn updated(slot: u64, client: Pubkey, defined: bool) -> ResolvedEvent {
+    resolved(
+        slot,
+        CallbackUpdated::new(Pubkey::new_unique(), client, defined),
+    )
+}
+
+#[test]
+fn request_level_takes_precedence() {
+    let client = Pubkey::new_unique();
+    let requested = resolved(10, Requested::new(client, [1; 32], Some(callback()), true));
+    let history = [updated(5, client, true)];
+    assert_eq!(
+        resolve_effective_callback(&requested, &history),
+        Some(EffectiveCallback::RequestLevel)
+    );
+}
+
+#[test]
+fn client_level_attributed_to_latest_preceding_update() {
+    let client = Pubkey::new_unique();
+    let requested = resolved(10, Requested::new(client, [1; 32], Some(callback()), false));
+    let history = [
+        updated(3, client, false),
+        updated(5, client, true),
+        updated(12, client, false),
+        updated(7, Pubkey::new_unique(), false),
+    ];
+    assert_eq!(
+        resolve_effective_callback(&requested, &history),
+        Some(EffectiveCallback::ClientLevel {
+            updated: Some(Box::new(history[1].clone()))
+        })
+    );
+    assert_eq!(
+        resolve_effective_callback(&requested, []),
+        Some(EffectiveCallback::ClientLevel { updated: None })
+    );
+}
+
+#[test]
+fn no_callback() {
+    let client = Pubkey::new_unique();
+    let requested = resolved(10, Requested::new(client, [1; 32], None, false));
+    let history = [updated(5, client, true), updated(8, client, false)];
+    assert_eq!(
+        resolve_effective_callback(&requested, &history),
+        Some(EffectiveCallback::None)
+    );
+}
+
+#[test]
+fn contradicting_history() {
+    let client = Pubkey::new_unique();
+    let requested = resolved(10, Requested::new(client, [1; 32], None, false));
+    let history = [updated(8, client, true)];
+    assert_eq!(resolve_effective_callback(&requested, &history), None);
+    assert_eq!(resolve_effective_callback(&history[0], []), None);
+}
+
+#[test]
+fn alt_request_ignores_client_level() {
+    let client = Pubkey::new_unique();
+    let history = [updated(5, client, true)];
+
+    let requested = resolved(
+        10,
+        RequestedAlt::new(client, [1; 32], Some(callback_alt()), vec![]),
+    );
+    assert_eq!(
+        resolve_effective_callback(&requested, &history),
+        Some(EffectiveCallback::RequestLevel)
+    );
+
+    // the client-level callback is never applied to ALT requests
+    let requested = resolved(10, RequestedAlt::new(client, [1; 32], None, vec![]));
+    assert_eq!(
+        resolve_effective_callback(&requested, &history),
+        Some(EffectiveCallback::None)
+    );
+}
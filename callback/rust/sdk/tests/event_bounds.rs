@@ -0,0 +1,128 @@
+//! Tests of the [`Event::try_from_bytes`] bounds on length-prefixed fields.
+#![cfg(feature = "sdk")]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    io,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use anchor_lang::{prelude::Pubkey, AnchorDeserialize, AnchorSerialize};
+use orao_solana_vrf_cb::{
+    events::{Requested, RequestedAlt},
+    sdk::{Event, REQUESTED_ALT_DISCRIMINATOR, REQUESTED_DISCRIMINATOR},
+    state::client::{Callback, ValidatedCallback},
+};
+
+/// Tracks the largest allocation to make sure a length prefix is not trusted.
+struct MaxAlloc;
+
+static MAX_ALLOCATION: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for MaxAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        MAX_ALLOCATION.fetch_max(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: MaxAlloc = MaxAlloc;
+
+/// Discriminator, client and seed of a request event.
+fn header(discriminator: [u8; 8]) -> Vec<u8> {
+    let mut bytes = discriminator.to_vec();
+    bytes.extend(Pubkey::new_unique().to_bytes());
+    bytes.extend([1; 32]);
+    bytes
+}
+
+fn assert_rejected(bytes: &[u8]) {
+    let err = Event::try_from_bytes(bytes).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData, "{err}");
+    assert!(
+        MAX_ALLOCATION.load(Ordering::Relaxed) < 64 * 1024,
+        "large allocation"
+    );
+}
+
+#[test]
+fn oversized_length_prefixes() {
+    // Requested with callback data of u32::MAX bytes
+    let mut bytes = header(REQUESTED_DISCRIMINATOR);
+    bytes.push(1);
+    bytes.extend(0_u32.to_le_bytes());
+    bytes.extend(u32::MAX.to_le_bytes());
+    bytes.extend([0; 16]);
+    assert_rejected(&bytes);
+
+    // Requested with u32::MAX callback accounts
+    let mut bytes = header(REQUESTED_DISCRIMINATOR);
+    bytes.push(1);
+    bytes.extend(u32::MAX.to_le_bytes());
+    bytes.extend([0; 16]);
+    assert_rejected(&bytes);
+
+    // RequestedAlt with u32::MAX callback data bytes
+    let mut bytes = header(REQUESTED_ALT_DISCRIMINATOR);
+    bytes.push(1);
+    bytes.extend([7; 32]);
+    bytes.extend(0_u32.to_le_bytes());
+    bytes.extend(u32::MAX.to_le_bytes());
+    bytes.extend([0; 16]);
+    assert_rejected(&bytes);
+
+    // RequestedAlt with u32::MAX lookup tables
+    let mut bytes = header(REQUESTED_ALT_DISCRIMINATOR);
+    bytes.push(0);
+    bytes.extend(u32::MAX.to_le_bytes());
+    bytes.extend([0; 16]);
+    assert_rejected(&bytes);
+}
+
+#[test]
+fn too_many_callback_accounts() {
+    let mut bytes = header(REQUESTED_DISCRIMINATOR);
+    bytes.push(1);
+    let accounts = Callback::MAX_REMAINING_ACCOUNTS as u32 + 1;
+    bytes.extend(accounts.to_le_bytes());
+    for _ in 0..accounts {
+        bytes.extend(Pubkey::new_unique().to_bytes());
+        bytes.push(0);
+    }
+    bytes.extend(0_u32.to_le_bytes());
+    bytes.push(1);
+    let err = Event::try_from_bytes(&bytes).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn valid_variable_length_fields() {
+    let mut data = vec![];
+    (vec![(Pubkey::new_unique(), true)], vec![0xab_u8; 100])
+        .serialize(&mut data)
+        .unwrap();
+    let callback = ValidatedCallback::try_from_slice(&data).unwrap();
+    let requested = Event::from(Requested::new(
+        Pubkey::new_unique(),
+        [1; 32],
+        Some(callback),
+        true,
+    ));
+    assert_eq!(
+        Event::try_from_bytes(&requested.to_bytes()).unwrap(),
+        requested
+    );
+
+    let alt = Event::from(RequestedAlt::new(
+        Pubkey::new_unique(),
+        [1; 32],
+        None,
+        vec![Pubkey::new_unique(), Pubkey::new_unique()],
+    ));
+    assert_eq!(Event::try_from_bytes(&alt.to_bytes()).unwrap(), alt);
+}
@@ -0,0 +1,67 @@
+//! Tests the validated event constructors and [`Event::to_program_data_log`].
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Responded},
+    sdk::{parse_logs, Event, ParseBytesError, PROGRAM_DATA_PREFIX},
+};
+
+#[test]
+fn from_slices_accepts_exact_lengths() {
+    let key = Pubkey::new_unique();
+
+    assert_eq!(
+        Fulfilled::from_slices(key, &[1; 32], &[2; 64]).unwrap(),
+        Fulfilled::new(key, [1; 32], [2; 64])
+    );
+    assert_eq!(
+        Responded::from_slices(key, key, &[1; 32], &[2; 64]).unwrap(),
+        Responded::new(key, key, [1; 32], [2; 64])
+    );
+}
+
+#[test]
+fn from_slices_rejects_wrong_lengths() {
+    let key = Pubkey::new_unique();
+
+    assert_eq!(
+        Fulfilled::from_slices(key, &[1; 31], &[2; 64]).unwrap_err(),
+        ParseBytesError::Length {
+            expected: 32,
+            actual: 31
+        }
+    );
+    assert_eq!(
+        Fulfilled::from_slices(key, &[1; 32], &[2; 65]).unwrap_err(),
+        ParseBytesError::Length {
+            expected: 64,
+            actual: 65
+        }
+    );
+    assert_eq!(
+        Responded::from_slices(key, key, &[1; 32], &[]).unwrap_err(),
+        ParseBytesError::Length {
+            expected: 64,
+            actual: 0
+        }
+    );
+}
+
+#[test]
+fn program_data_log_round_trips() {
+    let event = Event::from(Fulfilled::new(Pubkey::new_unique(), [1; 32], [2; 64]));
+    let log = event.to_program_data_log();
+
+    let data = log.strip_prefix(PROGRAM_DATA_PREFIX).unwrap();
+    assert_eq!(Event::try_from_base64(data).unwrap(), event);
+
+    let vrf = orao_solana_vrf_cb::id();
+    let logs = [
+        format!("Program {vrf} invoke [1]"),
+        log,
+        format!("Program {vrf} success"),
+    ];
+    let parsed: Vec<_> = parse_logs(&vrf, &logs).map(Result::unwrap).collect();
+    assert_eq!(parsed, [event]);
+}
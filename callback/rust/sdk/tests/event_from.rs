@@ -0,0 +1,34 @@
+//! Tests lifting inner events into [`Event`] with `.into()`.
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{
+        CallbackUpdated, CalledBack, Fulfilled, Registered, Requested, RequestedAlt, Responded,
+        Transferred, Withdrawn,
+    },
+    sdk::{Event, EventKind},
+};
+
+fn lift<T: Into<Event>>(event: T) -> Event {
+    event.into()
+}
+
+#[test]
+fn every_inner_event_lifts() {
+    let key = Pubkey::new_unique();
+    let events = [
+        lift(CallbackUpdated::new(key, key, true)),
+        lift(CalledBack::new(key)),
+        lift(Fulfilled::new(key, [1; 32], [2; 64])),
+        lift(Registered::new(key, key, key, key)),
+        lift(Requested::new(key, [1; 32], None, false)),
+        lift(RequestedAlt::new(key, [1; 32], None, vec![])),
+        lift(Responded::new(key, key, [1; 32], [2; 64])),
+        lift(Transferred::new(key, key, key)),
+        lift(Withdrawn::new(key, key, 1)),
+    ];
+
+    let kinds: Vec<_> = events.iter().map(Event::kind).collect();
+    assert_eq!(kinds, EventKind::ALL[..9]);
+}
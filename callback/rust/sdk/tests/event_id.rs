@@ -0,0 +1,39 @@
+//! Tests of the [`ResolvedEvent::event_id`].
+#![cfg(feature = "sdk")]
+
+use std::collections::HashSet;
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested},
+    sdk::{Event, ResolvedEvent},
+};
+
+#[test]
+fn stable_and_unique() {
+    let client = Pubkey::new_unique();
+    let requested: Event = Requested::new(client, [1; 32], None, false).into();
+    let fulfilled: Event = Fulfilled::new(client, [1; 32], [2; 64]).into();
+    let signature = Signature::new_unique();
+
+    let event = ResolvedEvent::new(signature, 10, None, 0, 3, requested.clone());
+    let id = event.event_id();
+    assert_eq!(id.len(), 64);
+    assert!(id.bytes().all(|x| x.is_ascii_hexdigit()));
+
+    // Re-parsing (or re-inclusion at another slot) keeps the id.
+    let refetched = ResolvedEvent::new(signature, 11, Some(1), 0, 3, requested.clone());
+    assert_eq!(refetched.event_id(), id);
+
+    let ids: HashSet<_> = [
+        event,
+        ResolvedEvent::new(signature, 10, None, 1, 4, requested.clone()),
+        ResolvedEvent::new(signature, 10, None, 0, 3, fulfilled),
+        ResolvedEvent::new(Signature::new_unique(), 10, None, 0, 3, requested),
+    ]
+    .iter()
+    .map(ResolvedEvent::event_id)
+    .collect();
+    assert_eq!(ids.len(), 4);
+}
@@ -0,0 +1,71 @@
+//! Tests [`events_with_fee_payer`] on a JSON-encoded transaction.
+#![cfg(feature = "sdk")]
+
+use std::io;
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::Requested,
+    sdk::{events_with_fee_payer, Event},
+};
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+
+fn transaction(
+    account_keys: Vec<String>,
+    logs: Vec<String>,
+) -> EncodedConfirmedTransactionWithStatusMeta {
+    serde_json::from_value(serde_json::json!({
+        "slot": 1,
+        "blockTime": null,
+        "transaction": {
+            "signatures": [Signature::new_unique().to_string()],
+            "message": {
+                "header": {
+                    "numRequiredSignatures": 1,
+                    "numReadonlySignedAccounts": 0,
+                    "numReadonlyUnsignedAccounts": 0
+                },
+                "accountKeys": account_keys,
+                "recentBlockhash": "11111111111111111111111111111111",
+                "instructions": []
+            }
+        },
+        "meta": {
+            "err": null,
+            "status": {"Ok": null},
+            "fee": 5000,
+            "preBalances": [],
+            "postBalances": [],
+            "logMessages": logs
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn requests_are_attributed_to_payer() {
+    let vrf = orao_solana_vrf_cb::id();
+    let payer = Pubkey::new_unique();
+    let event: Event = Requested::new(Pubkey::new_unique(), [1; 32], None, false).into();
+    let logs = vec![
+        format!("Program {vrf} invoke [1]"),
+        event.to_program_data_log(),
+        format!("Program {vrf} success"),
+    ];
+
+    let tx = transaction(vec![payer.to_string(), vrf.to_string()], logs);
+    let (actual, events) = events_with_fee_payer(&tx).unwrap();
+    assert_eq!(actual, payer);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event, event);
+}
+
+#[test]
+fn missing_payer() {
+    let tx = transaction(vec![], vec![]);
+    assert_eq!(
+        events_with_fee_payer(&tx).unwrap_err().kind(),
+        io::ErrorKind::InvalidData
+    );
+}
@@ -0,0 +1,64 @@
+//! Tests [`finalized_events`] using the mock RPC.
+//!
+//! The mock reports every transaction as finalized at slot 1.
+#![cfg(feature = "sdk")]
+
+use std::{collections::HashMap, time::Duration};
+
+use anchor_client::solana_client::{nonblocking::rpc_client::RpcClient, rpc_request::RpcRequest};
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use futures::StreamExt;
+use orao_solana_vrf_cb::{
+    events::Requested,
+    sdk::{finalized_events, ResolvedEvent},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+fn resolved(slot: u64) -> ResolvedEvent {
+    let event = Requested::new(Pubkey::new_unique(), [1; 32], None, false).into();
+    ResolvedEvent::new(Signature::new_unique(), slot, None, 0, 1, event)
+}
+
+fn rpc_with_root(root: u64) -> RpcClient {
+    RpcClient::new_mock_with_mocks(
+        "succeeds".to_string(),
+        HashMap::from([(RpcRequest::GetSlot, serde_json::json!(root))]),
+    )
+}
+
+#[tokio::test]
+async fn yields_finalized_and_drops_reorged() {
+    let rpc = rpc_with_root(5);
+    let finalized = resolved(1);
+    // finalized at another slot, i.e. the fork of slot 2 is abandoned
+    let reorged = resolved(2);
+
+    let events: Vec<_> = finalized_events(
+        &rpc,
+        futures::stream::iter(vec![finalized.clone(), reorged]),
+        POLL_INTERVAL,
+    )
+    .collect()
+    .await;
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].as_ref().unwrap(), &finalized);
+}
+
+#[tokio::test]
+async fn holds_events_above_root() {
+    // the root stays at 0 after the first (mocked) call
+    let rpc = rpc_with_root(0);
+    let events = finalized_events(
+        &rpc,
+        futures::stream::iter(vec![resolved(1)]),
+        POLL_INTERVAL,
+    );
+    let mut events = std::pin::pin!(events);
+
+    assert!(tokio::time::timeout(POLL_INTERVAL * 10, events.next())
+        .await
+        .is_err());
+}
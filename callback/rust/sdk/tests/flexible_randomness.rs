@@ -0,0 +1,147 @@
+//! Tests [`FlexibleRandomnessEvent`] on both randomness layouts.
+#![cfg(feature = "sdk")]
+
+use std::io;
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested, Responded},
+    sdk::{
+        Event, EventKind, FlexibleRandomnessEvent, RandomnessLayout, TruncatedEvent, UnknownEvent,
+        FULFILLED_DISCRIMINATOR, RESPONDED_DISCRIMINATOR,
+    },
+};
+
+/// A `Responded` event with a length-prefixed randomness of the given length.
+fn length_prefixed(authority: Pubkey, client: Pubkey, len: usize) -> Vec<u8> {
+    let mut bytes = RESPONDED_DISCRIMINATOR.to_vec();
+    bytes.extend(authority.to_bytes());
+    bytes.extend(client.to_bytes());
+    bytes.extend([1; 32]);
+    bytes.extend((len as u32).to_le_bytes());
+    bytes.extend(vec![7; len]);
+    bytes
+}
+
+fn truncated(err: io::Error) -> TruncatedEvent {
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    *err.get_ref()
+        .unwrap()
+        .downcast_ref::<TruncatedEvent>()
+        .unwrap()
+}
+
+#[test]
+fn fixed_layout() {
+    let client = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let bytes = Event::from(Fulfilled::new(client, [1; 32], [2; 64])).to_bytes();
+    let event = FlexibleRandomnessEvent::try_from_bytes(&bytes, RandomnessLayout::Fixed).unwrap();
+    assert_eq!(event.kind, EventKind::Fulfilled);
+    assert_eq!(event.authority, None);
+    assert_eq!(event.client, client);
+    assert_eq!(event.seed, [1; 32]);
+    assert_eq!(event.randomness_array(), Some([2; 64]));
+
+    let bytes = Event::from(Responded::new(authority, client, [1; 32], [3; 64])).to_bytes();
+    let event = FlexibleRandomnessEvent::try_from_bytes(&bytes, RandomnessLayout::Fixed).unwrap();
+    assert_eq!(event.kind, EventKind::Responded);
+    assert_eq!(event.authority, Some(authority));
+    assert_eq!(event.client, client);
+    assert_eq!(event.randomness, vec![3; 64]);
+}
+
+#[test]
+fn length_prefixed_layout() {
+    let authority = Pubkey::new_unique();
+    let client = Pubkey::new_unique();
+
+    // 60 bytes are as long as the fixed array along with the prefix
+    for len in [0_usize, 32, 60, 64, 128] {
+        let bytes = length_prefixed(authority, client, len);
+
+        let event =
+            FlexibleRandomnessEvent::try_from_bytes(&bytes, RandomnessLayout::LengthPrefixed)
+                .unwrap();
+        assert_eq!(event.authority, Some(authority));
+        assert_eq!(event.client, client);
+        assert_eq!(event.randomness, vec![7; len]);
+        assert_eq!(event.randomness_array().is_some(), len == 64);
+
+        // only the layout given by the caller decides how the bytes are read
+        if len == 60 {
+            let event =
+                FlexibleRandomnessEvent::try_from_bytes(&bytes, RandomnessLayout::Fixed).unwrap();
+            assert_eq!(&event.randomness[..4], &60_u32.to_le_bytes());
+        } else {
+            assert!(
+                FlexibleRandomnessEvent::try_from_bytes(&bytes, RandomnessLayout::Fixed).is_err()
+            );
+        }
+    }
+}
+
+#[test]
+fn trailing_bytes() {
+    let mut bytes = Event::from(Fulfilled::new(Pubkey::new_unique(), [1; 32], [2; 64])).to_bytes();
+    bytes.push(0);
+    let err = FlexibleRandomnessEvent::try_from_bytes(&bytes, RandomnessLayout::Fixed).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    let mut bytes = length_prefixed(Pubkey::new_unique(), Pubkey::new_unique(), 16);
+    bytes.extend([0; 3]);
+    let err = FlexibleRandomnessEvent::try_from_bytes(&bytes, RandomnessLayout::LengthPrefixed)
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn truncated_randomness() {
+    let mut bytes = FULFILLED_DISCRIMINATOR.to_vec();
+    bytes.extend([0; 64]);
+    bytes.extend(16_u32.to_le_bytes());
+    bytes.extend([7; 8]);
+
+    let err = FlexibleRandomnessEvent::try_from_bytes(&bytes, RandomnessLayout::LengthPrefixed)
+        .unwrap_err();
+    assert_eq!(
+        truncated(err),
+        TruncatedEvent::Payload {
+            kind: EventKind::Fulfilled,
+            expected: 84,
+            actual: 76,
+        }
+    );
+
+    let err =
+        FlexibleRandomnessEvent::try_from_bytes(&bytes[..70], RandomnessLayout::LengthPrefixed)
+            .unwrap_err();
+    assert_eq!(
+        truncated(err),
+        TruncatedEvent::Payload {
+            kind: EventKind::Fulfilled,
+            expected: 68,
+            actual: 62,
+        }
+    );
+
+    let err = FlexibleRandomnessEvent::try_from_bytes(&bytes, RandomnessLayout::Fixed).unwrap_err();
+    assert_eq!(
+        truncated(err),
+        TruncatedEvent::Payload {
+            kind: EventKind::Fulfilled,
+            expected: 128,
+            actual: 76,
+        }
+    );
+}
+
+#[test]
+fn other_events_are_unknown() {
+    let bytes = Event::from(Requested::new(Pubkey::new_unique(), [1; 32], None, false)).to_bytes();
+    for layout in [RandomnessLayout::Fixed, RandomnessLayout::LengthPrefixed] {
+        let err = FlexibleRandomnessEvent::try_from_bytes(&bytes, layout).unwrap_err();
+        assert!(err.get_ref().unwrap().is::<UnknownEvent>());
+    }
+}
@@ -0,0 +1,58 @@
+//! Tests [`forward_events`] with a channel sink.
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use futures::{channel::mpsc, future, SinkExt, StreamExt};
+use orao_solana_vrf_cb::{
+    events::Requested,
+    sdk::{forward_events, Event},
+};
+
+fn events() -> Vec<Event> {
+    let client = Pubkey::new_unique();
+    (0..3)
+        .map(|i| Requested::new(client, [i; 32], None, false).into())
+        .collect()
+}
+
+#[tokio::test]
+async fn forwards_and_closes() {
+    let events = events();
+    let (tx, rx) = mpsc::channel(1);
+
+    let forward = forward_events(futures::stream::iter(events.clone()), tx, future::pending());
+    let (forwarded, received) = future::join(forward, rx.collect::<Vec<_>>()).await;
+
+    assert_eq!(forwarded.unwrap(), 3);
+    // the receiver stream ends, so the sink is closed
+    assert_eq!(received, events);
+}
+
+#[tokio::test]
+async fn stops_on_shutdown() {
+    let (mut source, events) = mpsc::channel(4);
+    let (tx, mut rx) = mpsc::channel(4);
+    let (stop, shutdown) = futures::channel::oneshot::channel::<()>();
+
+    let event = self::events().remove(0);
+    source.send(event.clone()).await.unwrap();
+
+    let forward = tokio::spawn(forward_events(events, tx, async move {
+        let _ = shutdown.await;
+    }));
+    assert_eq!(rx.next().await, Some(event));
+    stop.send(()).unwrap();
+
+    // the source is still open, yet the forwarding stops and the sink is closed
+    assert_eq!(forward.await.unwrap().unwrap(), 1);
+    assert_eq!(rx.next().await, None);
+}
+
+#[tokio::test]
+async fn sink_error_is_returned() {
+    let (tx, rx) = mpsc::channel(1);
+    drop(rx);
+
+    let result = forward_events(futures::stream::iter(events()), tx, future::pending()).await;
+    assert!(result.unwrap_err().is_disconnected());
+}
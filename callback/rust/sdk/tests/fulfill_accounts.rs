@@ -0,0 +1,191 @@
+//! Tests of the [`expected_accounts`].
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::{instruction::AccountMeta, sysvar};
+use anchor_lang::{
+    prelude::Pubkey, solana_program::address_lookup_table::AddressLookupTableAccount,
+    AnchorDeserialize, AnchorSerialize,
+};
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested, RequestedAlt},
+    sdk::{expected_accounts, Event, ExpectedAccountsError},
+    state::{
+        client::{Client, ValidatedCallback, ValidatedCallbackAlt},
+        network_state::{NetworkConfiguration, NetworkState},
+        request::RequestAccount,
+        request_alt::RequestAltAccount,
+    },
+};
+
+struct Setup {
+    vrf_id: Pubkey,
+    client_address: Pubkey,
+    client: Client,
+    config: NetworkConfiguration,
+    oracle: Pubkey,
+}
+
+fn setup() -> Setup {
+    let vrf_id = orao_solana_vrf_cb::id();
+    let (program, state, oracle) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let mut config = NetworkConfiguration::new(Pubkey::new_unique(), Pubkey::new_unique(), 1_000);
+    config.fulfill_authorities = vec![Pubkey::new_unique(), oracle];
+
+    Setup {
+        vrf_id,
+        client_address: Client::find_address(&program, &state, &vrf_id).0,
+        client: Client::new(255, Pubkey::new_unique(), program, state, 0, None),
+        config,
+        oracle,
+    }
+}
+
+#[test]
+fn fulfill_accounts() {
+    let setup = setup();
+
+    // a callback with a writable and a read-only account
+    let (writable, readonly) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut data = vec![];
+    (vec![(writable, true), (readonly, false)], vec![1_u8, 2, 3])
+        .serialize(&mut data)
+        .unwrap();
+    let callback = ValidatedCallback::try_from_slice(&data).unwrap();
+
+    let seed = [7; 32];
+    let requested = Requested::new(setup.client_address, seed, Some(callback), false);
+
+    let accounts = expected_accounts(
+        &requested.into(),
+        &setup.client,
+        &setup.config,
+        &setup.oracle,
+        &[],
+        &setup.vrf_id,
+    )
+    .unwrap();
+    assert_eq!(
+        accounts,
+        vec![
+            AccountMeta::new(setup.oracle, true),
+            AccountMeta::new_readonly(setup.client.program, false),
+            AccountMeta::new(setup.client.state, false),
+            AccountMeta::new(setup.client_address, false),
+            AccountMeta::new(
+                RequestAccount::find_address(&setup.client_address, &seed, &setup.vrf_id).0,
+                false
+            ),
+            AccountMeta::new_readonly(NetworkState::find_address(&setup.vrf_id).0, false),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+            AccountMeta::new(writable, false),
+            AccountMeta::new_readonly(readonly, false),
+        ]
+    );
+}
+
+#[test]
+fn fulfill_alt_accounts() {
+    let setup = setup();
+    let plain = Pubkey::new_unique();
+    let table = AddressLookupTableAccount {
+        key: Pubkey::new_unique(),
+        addresses: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+    };
+
+    // accounts_hash, [Plain { pubkey, is_writable }, Lookup { table_index, address_index, is_writable }], data
+    let mut data = [7_u8; 32].to_vec();
+    data.extend(2_u32.to_le_bytes());
+    data.push(0);
+    data.extend(plain.to_bytes());
+    data.push(0);
+    data.extend([1, 0, 1, 1]);
+    data.extend(0_u32.to_le_bytes());
+    let callback = ValidatedCallbackAlt::try_from_slice(&data).unwrap();
+
+    let seed = [7; 32];
+    let requested = Event::from(RequestedAlt::new(
+        setup.client_address,
+        seed,
+        Some(callback),
+        vec![table.key],
+    ));
+
+    let accounts = expected_accounts(
+        &requested,
+        &setup.client,
+        &setup.config,
+        &setup.oracle,
+        std::slice::from_ref(&table),
+        &setup.vrf_id,
+    )
+    .unwrap();
+    assert_eq!(
+        accounts,
+        vec![
+            AccountMeta::new(setup.oracle, true),
+            AccountMeta::new_readonly(setup.client.program, false),
+            AccountMeta::new(setup.client.state, false),
+            AccountMeta::new(setup.client_address, false),
+            AccountMeta::new(
+                RequestAltAccount::find_address(&setup.client_address, &seed, &setup.vrf_id).0,
+                false
+            ),
+            AccountMeta::new_readonly(NetworkState::find_address(&setup.vrf_id).0, false),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+            AccountMeta::new_readonly(plain, false),
+            AccountMeta::new(table.addresses[1], false),
+        ]
+    );
+
+    // the table is not given
+    assert_eq!(
+        expected_accounts(
+            &requested,
+            &setup.client,
+            &setup.config,
+            &setup.oracle,
+            &[],
+            &setup.vrf_id,
+        ),
+        Err(ExpectedAccountsError::UnresolvedLookup {
+            table_index: 0,
+            address_index: 1
+        })
+    );
+}
+
+#[test]
+fn rejected_inputs() {
+    let setup = setup();
+    let requested = Event::from(Requested::new(setup.client_address, [7; 32], None, false));
+
+    let stranger = Pubkey::new_unique();
+    assert_eq!(
+        expected_accounts(
+            &requested,
+            &setup.client,
+            &setup.config,
+            &stranger,
+            &[],
+            &setup.vrf_id,
+        ),
+        Err(ExpectedAccountsError::NotAnAuthority(stranger))
+    );
+
+    let fulfilled = Event::from(Fulfilled::new(setup.client_address, [7; 32], [1; 64]));
+    assert_eq!(
+        expected_accounts(
+            &fulfilled,
+            &setup.client,
+            &setup.config,
+            &setup.oracle,
+            &[],
+            &setup.vrf_id,
+        ),
+        Err(ExpectedAccountsError::NotARequest)
+    );
+}
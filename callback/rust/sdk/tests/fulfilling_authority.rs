@@ -0,0 +1,73 @@
+//! Tests [`fulfillments_with_authority`] on a JSON-encoded transaction.
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Responded},
+    sdk::{fulfillments_with_authority, Event},
+};
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+
+fn transaction(logs: Vec<String>) -> EncodedConfirmedTransactionWithStatusMeta {
+    serde_json::from_value(serde_json::json!({
+        "slot": 1,
+        "blockTime": null,
+        "transaction": {
+            "signatures": [Signature::new_unique().to_string()],
+            "message": {
+                "header": {
+                    "numRequiredSignatures": 1,
+                    "numReadonlySignedAccounts": 0,
+                    "numReadonlyUnsignedAccounts": 0
+                },
+                "accountKeys": [Pubkey::new_unique().to_string()],
+                "recentBlockhash": "11111111111111111111111111111111",
+                "instructions": []
+            }
+        },
+        "meta": {
+            "err": null,
+            "status": {"Ok": null},
+            "fee": 5000,
+            "preBalances": [],
+            "postBalances": [],
+            "logMessages": logs
+        }
+    }))
+    .unwrap()
+}
+
+fn logs(events: &[Event]) -> Vec<String> {
+    let vrf = orao_solana_vrf_cb::id();
+    let mut logs = vec![format!("Program {vrf} invoke [1]")];
+    logs.extend(events.iter().map(Event::to_program_data_log));
+    logs.push(format!("Program {vrf} success"));
+    logs
+}
+
+#[test]
+fn authority_of_matching_response() {
+    let client = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let events: Vec<Event> = vec![
+        Responded::new(authority, client, [1; 32], [2; 64]).into(),
+        Responded::new(other, client, [9; 32], [2; 64]).into(),
+        Fulfilled::new(client, [1; 32], [3; 64]).into(),
+    ];
+
+    let fulfillments = fulfillments_with_authority(&transaction(logs(&events))).unwrap();
+    assert_eq!(fulfillments.len(), 1);
+    assert_eq!(fulfillments[0].0, Some(authority));
+    assert_eq!(fulfillments[0].1.event, events[2]);
+}
+
+#[test]
+fn missing_response() {
+    let events: Vec<Event> = vec![Fulfilled::new(Pubkey::new_unique(), [1; 32], [3; 64]).into()];
+
+    let fulfillments = fulfillments_with_authority(&transaction(logs(&events))).unwrap();
+    assert_eq!(fulfillments.len(), 1);
+    assert_eq!(fulfillments[0].0, None);
+}
@@ -0,0 +1,138 @@
+//! Tests [`fulfillment_cost`] on JSON-encoded transactions.
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::{bs58, ed25519_program, signature::Signature};
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Responded},
+    sdk::{fulfillment_cost, Event},
+};
+use serde_json::{json, Value};
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+
+fn transaction(meta: Value) -> EncodedConfirmedTransactionWithStatusMeta {
+    transaction_with_instructions(vec![Pubkey::new_unique()], json!([]), meta)
+}
+
+fn transaction_with_instructions(
+    account_keys: Vec<Pubkey>,
+    instructions: Value,
+    meta: Value,
+) -> EncodedConfirmedTransactionWithStatusMeta {
+    let account_keys: Vec<_> = account_keys.iter().map(Pubkey::to_string).collect();
+    serde_json::from_value(json!({
+        "slot": 1,
+        "blockTime": null,
+        "transaction": {
+            "signatures": [Signature::new_unique().to_string()],
+            "message": {
+                "header": {
+                    "numRequiredSignatures": 1,
+                    "numReadonlySignedAccounts": 0,
+                    "numReadonlyUnsignedAccounts": 0
+                },
+                "accountKeys": account_keys,
+                "recentBlockhash": "11111111111111111111111111111111",
+                "instructions": instructions
+            }
+        },
+        "meta": meta
+    }))
+    .unwrap()
+}
+
+fn logs(events: &[Event]) -> Vec<String> {
+    let vrf = orao_solana_vrf_cb::id();
+    let mut logs = vec![format!("Program {vrf} invoke [1]")];
+    logs.extend(events.iter().map(Event::to_program_data_log));
+    logs.push(format!("Program {vrf} success"));
+    logs
+}
+
+#[test]
+fn fee_and_compute_units() {
+    let client = Pubkey::new_unique();
+    let fulfilled = Event::from(Fulfilled::new(client, [1; 32], [2; 64]));
+    let events = [
+        Responded::new(Pubkey::new_unique(), client, [1; 32], [2; 64]).into(),
+        fulfilled.clone(),
+    ];
+    let tx = transaction(json!({
+        "err": null,
+        "status": {"Ok": null},
+        "fee": 15_000,
+        "preBalances": [],
+        "postBalances": [],
+        "logMessages": logs(&events),
+        "computeUnitsConsumed": 42_000
+    }));
+
+    let cost = fulfillment_cost(&tx).unwrap();
+    assert_eq!(cost.fulfilled.len(), 1);
+    assert_eq!(cost.fulfilled[0].event, fulfilled);
+    assert_eq!(cost.fee, Some(15_000));
+    assert_eq!(cost.priority_fee, Some(10_000));
+    assert_eq!(cost.compute_units_consumed, Some(42_000));
+}
+
+#[test]
+fn missing_fields() {
+    let tx = transaction(json!({
+        "err": null,
+        "status": {"Ok": null},
+        "fee": 5_000,
+        "preBalances": [],
+        "postBalances": [],
+        "logMessages": []
+    }));
+    let cost = fulfillment_cost(&tx).unwrap();
+    assert!(cost.fulfilled.is_empty());
+    assert_eq!(cost.fee, Some(5_000));
+    assert_eq!(cost.priority_fee, Some(0));
+    assert_eq!(cost.compute_units_consumed, None);
+
+    let tx = transaction(Value::Null);
+    let cost = fulfillment_cost(&tx).unwrap();
+    assert_eq!((cost.fee, cost.priority_fee), (None, None));
+}
+
+#[test]
+fn precompile_signatures_are_base_fee() {
+    let client = Pubkey::new_unique();
+    let events = [Fulfilled::new(client, [1; 32], [2; 64]).into()];
+    let vrf = orao_solana_vrf_cb::id();
+
+    // the Ed25519 instruction verifying two oracle signatures (its first byte
+    // is the number of signatures) followed by the fulfillment
+    let mut ed25519_data = vec![2, 0];
+    ed25519_data.extend([0; 28]);
+    let instructions = json!([
+        {
+            "programIdIndex": 1,
+            "accounts": [],
+            "data": bs58::encode(&ed25519_data).into_string(),
+        },
+        {
+            "programIdIndex": 2,
+            "accounts": [0],
+            "data": bs58::encode([1, 2, 3]).into_string(),
+        },
+    ]);
+    let tx = transaction_with_instructions(
+        vec![Pubkey::new_unique(), ed25519_program::ID, vrf],
+        instructions,
+        json!({
+            "err": null,
+            "status": {"Ok": null},
+            "fee": 3 * 5_000 + 7_000,
+            "preBalances": [],
+            "postBalances": [],
+            "logMessages": logs(&events),
+        }),
+    );
+
+    let cost = fulfillment_cost(&tx).unwrap();
+    assert_eq!(cost.fulfilled.len(), 1);
+    assert_eq!(cost.fee, Some(22_000));
+    assert_eq!(cost.priority_fee, Some(7_000));
+}
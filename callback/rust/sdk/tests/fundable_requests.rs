@@ -0,0 +1,27 @@
+//! Tests of [`fundable_requests`].
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{sdk::fundable_requests, state::network_state::NetworkConfiguration};
+
+fn config(request_fee: u64) -> NetworkConfiguration {
+    NetworkConfiguration::new(Pubkey::new_unique(), Pubkey::new_unique(), request_fee)
+}
+
+#[test]
+fn fee_and_rent() {
+    assert_eq!(fundable_requests(12_999, &config(1_000), 0), 12);
+    assert_eq!(fundable_requests(13_000, &config(1_000), 0), 13);
+    assert_eq!(fundable_requests(12_999, &config(500), 500), 12);
+    assert_eq!(fundable_requests(999, &config(1_000), 0), 0);
+    assert_eq!(fundable_requests(0, &config(1_000), 0), 0);
+}
+
+#[test]
+fn edge_cases() {
+    assert_eq!(fundable_requests(0, &config(0), 0), u64::MAX);
+    assert_eq!(fundable_requests(u64::MAX, &config(0), 1), u64::MAX);
+    // the cost of a request exceeds u64
+    assert_eq!(fundable_requests(u64::MAX, &config(u64::MAX), u64::MAX), 0);
+    assert_eq!(fundable_requests(u64::MAX, &config(u64::MAX), 0), 1);
+}
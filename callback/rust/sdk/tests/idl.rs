@@ -0,0 +1,78 @@
+//! Tests of the [`IdlEventDecoder`] against the program IDL.
+#![cfg(feature = "idl")]
+
+use std::io;
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested, Withdrawn},
+    sdk::{Event, IdlEventDecoder, UnknownEvent},
+};
+use serde_json::json;
+
+const IDL: &str = include_str!("../../../js/src/types/orao_vrf_cb.json");
+
+fn decoder() -> IdlEventDecoder {
+    IdlEventDecoder::from_json(IDL).expect("valid IDL")
+}
+
+#[test]
+fn known_events() {
+    let decoder = decoder();
+    let mut names = decoder.event_names().collect::<Vec<_>>();
+    names.sort_unstable();
+    assert!(names.contains(&"Requested"));
+    assert!(names.contains(&"RequestedAlt"));
+}
+
+#[test]
+fn decodes_built_in_events() {
+    let decoder = decoder();
+    let client = Pubkey::new_unique();
+
+    let fulfilled = decoder
+        .decode(&Event::from(Fulfilled::new(client, [1; 32], [2; 64])).to_bytes())
+        .unwrap();
+    // the IDL qualifies the name because of the `state::request::Fulfilled` type
+    assert_eq!(fulfilled.name, "orao_vrf_cb::events::fulfilled::Fulfilled");
+    assert_eq!(
+        fulfilled.value,
+        json!({ "client": client.to_string(), "seed": vec![1; 32], "randomness": vec![2; 64] })
+    );
+
+    let requested = decoder
+        .decode(&Event::from(Requested::new(client, [3; 32], None, true)).to_bytes())
+        .unwrap();
+    assert_eq!(
+        requested.value,
+        json!({
+            "client": client.to_string(),
+            "seed": vec![3; 32],
+            "callback": null,
+            "callback_override": true,
+        })
+    );
+
+    let owner = Pubkey::new_unique();
+    let withdrawn = decoder
+        .decode(&Event::from(Withdrawn::new(owner, client, 42)).to_bytes())
+        .unwrap();
+    assert_eq!(withdrawn.value["amount"], json!(42));
+}
+
+#[test]
+fn malformed_events() {
+    let decoder = decoder();
+    let bytes = Event::from(Fulfilled::new(Pubkey::new_unique(), [1; 32], [2; 64])).to_bytes();
+
+    let err = decoder.decode(&bytes[..bytes.len() - 1]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+    let err = decoder
+        .decode(&[bytes.as_slice(), &[0]].concat())
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    let err = decoder.decode(&[0xff; 16]).unwrap_err();
+    assert!(err.get_ref().unwrap().is::<UnknownEvent>());
+}
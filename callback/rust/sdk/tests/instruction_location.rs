@@ -0,0 +1,142 @@
+//! Tests [`instruction_for_event`] on JSON-encoded transactions.
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Responded},
+    sdk::{events_in_transaction, instruction_for_event, Event, InstructionLocation},
+};
+use serde_json::json;
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+
+const ED25519_PROGRAM: &str = "Ed25519SigVerify111111111111111111111111111";
+
+fn transaction(
+    account_keys: &[String],
+    programs: &[u8],
+    logs: &[String],
+) -> EncodedConfirmedTransactionWithStatusMeta {
+    let instructions = programs
+        .iter()
+        .map(|x| json!({"programIdIndex": x, "accounts": [], "data": ""}))
+        .collect::<Vec<_>>();
+    serde_json::from_value(json!({
+        "slot": 1,
+        "blockTime": null,
+        "transaction": {
+            "signatures": [Signature::new_unique().to_string()],
+            "message": {
+                "header": {
+                    "numRequiredSignatures": 1,
+                    "numReadonlySignedAccounts": 0,
+                    "numReadonlyUnsignedAccounts": 0
+                },
+                "accountKeys": account_keys,
+                "recentBlockhash": "11111111111111111111111111111111",
+                "instructions": instructions
+            }
+        },
+        "meta": {
+            "err": null,
+            "status": {"Ok": null},
+            "fee": 5_000,
+            "preBalances": [],
+            "postBalances": [],
+            "logMessages": logs
+        }
+    }))
+    .unwrap()
+}
+
+fn location(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    log_index: usize,
+) -> (Pubkey, usize, Option<usize>, usize) {
+    let InstructionLocation {
+        program_id,
+        instruction_index,
+        inner_instruction_index,
+        stack_height,
+        ..
+    } = instruction_for_event(tx, log_index).unwrap();
+    (
+        program_id,
+        instruction_index,
+        inner_instruction_index,
+        stack_height,
+    )
+}
+
+#[test]
+fn cpi_and_top_level_events() {
+    let vrf = orao_solana_vrf_cb::id();
+    let client_program = Pubkey::new_unique();
+    let client = Pubkey::new_unique();
+    let responded = Event::from(Responded::new(
+        Pubkey::new_unique(),
+        client,
+        [1; 32],
+        [2; 64],
+    ));
+    let fulfilled = Event::from(Fulfilled::new(client, [1; 32], [2; 64]));
+
+    let account_keys = [
+        Pubkey::new_unique().to_string(),
+        ED25519_PROGRAM.to_string(),
+        client_program.to_string(),
+        vrf.to_string(),
+    ];
+    let logs = [
+        format!("Program {client_program} invoke [1]"),
+        "Program log: Instruction: Fulfill".to_string(),
+        format!("Program {vrf} invoke [2]"),
+        "Program 11111111111111111111111111111111 invoke [3]".to_string(),
+        "Program 11111111111111111111111111111111 success".to_string(),
+        responded.to_program_data_log(),
+        format!("Program {vrf} success"),
+        format!("Program {client_program} success"),
+        format!("Program {vrf} invoke [1]"),
+        fulfilled.to_program_data_log(),
+        format!("Program {vrf} success"),
+    ];
+    // The precompile instruction does not log an invocation.
+    let tx = transaction(&account_keys, &[1, 2, 3], &logs);
+
+    let events = events_in_transaction(&tx).unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(location(&tx, events[0].log_index), (vrf, 1, Some(0), 2));
+    assert_eq!(location(&tx, events[1].log_index), (vrf, 2, None, 1));
+
+    assert_eq!(location(&tx, 1), (client_program, 1, None, 1));
+    assert_eq!(
+        location(&tx, 3),
+        (
+            "11111111111111111111111111111111".parse().unwrap(),
+            1,
+            Some(1),
+            3
+        )
+    );
+    // The `success` record still belongs to the finishing frame.
+    assert_eq!(location(&tx, 7), (client_program, 1, None, 1));
+}
+
+#[test]
+fn outside_of_invocations() {
+    let vrf = orao_solana_vrf_cb::id();
+    let logs = [
+        format!("Program {vrf} invoke [1]"),
+        format!("Program {vrf} success"),
+        "Program log: stray".to_string(),
+    ];
+    let tx = transaction(
+        &[Pubkey::new_unique().to_string(), vrf.to_string()],
+        &[1],
+        &logs,
+    );
+
+    assert_eq!(location(&tx, 0), (vrf, 0, None, 1));
+    assert_eq!(instruction_for_event(&tx, 2), None);
+    assert_eq!(instruction_for_event(&tx, 3), None);
+}
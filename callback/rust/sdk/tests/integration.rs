@@ -0,0 +1,459 @@
+//! End-to-end test against a local `solana-test-validator`.
+//!
+//! Enabled with the `integration` feature:
+//!
+//! ```sh
+//! cargo test --features integration --test integration
+//! ```
+//!
+//! The test performs the full flow: initializes a VRF instance with a single
+//! fulfill authority, registers the `example-client` program, funds the client,
+//! performs a request via CPI, fulfills it and asserts the emitted events.
+//!
+//! Environment:
+//!
+//! *   `SOLANA_TEST_VALIDATOR` — validator binary (defaults to `solana-test-validator`)
+//! *   `ORAO_VRF_CB_PROGRAM` — path to the VRF program `.so` (if not set,
+//!     the program is cloned from devnet)
+//! *   `EXAMPLE_CLIENT_PROGRAM` — path to the `example-client` program `.so`
+//!     (defaults to `../examples/cpi/target/deploy/example_client.so`)
+#![cfg(feature = "integration")]
+
+use std::{
+    net::{TcpListener, UdpSocket},
+    path::PathBuf,
+    process::{Child, Command, Stdio},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anchor_client::{
+    solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcTransactionConfig},
+    solana_sdk::{
+        commitment_config::CommitmentConfig,
+        ed25519_instruction::new_ed25519_instruction,
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+        signature::{Keypair, Signature},
+        signer::Signer,
+        system_program, sysvar,
+        transaction::Transaction,
+    },
+    Client, Cluster, Program,
+};
+use anchor_lang::{prelude::Pubkey, solana_program::hash::hash, AnchorSerialize};
+use orao_solana_vrf_cb::{
+    sdk::{
+        check_misbehavior, ensure_funded, events_in_transaction, register_client, Event,
+        FairnessProof,
+    },
+    state::{
+        client::Client as ClientAccount, network_state::NetworkState, request::RequestAccount,
+    },
+    ConfigureParams, FulfillParams, InitializeParams, RequestParams,
+};
+
+const EXAMPLE_CLIENT_ID: Pubkey =
+    anchor_lang::pubkey!("EioKxQafFaRbM5Yo1NXp1SP9HaFaqWWErZYpcb1a5eAC");
+const CLIENT_STATE_SEED: &[u8] = b"CLIENT_STATE";
+const ADDITIONAL_ACCOUNT_SEED: &[u8] = b"ADDITIONAL_ACCOUNT";
+
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(120);
+const FULFILL_TIMEOUT: Duration = Duration::from_secs(60);
+/// Number of ports given to the validator via `--dynamic-port-range`.
+const DYNAMIC_PORTS: u16 = 25;
+
+/// A running `solana-test-validator` instance.
+///
+/// The process is killed and the ledger is removed on drop.
+struct TestValidator {
+    child: Child,
+    ledger: PathBuf,
+    rpc_port: u16,
+}
+
+impl TestValidator {
+    fn start(mint: &Pubkey) -> Self {
+        // rpc_port + 1 is the websocket port
+        let rpc_port = free_ports(2);
+        let faucet_port = free_ports(1);
+        let dynamic_port = free_ports(DYNAMIC_PORTS);
+
+        let ledger = std::env::temp_dir().join(format!(
+            "orao-vrf-cb-integration-{}-{rpc_port}",
+            std::process::id()
+        ));
+
+        let example_client = std::env::var("EXAMPLE_CLIENT_PROGRAM").unwrap_or_else(|_| {
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../examples/cpi/target/deploy/example_client.so"
+            )
+            .to_string()
+        });
+
+        let mut command = Command::new(
+            std::env::var("SOLANA_TEST_VALIDATOR")
+                .unwrap_or_else(|_| "solana-test-validator".to_string()),
+        );
+        command
+            .arg("--ledger")
+            .arg(&ledger)
+            .args(["--reset", "--quiet", "--bind-address", "127.0.0.1"])
+            .args(["--rpc-port", &rpc_port.to_string()])
+            .args(["--faucet-port", &faucet_port.to_string()])
+            .args([
+                "--dynamic-port-range",
+                &format!("{}-{}", dynamic_port, dynamic_port + DYNAMIC_PORTS),
+            ])
+            .args(["--mint", &mint.to_string()])
+            // registration requires the payer to be the upgrade authority
+            .args(["--upgradeable-program", &EXAMPLE_CLIENT_ID.to_string()])
+            .arg(example_client)
+            .arg(mint.to_string());
+
+        match std::env::var("ORAO_VRF_CB_PROGRAM") {
+            Ok(path) => {
+                command
+                    .args(["--bpf-program", &orao_solana_vrf_cb::id().to_string()])
+                    .arg(path);
+            }
+            Err(_) => {
+                command.args([
+                    "--url",
+                    "devnet",
+                    "--clone-upgradeable-program",
+                    &orao_solana_vrf_cb::id().to_string(),
+                ]);
+            }
+        }
+
+        let child = command
+            .stdout(Stdio::null())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .expect("unable to spawn solana-test-validator");
+
+        Self {
+            child,
+            ledger,
+            rpc_port,
+        }
+    }
+
+    fn cluster(&self) -> Cluster {
+        Cluster::Custom(
+            format!("http://127.0.0.1:{}", self.rpc_port),
+            format!("ws://127.0.0.1:{}", self.rpc_port + 1),
+        )
+    }
+
+    /// Waits until the validator responds to RPC and produces blocks.
+    async fn wait_ready(&mut self, rpc: &RpcClient) {
+        let started = Instant::now();
+        loop {
+            if let Some(status) = self.child.try_wait().expect("validator status") {
+                panic!("solana-test-validator exited prematurely: {status}");
+            }
+            if rpc.get_health().await.is_ok() && rpc.get_slot().await.unwrap_or_default() > 0 {
+                return;
+            }
+            assert!(
+                started.elapsed() < STARTUP_TIMEOUT,
+                "solana-test-validator did not start in {STARTUP_TIMEOUT:?}"
+            );
+            tokio::time::sleep(Duration::from_millis(500)).await;
+        }
+    }
+}
+
+impl Drop for TestValidator {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = std::fs::remove_dir_all(&self.ledger);
+    }
+}
+
+/// Finds `count` consecutive ports that are free for both TCP and UDP.
+fn free_ports(count: u16) -> u16 {
+    let is_free = |port: u16| {
+        TcpListener::bind(("127.0.0.1", port)).is_ok()
+            && UdpSocket::bind(("127.0.0.1", port)).is_ok()
+    };
+
+    loop {
+        let base = TcpListener::bind("127.0.0.1:0")
+            .and_then(|x| x.local_addr())
+            .expect("unable to pick a port")
+            .port();
+        if base.checked_add(count).is_some() && (base..base + count).all(is_free) {
+            return base;
+        }
+    }
+}
+
+/// Anchor instruction discriminator.
+fn sighash(name: &str) -> [u8; 8] {
+    hash(format!("global:{name}").as_bytes()).to_bytes()[..8]
+        .try_into()
+        .unwrap()
+}
+
+async fn send_instructions(
+    rpc: &RpcClient,
+    payer: &Keypair,
+    instructions: &[Instruction],
+) -> Signature {
+    let blockhash = rpc.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        &[payer],
+        blockhash,
+    );
+    rpc.send_and_confirm_transaction(&tx)
+        .await
+        .expect("transaction failed")
+}
+
+/// Fetches VRF events emitted by the given transaction.
+async fn events(rpc: &RpcClient, signature: &Signature) -> Vec<Event> {
+    let tx = rpc
+        .get_transaction_with_config(
+            signature,
+            RpcTransactionConfig {
+                commitment: Some(CommitmentConfig::confirmed()),
+                max_supported_transaction_version: Some(0),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("transaction not found");
+
+    events_in_transaction(&tx)
+        .expect("malformed events")
+        .into_iter()
+        .map(|x| x.event)
+        .collect()
+}
+
+#[tokio::test]
+async fn request_and_fulfill() {
+    let payer = Arc::new(Keypair::new());
+    let fulfill_authority = Keypair::new();
+
+    let mut validator = TestValidator::start(&payer.pubkey());
+    let client = Client::new_with_options(
+        validator.cluster(),
+        payer.clone(),
+        CommitmentConfig::confirmed(),
+    );
+    let orao_vrf_cb: Program<Arc<Keypair>> = client.program(orao_solana_vrf_cb::id()).unwrap();
+    let rpc = orao_vrf_cb.rpc();
+    validator.wait_ready(&rpc).await;
+
+    // 1. Initialize a VRF instance with a single fulfill authority.
+    let network_state_address = NetworkState::find_address(&orao_vrf_cb.id()).0;
+    orao_vrf_cb
+        .request()
+        .accounts(orao_solana_vrf_cb::accounts::Initialize {
+            payer: payer.pubkey(),
+            network_state: network_state_address,
+            system_program: system_program::ID,
+        })
+        .args(orao_solana_vrf_cb::instruction::Initialize {
+            params: InitializeParams::new(LAMPORTS_PER_SOL / 100),
+        })
+        .send()
+        .await
+        .expect("initialize failed");
+
+    let mut network_state: NetworkState = orao_vrf_cb.account(network_state_address).await.unwrap();
+    network_state.config.fulfill_authorities = vec![fulfill_authority.pubkey()];
+    orao_vrf_cb
+        .request()
+        .accounts(orao_solana_vrf_cb::accounts::Configure {
+            payer: payer.pubkey(),
+            network_state: network_state_address,
+            system_program: system_program::ID,
+        })
+        .args(orao_solana_vrf_cb::instruction::Configure {
+            params: ConfigureParams::new(network_state.config.clone()),
+        })
+        .send()
+        .await
+        .expect("configure failed");
+
+    // 2. Initialize and register the example client.
+    let (client_state, client_state_bump) =
+        Pubkey::find_program_address(&[CLIENT_STATE_SEED], &EXAMPLE_CLIENT_ID);
+    let additional_account =
+        Pubkey::find_program_address(&[ADDITIONAL_ACCOUNT_SEED], &EXAMPLE_CLIENT_ID).0;
+    send_instructions(
+        &rpc,
+        &payer,
+        &[Instruction::new_with_bytes(
+            EXAMPLE_CLIENT_ID,
+            &sighash("initialize"),
+            vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new(client_state, false),
+                AccountMeta::new(additional_account, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+        )],
+    )
+    .await;
+
+    let (_, registered) = register_client(
+        &orao_vrf_cb,
+        EXAMPLE_CLIENT_ID,
+        vec![CLIENT_STATE_SEED.to_vec(), vec![client_state_bump]],
+        None,
+    )
+    .await
+    .expect("register failed");
+    assert_eq!(registered.state, client_state);
+
+    let client_address =
+        ClientAccount::find_address(&EXAMPLE_CLIENT_ID, &client_state, &orao_vrf_cb.id()).0;
+    assert_eq!(registered.client, client_address);
+    let balance = ensure_funded(&orao_vrf_cb, client_address, LAMPORTS_PER_SOL)
+        .await
+        .expect("unable to fund the client");
+    assert!(balance >= LAMPORTS_PER_SOL);
+
+    // 3. Perform a request via CPI.
+    let seed = RequestParams::builder().build().unwrap().params.seed;
+    let request_address = RequestAccount::find_address(&client_address, &seed, &orao_vrf_cb.id()).0;
+    let mut data = sighash("request").to_vec();
+    (seed, None::<(u8, bool)>).serialize(&mut data).unwrap();
+    let request_signature = send_instructions(
+        &rpc,
+        &payer,
+        &[Instruction::new_with_bytes(
+            EXAMPLE_CLIENT_ID,
+            &data,
+            vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(orao_vrf_cb.id(), false),
+                AccountMeta::new(client_state, false),
+                AccountMeta::new(client_address, false),
+                AccountMeta::new(network_state_address, false),
+                AccountMeta::new(network_state.config.treasury, false),
+                AccountMeta::new(request_address, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+        )],
+    )
+    .await;
+
+    // 4. Fulfill it on behalf of the fulfill authority.
+    let message = [client_address.as_ref(), &seed[..]].concat();
+    let dalek_keypair = ed25519_dalek::Keypair::from_bytes(&fulfill_authority.to_bytes()).unwrap();
+    let fulfill_signature = send_instructions(
+        &rpc,
+        &payer,
+        &[
+            new_ed25519_instruction(&dalek_keypair, &message),
+            Instruction::new_with_bytes(
+                orao_vrf_cb.id(),
+                &anchor_lang::InstructionData::data(&orao_solana_vrf_cb::instruction::Fulfill {
+                    params: FulfillParams::new(),
+                }),
+                anchor_lang::ToAccountMetas::to_account_metas(
+                    &orao_solana_vrf_cb::accounts::Fulfill {
+                        payer: payer.pubkey(),
+                        program: EXAMPLE_CLIENT_ID,
+                        state: client_state,
+                        client: client_address,
+                        request: request_address,
+                        network_state: network_state_address,
+                        instruction_acc: sysvar::instructions::ID,
+                    },
+                    None,
+                ),
+            ),
+        ],
+    )
+    .await;
+
+    // 5. Wait for the fulfilled state.
+    let started = Instant::now();
+    let randomness = loop {
+        let request: RequestAccount = orao_vrf_cb.account(request_address).await.unwrap();
+        if let Some(fulfilled) = request.fulfilled() {
+            break fulfilled.randomness;
+        }
+        assert!(
+            started.elapsed() < FULFILL_TIMEOUT,
+            "request is not fulfilled"
+        );
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    };
+    assert_ne!(randomness, [0_u8; 64]);
+
+    // 6. Assert the emitted events.
+    let requested = events(&rpc, &request_signature).await;
+    assert!(
+        requested.iter().any(|event| matches!(
+            event,
+            Event::Requested(x) if x.client == client_address && x.seed == seed && x.callback.is_none()
+        )),
+        "no Requested event in {requested:?}"
+    );
+
+    let fulfilled = events(&rpc, &fulfill_signature).await;
+    assert!(
+        fulfilled.iter().any(|event| matches!(
+            event,
+            Event::Responded(x) if x.authority == fulfill_authority.pubkey()
+                && x.client == client_address
+                && x.seed == seed
+        )),
+        "no Responded event in {fulfilled:?}"
+    );
+    assert!(
+        fulfilled.iter().any(|event| matches!(
+            event,
+            Event::Fulfilled(x) if x.client == client_address
+                && x.seed == seed
+                && x.randomness == randomness
+        )),
+        "no Fulfilled event in {fulfilled:?}"
+    );
+
+    let report = check_misbehavior(
+        &client_address,
+        &seed,
+        &fulfilled,
+        &network_state.config.fulfill_authorities,
+    );
+    assert!(report.is_clean(), "{report:?}");
+
+    // 7. Package a fairness proof of a dice roll.
+    let roll = |randomness: &[u8; 64]| randomness[0] % 6 + 1;
+    let requested_event = requested
+        .iter()
+        .find_map(|event| match event {
+            Event::Requested(x) => Some(x),
+            _ => None,
+        })
+        .unwrap();
+    let fulfilled_event = fulfilled
+        .iter()
+        .find_map(|event| match event {
+            Event::Fulfilled(x) => Some(x),
+            _ => None,
+        })
+        .unwrap();
+    let responses = fulfilled.iter().filter_map(|event| match event {
+        Event::Responded(x) => Some(x),
+        _ => None,
+    });
+    let proof = FairnessProof::build(requested_event, fulfilled_event, responses, roll).unwrap();
+    proof
+        .verify(&network_state.config.fulfill_authorities, roll)
+        .expect("fairness proof is valid");
+}
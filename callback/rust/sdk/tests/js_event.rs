@@ -0,0 +1,87 @@
+//! Tests the [`JsEvent`] representation against JS-shaped fixtures.
+#![cfg(feature = "js")]
+
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested, Withdrawn},
+    sdk::{Event, JsEvent},
+};
+use serde_json::{json, Value};
+
+const CLIENT: &str = "VRFCBePmGTpZ234BhbzNNzmyg39Rgdd6VgdfhHwKypU";
+const OWNER: &str = "11111111111111111111111111111111";
+
+fn roundtrip(fixture: Value, expected: Event) {
+    let parsed: JsEvent = serde_json::from_value(fixture.clone()).unwrap();
+    assert_eq!(parsed.0, expected);
+    assert_eq!(serde_json::to_value(JsEvent(expected)).unwrap(), fixture);
+}
+
+#[test]
+fn fulfilled() {
+    let client = Pubkey::from_str(CLIENT).unwrap();
+    let seed = [1; 32];
+    let randomness = [2; 64];
+    let fixture = json!({
+        "name": "Fulfilled",
+        "data": {
+            "client": CLIENT,
+            "seed": bs58(&seed),
+            "randomness": bs58(&randomness),
+        }
+    });
+    roundtrip(fixture, Fulfilled::new(client, seed, randomness).into());
+}
+
+#[test]
+fn camel_case_fields() {
+    let client = Pubkey::from_str(CLIENT).unwrap();
+    let fixture = json!({
+        "name": "Requested",
+        "data": {
+            "client": CLIENT,
+            "seed": bs58(&[3; 32]),
+            "callback": null,
+            "callbackOverride": true,
+        }
+    });
+    roundtrip(fixture, Requested::new(client, [3; 32], None, true).into());
+
+    let fixture = json!({
+        "name": "Withdrawn",
+        "data": { "owner": OWNER, "client": CLIENT, "amount": 1_000_000 }
+    });
+    roundtrip(
+        fixture,
+        Withdrawn::new(Pubkey::from_str(OWNER).unwrap(), client, 1_000_000).into(),
+    );
+}
+
+#[test]
+fn qualified_name() {
+    let fixture = json!({
+        "name": "orao_vrf_cb::events::fulfilled::Fulfilled",
+        "data": {
+            "client": CLIENT,
+            "seed": bs58(&[1; 32]),
+            "randomness": bs58(&[2; 64]),
+        }
+    });
+    let parsed: JsEvent = serde_json::from_value(fixture).unwrap();
+    assert!(matches!(parsed.0, Event::Fulfilled(_)));
+}
+
+#[test]
+fn malformed() {
+    let fixture = json!({
+        "name": "Fulfilled",
+        "data": { "client": "not base58!", "seed": "", "randomness": "" }
+    });
+    assert!(serde_json::from_value::<JsEvent>(fixture).is_err());
+}
+
+fn bs58(bytes: &[u8]) -> String {
+    anchor_client::solana_sdk::bs58::encode(bytes).into_string()
+}
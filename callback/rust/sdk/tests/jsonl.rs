@@ -0,0 +1,77 @@
+//! Tests of the [`write_jsonl`] exporter.
+#![cfg(feature = "jsonl")]
+
+use std::io::{self, Write};
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested},
+    sdk::{write_jsonl, Event, TaggedEvent},
+};
+
+fn events() -> Vec<Event> {
+    let client = Pubkey::new_unique();
+    vec![
+        Requested::new(client, [1; 32], None, false).into(),
+        Fulfilled::new(client, [1; 32], [2; 64]).into(),
+    ]
+}
+
+/// A writer accepting at most 7 bytes per call that counts flushes.
+#[derive(Default)]
+struct Trickle {
+    data: Vec<u8>,
+    flushes: usize,
+}
+
+impl Write for Trickle {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = buf.len().min(7);
+        self.data.extend_from_slice(&buf[..len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.flushes += 1;
+        Ok(())
+    }
+}
+
+#[test]
+fn one_object_per_line() {
+    let events = events();
+    let mut w = Trickle::default();
+    write_jsonl(events.clone(), &mut w).unwrap();
+
+    assert_eq!(w.flushes, 1);
+    let text = String::from_utf8(w.data).unwrap();
+    assert!(text.ends_with('\n'));
+    let parsed: Vec<Event> = text
+        .lines()
+        .map(|line| serde_json::from_str::<TaggedEvent>(line).unwrap().into())
+        .collect();
+    assert_eq!(parsed, events);
+    assert!(text
+        .lines()
+        .next()
+        .unwrap()
+        .contains(r#""type":"Requested""#));
+}
+
+#[test]
+fn write_errors_are_propagated() {
+    struct Broken;
+
+    impl Write for Broken {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let err = write_jsonl(events(), &mut Broken).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+}
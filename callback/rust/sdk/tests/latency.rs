@@ -0,0 +1,88 @@
+//! Tests [`LatencyStats`] percentiles over synthetic lifecycles.
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested},
+    sdk::{Latency, LatencyStats, LifecycleTracker, ResolvedEvent},
+};
+
+/// Requests fulfilled with the given `(slots, seconds)` latencies.
+fn tracker(latencies: &[(u64, Option<i64>)]) -> LifecycleTracker {
+    let mut tracker = LifecycleTracker::new();
+    for (i, (slots, seconds)) in latencies.iter().enumerate() {
+        let client = Pubkey::new_unique();
+        let seed = [i as u8; 32];
+        let requested_at = 1_000 + i as u64;
+        tracker.push(ResolvedEvent::new(
+            Signature::new_unique(),
+            requested_at,
+            seconds.map(|_| 1_700_000_000),
+            0,
+            0,
+            Requested::new(client, seed, None, false).into(),
+        ));
+        tracker.push(ResolvedEvent::new(
+            Signature::new_unique(),
+            requested_at + slots,
+            seconds.map(|x| 1_700_000_000 + x),
+            0,
+            0,
+            Fulfilled::new(client, seed, [0; 64]).into(),
+        ));
+    }
+    tracker
+}
+
+#[test]
+fn percentiles() {
+    let latencies = (1..=100)
+        .map(|x| (x, Some(x as i64 / 2)))
+        .collect::<Vec<_>>();
+    let stats = LatencyStats::from_lifecycles(tracker(&latencies).lifecycles());
+
+    assert_eq!(stats.len(), 100);
+    assert_eq!(
+        stats.p50(),
+        Some(Latency {
+            slots: 50,
+            seconds: Some(25)
+        })
+    );
+    assert_eq!(stats.p90().unwrap().slots, 90);
+    assert_eq!(stats.p99().unwrap().slots, 99);
+    assert_eq!(stats.percentile(0.0).unwrap().slots, 1);
+    assert_eq!(stats.percentile(100.0).unwrap().slots, 100);
+}
+
+#[test]
+fn ignores_pending_and_missing_block_time() {
+    let mut tracker = tracker(&[(3, None), (5, Some(2))]);
+    tracker.push(ResolvedEvent::new(
+        Signature::new_unique(),
+        1,
+        None,
+        0,
+        0,
+        Requested::new(Pubkey::new_unique(), [9; 32], None, false).into(),
+    ));
+    let stats = LatencyStats::from_lifecycles(tracker.lifecycles());
+
+    assert_eq!(stats.len(), 2);
+    assert_eq!(
+        stats.p99(),
+        Some(Latency {
+            slots: 5,
+            seconds: Some(2)
+        })
+    );
+    assert_eq!(
+        stats.p50(),
+        Some(Latency {
+            slots: 3,
+            seconds: Some(2)
+        })
+    );
+    assert!(LatencyStats::default().p50().is_none());
+}
@@ -0,0 +1,122 @@
+//! Tests of the [`InFlightLimiter`].
+#![cfg(feature = "sdk")]
+
+use std::time::Duration;
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested},
+    sdk::{Event, InFlightLimiter, ResolvedEvent, Seed},
+};
+
+fn resolved(slot: u64, event: Event) -> ResolvedEvent {
+    ResolvedEvent::new(Signature::new_unique(), slot, None, 0, 0, event)
+}
+
+/// Returns `true` if `acquire` completes within a short period.
+async fn acquired(limiter: &InFlightLimiter, seed: [u8; 32]) -> bool {
+    tokio::time::timeout(Duration::from_millis(50), limiter.acquire(seed))
+        .await
+        .is_ok()
+}
+
+#[tokio::test]
+async fn slot_is_freed_by_fulfillment() {
+    let client = Pubkey::new_unique();
+    let limiter = InFlightLimiter::new(client, 1);
+
+    assert!(acquired(&limiter, [1; 32]).await);
+    assert!(limiter.push(&resolved(
+        10,
+        Requested::new(client, [1; 32], None, false).into()
+    )));
+    assert_eq!((limiter.in_flight(), limiter.reserved()), (1, 0));
+    assert!(!acquired(&limiter, [3; 32]).await);
+
+    // other clients are ignored
+    let other = Pubkey::new_unique();
+    assert!(!limiter.push(&resolved(
+        11,
+        Fulfilled::new(other, [1; 32], [2; 64]).into()
+    )));
+
+    let waiter = limiter.acquire([3; 32]);
+    let fulfill = async {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(limiter.push(&resolved(
+            12,
+            Fulfilled::new(client, [1; 32], [2; 64]).into()
+        )));
+    };
+    tokio::time::timeout(
+        Duration::from_secs(5),
+        futures::future::join(waiter, fulfill),
+    )
+    .await
+    .expect("slot is not freed");
+    assert_eq!((limiter.in_flight(), limiter.reserved()), (0, 1));
+}
+
+#[tokio::test]
+async fn reservation_expires() {
+    let limiter = InFlightLimiter::new(Pubkey::new_unique(), 1)
+        .with_reservation_timeout(Duration::from_millis(20));
+
+    assert!(acquired(&limiter, [1; 32]).await);
+    tokio::time::timeout(Duration::from_secs(5), limiter.acquire([2; 32]))
+        .await
+        .expect("reservation is leaked");
+}
+
+#[tokio::test]
+async fn request_expires() {
+    let client = Pubkey::new_unique();
+    let limiter = InFlightLimiter::new(client, 1);
+
+    assert!(acquired(&limiter, [1; 32]).await);
+    limiter.push(&resolved(
+        10,
+        Requested::new(client, [1; 32], None, false).into(),
+    ));
+    assert!(!acquired(&limiter, [2; 32]).await);
+
+    assert!(limiter.expire(20, 10).is_empty());
+    assert_eq!(limiter.expire(21, 10), vec![Seed([1; 32])]);
+    assert!(acquired(&limiter, [2; 32]).await);
+}
+
+#[tokio::test]
+async fn foreign_request_keeps_reservation() {
+    let client = Pubkey::new_unique();
+    let limiter = InFlightLimiter::new(client, 2);
+
+    assert!(acquired(&limiter, [1; 32]).await);
+
+    // another submitter sharing the client
+    assert!(!limiter.push(&resolved(
+        10,
+        Requested::new(client, [9; 32], None, false).into()
+    )));
+    assert_eq!((limiter.in_flight(), limiter.reserved()), (0, 1));
+    assert!(!limiter.push(&resolved(
+        11,
+        Fulfilled::new(client, [9; 32], [2; 64]).into()
+    )));
+
+    assert!(limiter.push(&resolved(
+        12,
+        Requested::new(client, [1; 32], None, false).into()
+    )));
+    assert_eq!((limiter.in_flight(), limiter.reserved()), (1, 0));
+}
+
+#[tokio::test]
+async fn reacquiring_a_seed_is_a_noop() {
+    let limiter = InFlightLimiter::new(Pubkey::new_unique(), 1);
+
+    assert!(acquired(&limiter, [1; 32]).await);
+    assert!(acquired(&limiter, [1; 32]).await);
+    assert_eq!(limiter.reserved(), 1);
+    assert!(!acquired(&limiter, [2; 32]).await);
+}
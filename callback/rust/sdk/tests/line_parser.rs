@@ -0,0 +1,79 @@
+//! Tests of the [`LineParser`] fed with `solana logs` output.
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested},
+    sdk::{Event, LineParser},
+};
+
+fn output(events: &[Event]) -> Vec<String> {
+    let vrf = orao_solana_vrf_cb::id();
+    let other = Pubkey::new_unique();
+    let mut lines = vec![];
+    for event in events {
+        lines.push("Transaction executed in slot 42:".to_string());
+        lines.push("  Signature: 1111".to_string());
+        lines.push("  Status: Ok".to_string());
+        lines.push("  Log Messages:".to_string());
+        lines.push(format!("    Program {vrf} invoke [1]"));
+        lines.push(format!("    {}", event.to_program_data_log()));
+        lines.push(format!("    Program {other} invoke [2]"));
+        // Written by another program, thus ignored.
+        lines.push(format!("    {}", event.to_program_data_log()));
+        lines.push(format!("    Program {other} success"));
+        lines.push(format!("    Program {vrf} success"));
+    }
+    lines
+}
+
+fn events() -> Vec<Event> {
+    let client = Pubkey::new_unique();
+    vec![
+        Requested::new(client, [1; 32], None, false).into(),
+        Fulfilled::new(client, [1; 32], [2; 64]).into(),
+    ]
+}
+
+#[test]
+fn line_by_line() {
+    let events = events();
+    let mut parser = LineParser::new(&orao_solana_vrf_cb::id());
+
+    let mut parsed = vec![];
+    for line in output(&events) {
+        if let Some(event) = parser.push_line(&line) {
+            parsed.push(event.unwrap());
+        }
+    }
+    assert_eq!(parsed, events);
+}
+
+#[test]
+fn split_records() {
+    let events = events();
+    let text = output(&events).join("\n");
+    let mut parser = LineParser::new(&orao_solana_vrf_cb::id());
+
+    // Feed chunks of 5 bytes, so that every record is split.
+    let mut parsed = vec![];
+    for chunk in text.as_bytes().chunks(5) {
+        parsed.extend(parser.push_chunk(std::str::from_utf8(chunk).unwrap()));
+    }
+    assert!(parser.finish().is_none());
+    assert_eq!(
+        parsed.into_iter().collect::<Result<Vec<_>, _>>().unwrap(),
+        events
+    );
+
+    // The trailing record without a newline.
+    let mut parser = LineParser::new(&orao_solana_vrf_cb::id());
+    let vrf = orao_solana_vrf_cb::id();
+    assert!(parser
+        .push_chunk(&format!("Program {vrf} invoke [1]\n"))
+        .is_empty());
+    assert!(parser
+        .push_chunk(&events[0].to_program_data_log())
+        .is_empty());
+    assert_eq!(parser.finish().unwrap().unwrap(), events[0]);
+}
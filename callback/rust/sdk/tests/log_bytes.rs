@@ -0,0 +1,51 @@
+//! Tests [`parse_logs_bytes`] on logs with invalid UTF-8.
+#![cfg(feature = "sdk")]
+
+use std::io;
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::Requested,
+    sdk::{parse_logs, parse_logs_bytes, Event},
+};
+
+#[test]
+fn matches_parse_logs() {
+    let vrf = orao_solana_vrf_cb::id();
+    let event: Event = Requested::new(Pubkey::new_unique(), [1; 32], None, false).into();
+    let logs = [
+        format!("Program {vrf} invoke [1]"),
+        event.to_program_data_log(),
+        format!("Program {vrf} success"),
+    ];
+
+    let from_bytes = parse_logs_bytes(&vrf, logs.iter().map(String::as_bytes))
+        .collect::<io::Result<Vec<_>>>()
+        .unwrap();
+    let from_str = parse_logs(&vrf, &logs)
+        .collect::<io::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(from_bytes, vec![event]);
+    assert_eq!(from_bytes, from_str);
+}
+
+#[test]
+fn invalid_utf8() {
+    let vrf = orao_solana_vrf_cb::id();
+    let event: Event = Requested::new(Pubkey::new_unique(), [1; 32], None, false).into();
+    let invoke = format!("Program {vrf} invoke [1]").into_bytes();
+    let success = format!("Program {vrf} success").into_bytes();
+    let data = event.to_program_data_log().into_bytes();
+    let invalid_log = b"Program log: \xff\xfe".to_vec();
+    let invalid_data = b"Program data: \xc3\x28".to_vec();
+
+    let logs: Vec<&[u8]> = vec![&invoke, &invalid_log, &data, &invalid_data, &success];
+    let events: Vec<_> = parse_logs_bytes(&vrf, &logs).collect();
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].as_ref().unwrap(), &event);
+    assert_eq!(
+        events[1].as_ref().unwrap_err().kind(),
+        io::ErrorKind::InvalidData
+    );
+}
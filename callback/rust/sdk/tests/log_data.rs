@@ -0,0 +1,40 @@
+//! Tests [`Event::try_from_log_data`] and [`Event::try_from_log_data_fields`].
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use orao_solana_vrf_cb::{events::Fulfilled, sdk::Event};
+
+#[test]
+fn raw_log_data() {
+    let event = Event::from(Fulfilled::new(Pubkey::new_unique(), [1; 32], [2; 64]));
+    let bytes = event.to_bytes();
+
+    assert_eq!(Event::try_from_log_data(&bytes).unwrap(), event);
+    assert_eq!(
+        Event::try_from_base64(&STANDARD.encode(&bytes)).unwrap(),
+        event
+    );
+    assert!(Event::try_from_log_data(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn log_data_fields_are_concatenated() {
+    let event = Event::from(Fulfilled::new(Pubkey::new_unique(), [1; 32], [2; 64]));
+    let bytes = event.to_bytes();
+    let (discriminator, payload) = bytes.split_at(8);
+
+    assert_eq!(Event::try_from_log_data_fields(&[&bytes]).unwrap(), event);
+    assert_eq!(
+        Event::try_from_log_data_fields(&[discriminator, payload]).unwrap(),
+        event
+    );
+    // the base64 counterpart — a chunk per field
+    let record = format!(
+        "{} {}",
+        STANDARD.encode(discriminator),
+        STANDARD.encode(payload)
+    );
+    assert_eq!(Event::try_from_base64(&record).unwrap(), event);
+    assert!(Event::try_from_log_data_fields(&[]).is_err());
+}
@@ -0,0 +1,111 @@
+//! Tests [`events_in_logs_notification`] against multi-program `logsSubscribe` notifications.
+#![cfg(feature = "sdk")]
+
+use anchor_client::{
+    solana_client::rpc_response::{Response, RpcLogsResponse, RpcResponseContext},
+    solana_sdk::{
+        instruction::InstructionError, signature::Signature, transaction::TransactionError,
+    },
+};
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Responded},
+    sdk::{events_in_logs_notification, Event},
+};
+
+const SLOT: u64 = 42;
+
+fn notification(logs: Vec<String>, err: Option<TransactionError>) -> Response<RpcLogsResponse> {
+    Response {
+        context: RpcResponseContext::new(SLOT),
+        value: RpcLogsResponse {
+            signature: Signature::new_unique().to_string(),
+            err,
+            logs,
+        },
+    }
+}
+
+/// A transaction where a client program invokes the VRF that invokes back the client,
+/// and both of the programs emit `Program data:` records.
+fn multi_program_logs(vrf_events: &[Event], foreign_event: &Event) -> Vec<String> {
+    let vrf = orao_solana_vrf_cb::id();
+    let client = Pubkey::new_unique();
+
+    let mut logs = vec![
+        "Program ComputeBudget111111111111111111111111111111 invoke [1]".to_string(),
+        "Program ComputeBudget111111111111111111111111111111 success".to_string(),
+        format!("Program {client} invoke [1]"),
+        foreign_event.to_program_data_log(),
+        format!("Program {vrf} invoke [2]"),
+        "Program log: Instruction: Fulfill".to_string(),
+    ];
+    logs.push(vrf_events[0].to_program_data_log());
+    logs.push(format!("Program {client} invoke [3]"));
+    logs.push(foreign_event.to_program_data_log());
+    logs.push(format!("Program {client} success"));
+    logs.extend(vrf_events[1..].iter().map(Event::to_program_data_log));
+    logs.push(format!(
+        "Program {vrf} consumed 42000 of 200000 compute units"
+    ));
+    logs.push(format!("Program {vrf} success"));
+    logs.push(foreign_event.to_program_data_log());
+    logs.push(format!("Program {client} success"));
+    logs
+}
+
+fn vrf_events() -> Vec<Event> {
+    let client = Pubkey::new_unique();
+    let seed = [1; 32];
+    vec![
+        Responded::new(Pubkey::new_unique(), client, seed, [2; 64]).into(),
+        Fulfilled::new(client, seed, [2; 64]).into(),
+    ]
+}
+
+#[test]
+fn extracts_only_vrf_events() {
+    let events = vrf_events();
+    let foreign = Fulfilled::new(Pubkey::new_unique(), [3; 32], [4; 64]).into();
+    let logs = multi_program_logs(&events, &foreign);
+
+    let resolved = events_in_logs_notification(&notification(logs.clone(), None)).unwrap();
+
+    assert_eq!(
+        resolved.iter().map(|x| x.event.clone()).collect::<Vec<_>>(),
+        events
+    );
+    for x in &resolved {
+        assert_eq!(x.slot, SLOT);
+        assert_eq!(logs[x.log_index], x.event.to_program_data_log());
+    }
+    assert!(resolved.windows(2).all(|x| x[0].log_index < x[1].log_index));
+}
+
+#[test]
+fn drops_failed_transactions() {
+    let events = vrf_events();
+    let foreign = Fulfilled::new(Pubkey::new_unique(), [3; 32], [4; 64]).into();
+    let logs = multi_program_logs(&events, &foreign);
+    let err = TransactionError::InstructionError(1, InstructionError::Custom(6000));
+
+    let resolved = events_in_logs_notification(&notification(logs, Some(err))).unwrap();
+
+    assert!(resolved.is_empty());
+}
+
+#[test]
+fn ignores_foreign_garbage() {
+    let vrf = orao_solana_vrf_cb::id();
+    let logs = vec![
+        format!("Program {} invoke [1]", Pubkey::new_unique()),
+        "Program data: not a base64 at all".to_string(),
+        format!("Program {} success", Pubkey::new_unique()),
+        format!("Program {vrf} invoke [1]"),
+        format!("Program {vrf} success"),
+    ];
+
+    let resolved = events_in_logs_notification(&notification(logs, None)).unwrap();
+
+    assert!(resolved.is_empty());
+}
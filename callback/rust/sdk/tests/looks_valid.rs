@@ -0,0 +1,34 @@
+//! Tests of the [`Fulfilled::looks_valid`].
+
+use anchor_lang::{prelude::Pubkey, solana_program::hash::hashv};
+use orao_solana_vrf_cb::events::Fulfilled;
+
+fn fulfilled(randomness: [u8; 64]) -> Fulfilled {
+    Fulfilled::new(Pubkey::default(), [0; 32], randomness)
+}
+
+#[test]
+fn random_bytes_look_valid() {
+    let mut randomness = [0; 64];
+    randomness[..32].copy_from_slice(hashv(&[b"first"]).as_ref());
+    randomness[32..].copy_from_slice(hashv(&[b"second"]).as_ref());
+
+    assert!(fulfilled(randomness).looks_valid());
+}
+
+#[test]
+fn degenerate_randomness() {
+    assert!(!fulfilled([0; 64]).looks_valid());
+    assert!(!fulfilled([0xab; 64]).looks_valid());
+
+    // equal halves
+    let mut randomness = [0; 64];
+    randomness[..32].copy_from_slice(hashv(&[b"half"]).as_ref());
+    randomness[32..].copy_from_slice(hashv(&[b"half"]).as_ref());
+    assert!(!fulfilled(randomness).looks_valid());
+
+    // a single differing byte is enough
+    let mut randomness = [0; 64];
+    randomness[63] = 1;
+    assert!(fulfilled(randomness).looks_valid());
+}
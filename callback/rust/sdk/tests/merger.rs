@@ -0,0 +1,96 @@
+//! Tests [`MultiSourceMerger`] with synthetic event streams.
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::Fulfilled,
+    sdk::{MultiSourceMerger, ResolvedEvent},
+};
+
+fn event(signature: Signature, slot: u64, log_index: usize) -> ResolvedEvent {
+    ResolvedEvent::new(
+        signature,
+        slot,
+        None,
+        log_index,
+        log_index,
+        Fulfilled::new(Pubkey::new_unique(), [0; 32], [0; 64]).into(),
+    )
+}
+
+#[test]
+fn dedupes_and_orders() {
+    let signatures = [Signature::new_unique(), Signature::new_unique()];
+    let a = [event(signatures[1], 20, 0), event(signatures[0], 10, 1)];
+    let b = [event(signatures[0], 10, 0), a[1].clone(), a[0].clone()];
+
+    let mut merger = MultiSourceMerger::new();
+    let mut new = Vec::new();
+    for x in &a {
+        new.push(merger.push(0, x.clone()));
+    }
+    for x in &b {
+        new.push(merger.push(1, x.clone()));
+    }
+
+    assert_eq!(new, [true, true, true, false, false]);
+    assert_eq!(
+        merger
+            .events()
+            .map(|x| (x.slot, x.log_index))
+            .collect::<Vec<_>>(),
+        [(10, 0), (10, 1), (20, 0)]
+    );
+}
+
+#[test]
+fn detects_gaps() {
+    let mut merger = MultiSourceMerger::new();
+    merger.mark_covered(0, 100..=199);
+    merger.mark_covered(1, 150..=249);
+    merger.mark_covered(1, 300..=399);
+    merger.mark_covered(0, 250..=259);
+
+    assert_eq!(merger.gaps(100..=399), [260..=299]);
+    assert_eq!(merger.gaps(50..=450), [50..=99, 260..=299, 400..=450]);
+    assert!(merger.gaps(120..=180).is_empty());
+    assert_eq!(merger.gaps(270..=280), [270..=280]);
+}
+
+#[test]
+fn reports_missed_events() {
+    let mut merger = MultiSourceMerger::new();
+    let seen_by_both = event(Signature::new_unique(), 10, 0);
+    let missed = event(Signature::new_unique(), 11, 0);
+    let outside = event(Signature::new_unique(), 30, 0);
+
+    merger.mark_covered(0, 0..=20);
+    merger.push(0, seen_by_both.clone());
+    merger.push(1, seen_by_both);
+    merger.push(1, missed.clone());
+    merger.push(1, outside);
+
+    assert_eq!(merger.missed_by(0), [&missed]);
+    assert!(merger.missed_by(1).is_empty());
+}
+
+#[test]
+fn drains_in_order() {
+    let mut merger = MultiSourceMerger::new();
+    for slot in [5, 1, 9, 3] {
+        merger.push(0, event(Signature::new_unique(), slot, 0));
+    }
+
+    let drained = merger.drain_until(5);
+    assert_eq!(
+        drained.iter().map(|x| x.slot).collect::<Vec<_>>(),
+        [1, 3, 5]
+    );
+    assert_eq!(merger.events().map(|x| x.slot).collect::<Vec<_>>(), [9]);
+
+    // a late duplicate of a drained event is still recognized
+    assert!(!merger.push(1, drained[0].clone()));
+    merger.forget_until(5);
+    assert!(merger.push(1, drained[0].clone()));
+}
@@ -0,0 +1,52 @@
+//! Tests of the [`with_meta`] and [`map_meta`] adapters.
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use futures::StreamExt;
+use orao_solana_vrf_cb::{
+    events::Requested,
+    sdk::{map_meta, with_meta, ResolvedEvent, Tagged},
+};
+
+fn events() -> Vec<ResolvedEvent> {
+    let client = Pubkey::new_unique();
+    (0..3)
+        .map(|i| {
+            let event = Requested::new(client, [i; 32], None, false).into();
+            ResolvedEvent::new(Signature::new_unique(), 10 + i as u64, None, 0, 1, event)
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn meta_travels_with_events() {
+    let events = events();
+    let mut round = 0;
+    let tagged = with_meta(futures::stream::iter(events.clone()), |_| {
+        round += 1;
+        round
+    });
+    let tagged: Vec<_> = map_meta(tagged, |round| format!("round {round}"))
+        .collect()
+        .await;
+
+    assert_eq!(
+        tagged.iter().map(|x| x.meta.as_str()).collect::<Vec<_>>(),
+        ["round 1", "round 2", "round 3"]
+    );
+    assert_eq!(
+        tagged
+            .into_iter()
+            .map(Tagged::into_event)
+            .collect::<Vec<_>>(),
+        events
+    );
+}
+
+#[test]
+fn map_meta_keeps_event() {
+    let event = events().remove(0);
+    let tagged = Tagged::new(event.clone(), 2_u8).map_meta(|x| x * 2);
+    assert_eq!(tagged, Tagged::new(event, 4));
+}
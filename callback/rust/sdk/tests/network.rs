@@ -0,0 +1,181 @@
+//! Tests of [`Network`].
+#![cfg(feature = "sdk")]
+
+use anchor_client::{
+    solana_client::rpc_response::{Response, RpcLogsResponse, RpcResponseContext},
+    solana_sdk::{hash::Hash, signature::Signature},
+};
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{error::ErrorCode, events::Withdrawn, sdk::*};
+use serde_json::json;
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+
+fn logs(program_id: &Pubkey, event: &Event) -> Vec<String> {
+    vec![
+        format!("Program {program_id} invoke [1]"),
+        event.to_program_data_log(),
+        format!("Program {program_id} success"),
+    ]
+}
+
+#[test]
+fn parse_logs_of_the_network_program() {
+    let event = Event::from(Withdrawn::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        1,
+    ));
+    let fork = Pubkey::new_unique();
+
+    for network in [Network::Mainnet, Network::Devnet] {
+        assert_eq!(network.program_id(), orao_solana_vrf_cb::id());
+        let events = network
+            .parse_logs(logs(&orao_solana_vrf_cb::id(), &event))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(events, vec![event.clone()]);
+        assert_eq!(network.parse_logs(logs(&fork, &event)).count(), 0);
+    }
+
+    let custom = Network::Custom(fork);
+    assert_eq!(custom.parse_logs(logs(&fork, &event)).count(), 1);
+    assert_eq!(
+        custom
+            .parse_logs(logs(&orao_solana_vrf_cb::id(), &event))
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn from_genesis_hash() {
+    let hash = |s: &str| s.parse::<Hash>().unwrap();
+    assert_eq!(
+        Network::from_genesis_hash(&hash("5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dw2N9d")),
+        Some(Network::Mainnet)
+    );
+    assert_eq!(
+        Network::from_genesis_hash(&hash("EtWTRABZaYq6iMfeYKouRu166VU2xqa1wcaWoxPkrZBG")),
+        Some(Network::Devnet)
+    );
+    assert_eq!(Network::from_genesis_hash(&Hash::new_unique()), None);
+}
+
+fn transaction(logs: Vec<String>) -> EncodedConfirmedTransactionWithStatusMeta {
+    serde_json::from_value(json!({
+        "slot": 1,
+        "blockTime": null,
+        "transaction": {
+            "signatures": [Signature::new_unique().to_string()],
+            "message": {
+                "header": {
+                    "numRequiredSignatures": 1,
+                    "numReadonlySignedAccounts": 0,
+                    "numReadonlyUnsignedAccounts": 0
+                },
+                "accountKeys": [Pubkey::new_unique().to_string()],
+                "recentBlockhash": "11111111111111111111111111111111",
+                "instructions": []
+            }
+        },
+        "meta": {
+            "err": null,
+            "status": {"Ok": null},
+            "fee": 5_000,
+            "preBalances": [],
+            "postBalances": [],
+            "logMessages": logs
+        }
+    }))
+    .unwrap()
+}
+
+fn notification(logs: Vec<String>) -> Response<RpcLogsResponse> {
+    Response {
+        context: RpcResponseContext::new(1),
+        value: RpcLogsResponse {
+            signature: Signature::new_unique().to_string(),
+            err: None,
+            logs,
+        },
+    }
+}
+
+#[test]
+fn transaction_events_of_the_network_program() {
+    let event = Event::from(Withdrawn::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        1,
+    ));
+    let fork = Pubkey::new_unique();
+    let custom = Network::Custom(fork);
+
+    let tx = transaction(logs(&fork, &event));
+    let events = custom.events_in_transaction(&tx).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event, event);
+    assert_eq!(custom.events_in_transaction_strict(&tx).unwrap().len(), 1);
+    // a foreign program to the default entrypoint
+    assert!(events_in_transaction(&tx).unwrap().is_empty());
+    assert!(Network::Mainnet
+        .events_in_transaction(&tx)
+        .unwrap()
+        .is_empty());
+
+    let tx = transaction(logs(&orao_solana_vrf_cb::id(), &event));
+    assert!(custom.events_in_transaction(&tx).unwrap().is_empty());
+    assert_eq!(events_in_transaction(&tx).unwrap().len(), 1);
+}
+
+#[test]
+fn notification_events_of_the_network_program() {
+    let event = Event::from(Withdrawn::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        1,
+    ));
+    let fork = Pubkey::new_unique();
+    let custom = Network::Custom(fork);
+
+    let notification = notification(logs(&fork, &event));
+    let events = custom.events_in_logs_notification(&notification).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event, event);
+    assert!(events_in_logs_notification(&notification)
+        .unwrap()
+        .is_empty());
+    assert!(Network::Devnet
+        .events_in_logs_notification(&notification)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn log_lines_and_errors_of_the_network_program() {
+    let event = Event::from(Withdrawn::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        1,
+    ));
+    let fork = Pubkey::new_unique();
+    let custom = Network::Custom(fork);
+
+    let archive = logs(&fork, &event).join("\n");
+    let events: Vec<_> = custom
+        .events_in_log_lines(archive.as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(events, vec![event]);
+    assert_eq!(events_in_log_lines(archive.as_bytes()).count(), 0);
+
+    let failed = [
+        format!("Program {fork} invoke [1]"),
+        format!("Program {fork} failed: custom program error: 0x1771"),
+    ];
+    assert_eq!(
+        custom.program_error_from_logs(&failed),
+        Some(VrfProgramError::Program(ErrorCode::UnexpectedClientProgram))
+    );
+    assert_eq!(VrfProgramError::from_logs(&failed), None);
+}
@@ -0,0 +1,56 @@
+//! Tests of the [`fetch_network_state`] using the mock RPC.
+#![cfg(feature = "sdk")]
+
+use std::collections::HashMap;
+
+use anchor_client::solana_client::{nonblocking::rpc_client::RpcClient, rpc_request::RpcRequest};
+use anchor_lang::{prelude::Pubkey, AccountSerialize};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use orao_solana_vrf_cb::{
+    sdk::fetch_network_state,
+    state::network_state::{NetworkConfiguration, NetworkState},
+};
+
+#[tokio::test]
+async fn network_state_is_decoded() {
+    let treasury = Pubkey::new_unique();
+    let mut data = vec![];
+    NetworkState::new(
+        255,
+        NetworkConfiguration::new(Pubkey::new_unique(), treasury, 1_000),
+    )
+    .try_serialize(&mut data)
+    .unwrap();
+
+    let account = serde_json::json!({
+        "context": { "slot": 1 },
+        "value": {
+            "lamports": 1_000_000,
+            "data": [STANDARD.encode(&data), "base64"],
+            "owner": orao_solana_vrf_cb::id().to_string(),
+            "executable": false,
+            "rentEpoch": 0,
+            "space": data.len(),
+        },
+    });
+    let rpc = RpcClient::new_mock_with_mocks(
+        "succeeds".to_string(),
+        HashMap::from([(RpcRequest::GetAccountInfo, account)]),
+    );
+
+    let state = fetch_network_state(&rpc, &orao_solana_vrf_cb::id())
+        .await
+        .unwrap();
+    assert_eq!(state.bump, 255);
+    assert_eq!(state.config.treasury, treasury);
+    assert_eq!(state.config.request_fee, 1_000);
+}
+
+#[tokio::test]
+async fn missing_network_state() {
+    let rpc = RpcClient::new_mock("succeeds".to_string());
+    let err = fetch_network_state(&rpc, &orao_solana_vrf_cb::id())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, anchor_client::ClientError::AccountNotFound));
+}
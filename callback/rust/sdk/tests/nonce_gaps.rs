@@ -0,0 +1,45 @@
+//! Tests of [`detect_nonce_gaps`].
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested},
+    sdk::{detect_nonce_gaps, Event},
+};
+
+/// A seed holding the little-endian nonce followed by zeros.
+fn seed(nonce: u64) -> [u8; 32] {
+    let mut seed = [0; 32];
+    seed[..8].copy_from_slice(&nonce.to_le_bytes());
+    seed
+}
+
+fn nonce(seed: &[u8; 32]) -> Option<u64> {
+    let (nonce, rest) = seed.split_at(8);
+    rest.iter()
+        .all(|x| *x == 0)
+        .then(|| u64::from_le_bytes(nonce.try_into().unwrap()))
+}
+
+#[test]
+fn missing_nonces() {
+    let client = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let events: Vec<Event> = vec![
+        Requested::new(client, seed(3), None, false).into(),
+        Requested::new(client, seed(5), None, false).into(),
+        // fulfilled only, the request is out of the scan
+        Fulfilled::new(client, seed(6), [0; 64]).into(),
+        Requested::new(client, seed(9), None, false).into(),
+        // another seed scheme
+        Requested::new(client, [0xff; 32], None, false).into(),
+        Requested::new(other, seed(7), None, false).into(),
+    ];
+
+    assert_eq!(detect_nonce_gaps(&client, &events, nonce), vec![4, 7, 8]);
+    assert_eq!(detect_nonce_gaps(&other, &events, nonce), Vec::<u64>::new());
+    assert_eq!(
+        detect_nonce_gaps(&Pubkey::new_unique(), &events, nonce),
+        Vec::<u64>::new()
+    );
+}
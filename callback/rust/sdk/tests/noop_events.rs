@@ -0,0 +1,29 @@
+//! Tests of the [`Transferred::is_noop`] and [`CallbackUpdated::is_noop`].
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::events::{CallbackUpdated, Transferred};
+
+#[test]
+fn transfer_to_the_current_owner() {
+    let (owner, client) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    assert!(Transferred::new(owner, client, owner).is_noop());
+    assert!(!Transferred::new(owner, client, Pubkey::new_unique()).is_noop());
+}
+
+#[test]
+fn unsetting_an_unset_callback() {
+    let (owner, client) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let unset = CallbackUpdated::new(owner, client, false);
+    let set = CallbackUpdated::new(owner, client, true);
+
+    assert!(unset.is_noop(&unset));
+    assert!(!unset.is_noop(&set));
+    assert!(!set.is_noop(&unset));
+    // the callback itself is unknown, so setting it is never a no-op
+    assert!(!set.is_noop(&set));
+
+    // updates of another client are unrelated
+    let other = CallbackUpdated::new(owner, Pubkey::new_unique(), false);
+    assert!(!unset.is_noop(&other));
+}
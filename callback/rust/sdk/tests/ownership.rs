@@ -0,0 +1,78 @@
+//! Tests of the [`clients_for_owner`] and [`first_seen_slot`] queries.
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Registered, Requested, Transferred},
+    sdk::{clients_for_owner, first_seen_slot, Event, ResolvedEvent},
+};
+
+fn resolved(slot: u64, event: impl Into<Event>) -> ResolvedEvent {
+    ResolvedEvent::new(Signature::new_unique(), slot, None, 0, 0, event.into())
+}
+
+fn registered(slot: u64, owner: Pubkey, client: Pubkey) -> ResolvedEvent {
+    let program = Pubkey::new_unique();
+    resolved(
+        slot,
+        Registered::new(owner, program, Pubkey::new_unique(), client),
+    )
+}
+
+#[test]
+fn ownership_follows_transfers() {
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (first, second, third) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+
+    // Given out of order on purpose.
+    let events = [
+        resolved(5, Transferred::new(alice, second, bob)),
+        registered(1, alice, first),
+        registered(2, alice, second),
+        registered(3, bob, third),
+        resolved(6, Transferred::new(bob, third, alice)),
+    ];
+
+    let mut expected = vec![first, third];
+    expected.sort();
+    assert_eq!(clients_for_owner(&events, &alice), expected);
+    assert_eq!(clients_for_owner(&events, &bob), vec![second]);
+    assert!(clients_for_owner(&events, &Pubkey::new_unique()).is_empty());
+}
+
+#[test]
+fn transferred_back() {
+    let (alice, bob, client) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let events = [
+        registered(1, alice, client),
+        resolved(2, Transferred::new(alice, client, bob)),
+        resolved(3, Transferred::new(bob, client, alice)),
+    ];
+
+    assert_eq!(clients_for_owner(&events, &alice), vec![client]);
+    assert!(clients_for_owner(&events, &bob).is_empty());
+}
+
+#[test]
+fn earliest_event_of_client() {
+    let owner = Pubkey::new_unique();
+    let (client, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let events = [
+        resolved(30, Requested::new(client, [1; 32], None, false)),
+        registered(20, owner, client),
+        registered(10, owner, other),
+    ];
+
+    assert_eq!(first_seen_slot(&events, &client), Some(20));
+    assert_eq!(first_seen_slot(&events, &other), Some(10));
+    assert_eq!(first_seen_slot(&events, &Pubkey::new_unique()), None);
+}
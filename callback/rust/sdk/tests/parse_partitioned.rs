@@ -0,0 +1,37 @@
+//! Tests [`parse_logs_partitioned`].
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use orao_solana_vrf_cb::{
+    events::Requested,
+    sdk::{parse_logs_partitioned, Event, PROGRAM_DATA_PREFIX},
+};
+
+#[test]
+fn collects_events_and_errors() {
+    let vrf = orao_solana_vrf_cb::id();
+    let first: Event = Requested::new(Pubkey::new_unique(), [1; 32], None, false).into();
+    let second: Event = Requested::new(Pubkey::new_unique(), [2; 32], None, true).into();
+    let unknown = STANDARD.encode([0xee; 16]);
+    let logs = [
+        format!("Program {vrf} invoke [1]"),
+        first.to_program_data_log(),
+        format!("{PROGRAM_DATA_PREFIX}not base64!"),
+        format!("{PROGRAM_DATA_PREFIX}{unknown}"),
+        second.to_program_data_log(),
+        format!("Program {vrf} success"),
+    ];
+
+    let (events, errors) = parse_logs_partitioned(&vrf, &logs);
+    assert_eq!(events, vec![first, second]);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].index, 2);
+}
+
+#[test]
+fn empty_logs() {
+    let (events, errors) = parse_logs_partitioned(&orao_solana_vrf_cb::id(), Vec::<String>::new());
+    assert!(events.is_empty());
+    assert!(errors.is_empty());
+}
@@ -0,0 +1,91 @@
+//! Tests of the [`partition_by_client`] stream adapter.
+#![cfg(feature = "sdk")]
+
+use std::time::Duration;
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use futures::StreamExt;
+use orao_solana_vrf_cb::{
+    events::Requested,
+    sdk::{partition_by_client, Event, ResolvedEvent},
+};
+
+fn resolved(client: Pubkey, slot: u64) -> ResolvedEvent {
+    let event = Event::from(Requested::new(client, [1; 32], None, false));
+    ResolvedEvent::new(Signature::new_unique(), slot, None, 0, 0, event)
+}
+
+#[tokio::test]
+async fn events_are_routed_by_client() {
+    let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let events = vec![
+        resolved(a, 1),
+        resolved(b, 2),
+        resolved(a, 3),
+        resolved(b, 4),
+        resolved(a, 5),
+    ];
+
+    let mut partitions = partition_by_client(futures::stream::iter(events), 8);
+    let (first, first_events) = partitions.next().await.unwrap();
+    let (second, second_events) = partitions.next().await.unwrap();
+    assert_eq!((first, second), (a, b));
+
+    let slots = |events: Vec<ResolvedEvent>| events.iter().map(|x| x.slot).collect::<Vec<_>>();
+    let (first_events, second_events) = tokio::join!(
+        first_events.collect::<Vec<_>>(),
+        second_events.collect::<Vec<_>>()
+    );
+    assert_eq!(slots(first_events), vec![1, 3, 5]);
+    assert_eq!(slots(second_events), vec![2, 4]);
+    assert!(partitions.next().await.is_none());
+}
+
+#[tokio::test]
+async fn buffers_are_bounded() {
+    let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let events = vec![
+        resolved(a, 1),
+        resolved(a, 2),
+        resolved(a, 3),
+        resolved(b, 4),
+    ];
+
+    let mut partitions = partition_by_client(futures::stream::iter(events), 1);
+    let (_, mut a_events) = partitions.next().await.unwrap();
+
+    // `a` is not consumed, so routing stalls before `b` appears.
+    let stalled = tokio::time::timeout(Duration::from_millis(50), partitions.next()).await;
+    assert!(stalled.is_err());
+
+    assert_eq!(a_events.next().await.unwrap().slot, 1);
+    assert_eq!(a_events.next().await.unwrap().slot, 2);
+    let (client, mut b_events) = partitions.next().await.unwrap();
+    assert_eq!(client, b);
+    assert_eq!(b_events.next().await.unwrap().slot, 4);
+}
+
+#[tokio::test]
+async fn dropped_substream_is_reyielded() {
+    let a = Pubkey::new_unique();
+    let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+    let mut partitions = partition_by_client(receiver_stream(receiver), 4);
+
+    sender.send(resolved(a, 1)).unwrap();
+    let (_, events) = partitions.next().await.unwrap();
+    drop(events);
+
+    sender.send(resolved(a, 2)).unwrap();
+    let (client, mut events) = partitions.next().await.unwrap();
+    assert_eq!(client, a);
+    assert_eq!(events.next().await.unwrap().slot, 2);
+}
+
+fn receiver_stream(
+    receiver: tokio::sync::mpsc::UnboundedReceiver<ResolvedEvent>,
+) -> impl futures::Stream<Item = ResolvedEvent> {
+    futures::stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|x| (x, receiver))
+    })
+}
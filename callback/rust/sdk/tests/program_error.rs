@@ -0,0 +1,60 @@
+//! Tests of the [`VrfProgramError`] decoding.
+#![cfg(feature = "sdk")]
+
+use orao_solana_vrf_cb::{error::ErrorCode, sdk::VrfProgramError};
+
+#[test]
+fn codes_roundtrip() {
+    for code in 6000..6014 {
+        let error = VrfProgramError::from_code(code);
+        assert!(matches!(error, VrfProgramError::Program(_)), "{code}");
+        assert_eq!(error.code(), code);
+    }
+    assert_eq!(
+        VrfProgramError::from_code(6009),
+        VrfProgramError::Program(ErrorCode::Fulfilled)
+    );
+    assert_eq!(
+        VrfProgramError::from_code(6100),
+        VrfProgramError::Unknown(6100)
+    );
+    assert_eq!(
+        VrfProgramError::from_code(2003),
+        VrfProgramError::Framework(2003)
+    );
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        VrfProgramError::from_code(6000).to_string(),
+        "Not authorized (NotAuthorized, code 6000)"
+    );
+    assert_eq!(
+        VrfProgramError::from_code(2003).to_string(),
+        "Anchor constraint error (code 2003)"
+    );
+    assert_eq!(
+        VrfProgramError::from_code(7000).to_string(),
+        "Unknown program error (code 7000)"
+    );
+}
+
+#[test]
+fn parsed_from_logs() {
+    let vrf = orao_solana_vrf_cb::id();
+    let other = anchor_lang::prelude::Pubkey::new_unique();
+    let logs = [
+        format!("Program {vrf} invoke [1]"),
+        format!("Program {other} invoke [2]"),
+        format!("Program {other} failed: custom program error: 0x1"),
+        "Program log: AnchorError occurred. Error Code: Fulfilled.".to_string(),
+        format!("Program {vrf} failed: custom program error: 0x1779"),
+    ];
+
+    assert_eq!(
+        VrfProgramError::from_logs(&logs),
+        Some(VrfProgramError::Program(ErrorCode::Fulfilled))
+    );
+    assert_eq!(VrfProgramError::from_logs(&logs[..4]), None);
+}
@@ -0,0 +1,42 @@
+//! Tests of the fixed-size randomness accessor.
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{events, sdk::*};
+
+#[test]
+fn randomness_accessor() {
+    let fulfilled = Event::from(events::Fulfilled::new(
+        Pubkey::new_unique(),
+        [1; 32],
+        [2; 64],
+    ));
+    let responded = Event::from(events::Responded::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        [1; 32],
+        [3; 64],
+    ));
+    let requested = Event::from(events::Requested::new(
+        Pubkey::new_unique(),
+        [1; 32],
+        None,
+        false,
+    ));
+
+    assert_eq!(fulfilled.randomness(), Some(&[2; 64]));
+    assert_eq!(responded.randomness(), Some(&[3; 64]));
+    assert_eq!(requested.randomness(), None);
+}
+
+#[test]
+fn truncated_randomness_is_rejected() {
+    let event = Event::from(events::Fulfilled::new(
+        Pubkey::new_unique(),
+        [1; 32],
+        [2; 64],
+    ));
+    let bytes = event.to_bytes();
+
+    assert!(Event::try_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
@@ -0,0 +1,161 @@
+//! Tests of the [`RequestReceipt`] built from a tracked lifecycle.
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{CallbackUpdated, CalledBack, Fulfilled, Requested, Responded},
+    sdk::{Event, LifecycleTracker, Randomness, RequestReceipt, ResolvedEvent, Seed},
+};
+
+fn resolved(signature: Signature, slot: u64, index: usize, event: Event) -> ResolvedEvent {
+    ResolvedEvent::new(signature, slot, Some(slot as i64), index, index, event)
+}
+
+#[test]
+fn receipt_of_fulfilled_request() {
+    let client = Pubkey::new_unique();
+    let seed = [1; 32];
+    let request_tx = Signature::new_unique();
+    let fulfill_tx = Signature::new_unique();
+
+    let mut tracker = LifecycleTracker::new();
+    // transactions may arrive out of order
+    tracker.extend([
+        resolved(
+            fulfill_tx,
+            20,
+            0,
+            Responded::new(Pubkey::new_unique(), client, seed, [3; 64]).into(),
+        ),
+        resolved(
+            fulfill_tx,
+            20,
+            1,
+            Fulfilled::new(client, seed, [3; 64]).into(),
+        ),
+        resolved(
+            fulfill_tx,
+            20,
+            2,
+            CalledBack::new(Pubkey::new_unique()).into(),
+        ),
+        resolved(
+            request_tx,
+            10,
+            0,
+            Requested::new(client, seed, None, false).into(),
+        ),
+    ]);
+
+    let lifecycle = tracker.get(&client, &Seed(seed)).unwrap();
+    let receipt = RequestReceipt::from_lifecycle(lifecycle).unwrap();
+    assert_eq!(receipt.request_signature, request_tx);
+    assert_eq!(receipt.fulfill_signature, fulfill_tx);
+    assert_eq!(receipt.randomness, Randomness([3; 64]));
+    assert_eq!(receipt.latency_slots, 10);
+    assert_eq!(receipt.latency_seconds, Some(10));
+    assert!(receipt.callback_invoked);
+    assert_eq!(receipt.num_responses(), 1);
+    assert_eq!(
+        receipt
+            .events
+            .iter()
+            .map(|x| x.event.kind())
+            .collect::<Vec<_>>(),
+        lifecycle
+            .events()
+            .map(|x| x.event.kind())
+            .collect::<Vec<_>>(),
+    );
+    assert!(receipt.callback_updated().is_none());
+
+    let update = resolved(
+        Signature::new_unique(),
+        5,
+        0,
+        CallbackUpdated::new(Pubkey::new_unique(), client, true).into(),
+    );
+    let late_update = resolved(
+        Signature::new_unique(),
+        15,
+        0,
+        CallbackUpdated::new(Pubkey::new_unique(), client, true).into(),
+    );
+    let receipt = receipt
+        .with_callback_updated(late_update)
+        .with_callback_updated(update.clone());
+    assert_eq!(receipt.callback_updated(), Some(&update));
+    assert_eq!(receipt.events.len(), 5);
+}
+
+#[test]
+fn pending_request_has_no_receipt() {
+    let client = Pubkey::new_unique();
+    let tracker = LifecycleTracker::from_iter([resolved(
+        Signature::new_unique(),
+        10,
+        0,
+        Requested::new(client, [1; 32], None, false).into(),
+    )]);
+
+    let lifecycle = tracker.get(&client, &Seed([1; 32])).unwrap();
+    assert!(RequestReceipt::from_lifecycle(lifecycle).is_none());
+}
+
+#[test]
+fn latest_preceding_callback_update_is_kept() {
+    let client = Pubkey::new_unique();
+    let seed = [1; 32];
+    let request_tx = Signature::new_unique();
+    let fulfill_tx = Signature::new_unique();
+
+    let tracker = LifecycleTracker::from_iter([
+        resolved(
+            request_tx,
+            10,
+            1,
+            Requested::new(client, seed, None, false).into(),
+        ),
+        resolved(
+            fulfill_tx,
+            20,
+            0,
+            Fulfilled::new(client, seed, [3; 64]).into(),
+        ),
+    ]);
+    let receipt =
+        RequestReceipt::from_lifecycle(tracker.get(&client, &Seed(seed)).unwrap()).unwrap();
+
+    let update = |signature, slot, index| {
+        resolved(
+            signature,
+            slot,
+            index,
+            CallbackUpdated::new(Pubkey::new_unique(), client, true).into(),
+        )
+    };
+    let earlier = update(Signature::new_unique(), 3, 0);
+    let latest = update(Signature::new_unique(), 7, 0);
+    // within the request transaction
+    let same_tx = update(request_tx, 10, 0);
+    let same_tx_after = update(request_tx, 10, 2);
+    // the order of transactions of the same slot is unknown
+    let same_slot = update(Signature::new_unique(), 10, 0);
+
+    let receipt = receipt
+        .with_callback_updated(latest.clone())
+        .with_callback_updated(earlier)
+        .with_callback_updated(same_slot)
+        .with_callback_updated(same_tx_after);
+    assert_eq!(receipt.callback_updated(), Some(&latest));
+    assert_eq!(receipt.events.len(), 3);
+
+    let receipt = receipt.with_callback_updated(same_tx.clone());
+    assert_eq!(receipt.callback_updated(), Some(&same_tx));
+    assert_eq!(receipt.events.len(), 3);
+
+    // the order of the history does not matter
+    let receipt = receipt.with_callback_updated(latest);
+    assert_eq!(receipt.callback_updated(), Some(&same_tx));
+}
@@ -0,0 +1,40 @@
+//! Tests of the [`reconcile_against_chain`] using the mock RPC.
+#![cfg(feature = "sdk")]
+
+use anchor_client::{
+    solana_client::nonblocking::rpc_client::RpcClient, solana_sdk::signature::Signature,
+};
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{events, sdk::*};
+
+/// The mock RPC reports every transaction as landed at this slot.
+const MOCK_SLOT: u64 = 1;
+
+fn resolved(slot: u64) -> ResolvedEvent {
+    let event = Event::from(events::Fulfilled::new(
+        Pubkey::new_unique(),
+        [1; 32],
+        [2; 64],
+    ));
+    ResolvedEvent::new(Signature::new_unique(), slot, None, 0, 0, event)
+}
+
+#[tokio::test]
+async fn landed_events_are_kept() {
+    let rpc = RpcClient::new_mock("succeeds".to_string());
+    let events = [resolved(MOCK_SLOT), resolved(MOCK_SLOT + 1)];
+
+    let dropped = reconcile_against_chain(&rpc, &events).await.unwrap();
+    assert_eq!(dropped, vec![events[1].clone()]);
+}
+
+#[tokio::test]
+async fn missing_and_failed_events_are_dropped() {
+    for url in ["sig_not_found", "instruction_error"] {
+        let rpc = RpcClient::new_mock(url.to_string());
+        let events = [resolved(MOCK_SLOT), resolved(MOCK_SLOT)];
+
+        let dropped = reconcile_against_chain(&rpc, &events).await.unwrap();
+        assert_eq!(dropped, events.to_vec(), "{url}");
+    }
+}
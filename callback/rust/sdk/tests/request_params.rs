@@ -0,0 +1,42 @@
+//! Tests of [`RequestParamsBuilder`].
+#![cfg(feature = "sdk")]
+
+use orao_solana_vrf_cb::{
+    sdk::{RequestParamsError, Seed},
+    state::client::Callback,
+    RequestParams,
+};
+
+#[test]
+fn defaults() {
+    let first = RequestParams::builder().build().unwrap();
+    let second = RequestParams::builder().build().unwrap();
+    assert_ne!(first.seed(), second.seed());
+    assert!(first.params.callback.is_none());
+    assert!(!first.callback_override());
+
+    let prepared = RequestParams::builder().with_seed([1; 32]).build().unwrap();
+    assert_eq!(prepared.seed(), Seed([1; 32]));
+    assert_eq!(prepared.params.seed, [1; 32]);
+}
+
+#[test]
+fn callback_override() {
+    assert_eq!(
+        RequestParams::builder()
+            .with_callback_override()
+            .build()
+            .unwrap_err(),
+        RequestParamsError::OverrideWithoutCallback
+    );
+
+    for with_override in [false, true] {
+        let mut builder = RequestParams::builder().with_callback(Callback::new(vec![1, 2]));
+        if with_override {
+            builder = builder.with_callback_override();
+        }
+        let prepared = builder.build().unwrap();
+        assert_eq!(prepared.params.callback.as_ref().unwrap().data, vec![1, 2]);
+        assert!(prepared.callback_override());
+    }
+}
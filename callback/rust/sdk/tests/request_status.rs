@@ -0,0 +1,66 @@
+//! Tests [`RequestStatus`] decoding of the request account layouts.
+#![cfg(feature = "sdk")]
+
+use anchor_lang::{prelude::Pubkey, AccountSerialize};
+use orao_solana_vrf_cb::{
+    sdk::{Randomness, RequestStatus},
+    state::{
+        request::{Fulfilled, RequestAccount, RequestState, Response},
+        request_alt::{RequestAltAccount, RequestAltState},
+    },
+};
+
+fn serialize(account: &impl AccountSerialize) -> Vec<u8> {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    data
+}
+
+#[test]
+fn pending_request() {
+    let mut state = RequestState::new(None, false);
+    state
+        .pending_mut()
+        .unwrap()
+        .responses
+        .push(Response::new(Pubkey::new_unique(), [1; 64]));
+    let account = RequestAccount::new(255, 1, Pubkey::new_unique(), [2; 32], state);
+
+    assert_eq!(
+        RequestStatus::from_account_data(&serialize(&account)).unwrap(),
+        RequestStatus::Pending { responses: 1 }
+    );
+}
+
+#[test]
+fn fulfilled_request() {
+    let state = RequestState::Fulfilled(Fulfilled::new([3; 64], None));
+    let account = RequestAccount::new(255, 1, Pubkey::new_unique(), [2; 32], state);
+
+    assert_eq!(
+        RequestStatus::from_account_data(&serialize(&account)).unwrap(),
+        RequestStatus::Fulfilled {
+            randomness: Randomness([3; 64])
+        }
+    );
+}
+
+#[test]
+fn alt_request() {
+    let state = RequestAltState::new(vec![Pubkey::new_unique()], None);
+    let account = RequestAltAccount::new(255, 1, Pubkey::new_unique(), [2; 32], state);
+
+    assert_eq!(
+        RequestStatus::from_account_data(&serialize(&account)).unwrap(),
+        RequestStatus::Pending { responses: 0 }
+    );
+}
+
+#[test]
+fn closed_or_foreign_account() {
+    assert_eq!(
+        RequestStatus::from_account_data(&[]).unwrap(),
+        RequestStatus::NotFound
+    );
+    assert!(RequestStatus::from_account_data(&[0; 100]).is_err());
+}
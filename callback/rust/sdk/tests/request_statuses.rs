@@ -0,0 +1,81 @@
+//! Tests of the [`fetch_request_statuses`] using the mock RPC.
+#![cfg(feature = "sdk")]
+
+use std::collections::HashMap;
+
+use anchor_client::solana_client::{nonblocking::rpc_client::RpcClient, rpc_request::RpcRequest};
+use anchor_lang::{prelude::Pubkey, AccountSerialize};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use orao_solana_vrf_cb::{
+    sdk::{fetch_request_statuses, Randomness, RequestStatus, Seed},
+    state::{
+        request::{Fulfilled, RequestAccount, RequestState},
+        request_alt::{RequestAltAccount, RequestAltState},
+    },
+};
+use serde_json::{json, Value};
+
+fn account(account: &impl AccountSerialize) -> Value {
+    let mut data = vec![];
+    account.try_serialize(&mut data).unwrap();
+    json!({
+        "lamports": 1_000_000,
+        "data": [STANDARD.encode(&data), "base64"],
+        "owner": orao_solana_vrf_cb::id().to_string(),
+        "executable": false,
+        "rentEpoch": 0,
+        "space": data.len(),
+    })
+}
+
+#[tokio::test]
+async fn statuses_in_order() {
+    let client = Pubkey::new_unique();
+    let fulfilled = RequestAccount::new(
+        255,
+        1,
+        client,
+        [1; 32],
+        RequestState::Fulfilled(Fulfilled::new([3; 64], None)),
+    );
+    let pending =
+        RequestAltAccount::new(255, 1, client, [2; 32], RequestAltState::new(vec![], None));
+
+    // [request, request alt] per pair
+    let accounts = json!({
+        "context": { "slot": 1 },
+        "value": [account(&fulfilled), null, null, account(&pending), null, null],
+    });
+    let rpc = RpcClient::new_mock_with_mocks(
+        "succeeds".to_string(),
+        HashMap::from([(RpcRequest::GetMultipleAccounts, accounts)]),
+    );
+
+    let pairs = [
+        (client, Seed([1; 32])),
+        (client, Seed([2; 32])),
+        (client, Seed([3; 32])),
+    ];
+    let statuses = fetch_request_statuses(&rpc, &pairs, &orao_solana_vrf_cb::id())
+        .await
+        .unwrap();
+    assert_eq!(
+        statuses,
+        vec![
+            RequestStatus::Fulfilled {
+                randomness: Randomness([3; 64])
+            },
+            RequestStatus::Pending { responses: 0 },
+            RequestStatus::NotFound,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn no_pairs() {
+    let rpc = RpcClient::new_mock("fails".to_string());
+    let statuses = fetch_request_statuses(&rpc, &[], &orao_solana_vrf_cb::id())
+        .await
+        .unwrap();
+    assert!(statuses.is_empty());
+}
@@ -0,0 +1,68 @@
+//! Tests of the [`check_seed_unused`] using the mock RPC.
+#![cfg(feature = "sdk")]
+
+use std::collections::HashMap;
+
+use anchor_client::solana_client::{nonblocking::rpc_client::RpcClient, rpc_request::RpcRequest};
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    sdk::{check_seed_unused, SeedCheckError},
+    state::request_alt::RequestAltAccount,
+};
+
+#[tokio::test]
+async fn unused_seed() {
+    let rpc = RpcClient::new_mock("succeeds".to_string());
+    check_seed_unused(
+        &rpc,
+        &Pubkey::new_unique(),
+        &[1; 32],
+        &orao_solana_vrf_cb::id(),
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn used_seed() {
+    let client = Pubkey::new_unique();
+    let seed = [1; 32];
+    let accounts = serde_json::json!({
+        "context": { "slot": 1 },
+        "value": [
+            null,
+            {
+                "lamports": 1_000_000,
+                "data": ["", "base64"],
+                "owner": orao_solana_vrf_cb::id().to_string(),
+                "executable": false,
+                "rentEpoch": 0,
+                "space": 0,
+            },
+        ],
+    });
+    let rpc = RpcClient::new_mock_with_mocks(
+        "succeeds".to_string(),
+        HashMap::from([(RpcRequest::GetMultipleAccounts, accounts)]),
+    );
+
+    let err = check_seed_unused(&rpc, &client, &seed, &orao_solana_vrf_cb::id())
+        .await
+        .unwrap_err();
+    let expected = RequestAltAccount::find_address(&client, &seed, &orao_solana_vrf_cb::id()).0;
+    assert!(matches!(err, SeedCheckError::Used { request } if request == expected));
+}
+
+#[tokio::test]
+async fn rpc_error() {
+    let rpc = RpcClient::new_mock("fails".to_string());
+    let err = check_seed_unused(
+        &rpc,
+        &Pubkey::new_unique(),
+        &[1; 32],
+        &orao_solana_vrf_cb::id(),
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, SeedCheckError::Client(_)));
+}
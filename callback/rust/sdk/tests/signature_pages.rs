@@ -0,0 +1,174 @@
+//! Tests [`signature_pages`] against a mocked `getSignaturesForAddress` RPC method.
+#![cfg(feature = "sdk")]
+
+use std::collections::HashSet;
+
+use anchor_client::{
+    solana_client::{
+        client_error::Result as ClientResult,
+        nonblocking::rpc_client::RpcClient,
+        rpc_client::RpcClientConfig,
+        rpc_request::RpcRequest,
+        rpc_response::RpcConfirmedTransactionStatusWithSignature,
+        rpc_sender::{RpcSender, RpcTransportStats},
+    },
+    solana_sdk::{commitment_config::CommitmentConfig, signature::Signature},
+};
+use anchor_lang::prelude::Pubkey;
+use futures::TryStreamExt;
+use orao_solana_vrf_cb::sdk::{signature_pages, SignaturePager};
+
+/// Serves signatures of a single address following the RPC paging rules.
+struct MockLedger {
+    /// Newest first.
+    signatures: Vec<Signature>,
+    /// Mimics providers that return the `before` signature itself.
+    inclusive_before: bool,
+}
+
+impl MockLedger {
+    fn new(len: usize) -> Self {
+        Self {
+            signatures: (0..len).map(|_| Signature::new_unique()).collect(),
+            inclusive_before: false,
+        }
+    }
+
+    fn position(&self, signature: &serde_json::Value) -> Option<usize> {
+        let signature = signature.as_str()?;
+        self.signatures
+            .iter()
+            .position(|x| x.to_string() == signature)
+    }
+
+    fn page(&self, config: &serde_json::Value) -> Vec<RpcConfirmedTransactionStatusWithSignature> {
+        let start = match self.position(&config["before"]) {
+            Some(position) if self.inclusive_before => position,
+            Some(position) => position + 1,
+            None => 0,
+        };
+        let end = self
+            .position(&config["until"])
+            .unwrap_or(self.signatures.len());
+        let limit = config["limit"].as_u64().unwrap() as usize;
+        assert!(limit <= SignaturePager::MAX_LIMIT);
+
+        self.signatures[start..end.max(start)]
+            .iter()
+            .take(limit)
+            .enumerate()
+            .map(
+                |(i, signature)| RpcConfirmedTransactionStatusWithSignature {
+                    signature: signature.to_string(),
+                    slot: (self.signatures.len() - start - i) as u64,
+                    err: None,
+                    memo: None,
+                    block_time: None,
+                    confirmation_status: None,
+                },
+            )
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl RpcSender for MockLedger {
+    async fn send(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> ClientResult<serde_json::Value> {
+        assert_eq!(request, RpcRequest::GetSignaturesForAddress);
+        Ok(serde_json::to_value(self.page(&params[1])).unwrap())
+    }
+
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        RpcTransportStats::default()
+    }
+
+    fn url(&self) -> String {
+        "mock".into()
+    }
+}
+
+/// Returns the yielded pages and the ledger signatures for comparison.
+async fn collect_pages(
+    ledger: MockLedger,
+    until: Option<usize>,
+) -> (Vec<Vec<Signature>>, Vec<Signature>) {
+    let signatures = ledger.signatures.clone();
+    let until = until.map(|i| signatures[i]);
+    let rpc = RpcClient::new_sender(
+        ledger,
+        RpcClientConfig::with_commitment(CommitmentConfig::confirmed()),
+    );
+
+    let pages = signature_pages(&rpc, Pubkey::new_unique(), until)
+        .map_ok(|page| {
+            page.into_iter()
+                .map(|x| x.signature.parse().unwrap())
+                .collect::<Vec<_>>()
+        })
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    (pages, signatures)
+}
+
+fn assert_no_gaps(pages: &[Vec<Signature>], expected: &[Signature]) {
+    let flat = pages.iter().flatten().copied().collect::<Vec<_>>();
+    assert_eq!(flat.len(), flat.iter().collect::<HashSet<_>>().len());
+    assert_eq!(flat, expected);
+}
+
+#[tokio::test]
+async fn walks_multiple_pages() {
+    let (pages, signatures) = collect_pages(MockLedger::new(2503), None).await;
+
+    assert_eq!(
+        pages.iter().map(Vec::len).collect::<Vec<_>>(),
+        [1000, 1000, 503]
+    );
+    assert_no_gaps(&pages, &signatures);
+}
+
+#[tokio::test]
+async fn skips_trailing_empty_page() {
+    let (pages, signatures) = collect_pages(MockLedger::new(2000), None).await;
+
+    assert_eq!(pages.iter().map(Vec::len).collect::<Vec<_>>(), [1000, 1000]);
+    assert_no_gaps(&pages, &signatures);
+}
+
+#[tokio::test]
+async fn stops_at_until() {
+    let (pages, signatures) = collect_pages(MockLedger::new(2503), Some(1500)).await;
+
+    assert_eq!(pages.iter().map(Vec::len).collect::<Vec<_>>(), [1000, 500]);
+    assert_no_gaps(&pages, &signatures[..1500]);
+}
+
+#[tokio::test]
+async fn until_on_page_boundary() {
+    let (pages, signatures) = collect_pages(MockLedger::new(2503), Some(1000)).await;
+
+    assert_eq!(pages.iter().map(Vec::len).collect::<Vec<_>>(), [1000]);
+    assert_no_gaps(&pages, &signatures[..1000]);
+}
+
+#[tokio::test]
+async fn tolerates_inclusive_before() {
+    let mut ledger = MockLedger::new(2503);
+    ledger.inclusive_before = true;
+    let (pages, signatures) = collect_pages(ledger, None).await;
+
+    assert_no_gaps(&pages, &signatures);
+}
+
+#[tokio::test]
+async fn empty_history() {
+    let (pages, _) = collect_pages(MockLedger::new(0), None).await;
+
+    assert!(pages.is_empty());
+}
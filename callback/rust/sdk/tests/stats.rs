@@ -0,0 +1,42 @@
+//! Tests of the [`EventStats`] aggregate.
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested, Withdrawn},
+    sdk::{Event, EventKind, EventStats},
+};
+
+#[test]
+fn event_stats() {
+    let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let events: Vec<Event> = vec![
+        Requested::new(first, [1; 32], None, false).into(),
+        Requested::new(first, [2; 32], None, false).into(),
+        // a duplicate
+        Requested::new(first, [2; 32], None, false).into(),
+        Fulfilled::new(first, [1; 32], [0; 64]).into(),
+        // a request made before the observed period
+        Fulfilled::new(second, [3; 32], [0; 64]).into(),
+        Withdrawn::new(Pubkey::new_unique(), second, 100).into(),
+        Withdrawn::new(Pubkey::new_unique(), second, 50).into(),
+    ];
+
+    let stats: EventStats = events.iter().collect();
+    assert_eq!(stats, events.into_iter().collect());
+
+    assert_eq!(stats.total(), 7);
+    assert_eq!(stats.count(EventKind::Requested), 3);
+    assert_eq!(stats.count(EventKind::Fulfilled), 2);
+    assert_eq!(stats.count(EventKind::Responded), 0);
+    assert_eq!(stats.unique_clients(), 2);
+    assert_eq!(stats.total_withdrawn(), 150);
+    assert_eq!(stats.completion_rate(), Some(0.5));
+}
+
+#[test]
+fn empty_stats() {
+    let stats = EventStats::from_iter(Vec::<Event>::new());
+    assert_eq!(stats.total(), 0);
+    assert_eq!(stats.completion_rate(), None);
+}
@@ -0,0 +1,74 @@
+//! Tests [`events_in_transaction_strict`] on successful and failed transactions.
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::Fulfilled,
+    sdk::{events_in_transaction, events_in_transaction_strict, Event},
+};
+use serde_json::{json, Value};
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+
+fn transaction(
+    err: Value,
+    status: Value,
+    logs: Vec<String>,
+) -> EncodedConfirmedTransactionWithStatusMeta {
+    serde_json::from_value(json!({
+        "slot": 1,
+        "blockTime": null,
+        "transaction": {
+            "signatures": [Signature::new_unique().to_string()],
+            "message": {
+                "header": {
+                    "numRequiredSignatures": 1,
+                    "numReadonlySignedAccounts": 0,
+                    "numReadonlyUnsignedAccounts": 0
+                },
+                "accountKeys": [Pubkey::new_unique().to_string()],
+                "recentBlockhash": "11111111111111111111111111111111",
+                "instructions": []
+            }
+        },
+        "meta": {
+            "err": err,
+            "status": status,
+            "fee": 5000,
+            "preBalances": [],
+            "postBalances": [],
+            "logMessages": logs
+        }
+    }))
+    .unwrap()
+}
+
+fn logs(event: &Event) -> Vec<String> {
+    let vrf = orao_solana_vrf_cb::id();
+    vec![
+        format!("Program {vrf} invoke [1]"),
+        event.to_program_data_log(),
+        format!("Program {vrf} failed: custom program error: 0x1770"),
+    ]
+}
+
+#[test]
+fn failed_transaction_has_no_events() {
+    let event: Event = Fulfilled::new(Pubkey::new_unique(), [1; 32], [2; 64]).into();
+    let err = json!({"InstructionError": [0, {"Custom": 6000}]});
+    let tx = transaction(err.clone(), json!({"Err": err}), logs(&event));
+
+    // the logs of the failed transaction do contain the event
+    assert_eq!(events_in_transaction(&tx).unwrap().len(), 1);
+    assert!(events_in_transaction_strict(&tx).unwrap().is_empty());
+}
+
+#[test]
+fn successful_transaction() {
+    let event: Event = Fulfilled::new(Pubkey::new_unique(), [1; 32], [2; 64]).into();
+    let tx = transaction(Value::Null, json!({"Ok": null}), logs(&event));
+
+    let events = events_in_transaction_strict(&tx).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event, event);
+}
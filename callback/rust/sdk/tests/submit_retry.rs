@@ -0,0 +1,189 @@
+//! Tests of [`submit_with_retry`] using the mock RPC.
+#![cfg(feature = "sdk")]
+
+use std::{collections::HashMap, convert::Infallible};
+
+use anchor_client::solana_client::{
+    client_error::Result as ClientResult,
+    nonblocking::rpc_client::RpcClient,
+    rpc_client::RpcClientConfig,
+    rpc_request::RpcRequest,
+    rpc_sender::{RpcSender, RpcTransportStats},
+};
+use anchor_lang::{prelude::Pubkey, AccountSerialize};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use orao_solana_vrf_cb::{
+    sdk::{submit_with_retry, Seed, SubmitRetryError},
+    state::request::{Fulfilled, RequestAccount, RequestState},
+    RequestParams,
+};
+use serde_json::{json, Value};
+
+fn account_json(data: &[u8], owner: &Pubkey) -> Value {
+    json!({
+        "lamports": 1_000_000,
+        "data": [STANDARD.encode(data), "base64"],
+        "owner": owner.to_string(),
+        "executable": false,
+        "rentEpoch": 0,
+        "space": data.len(),
+    })
+}
+
+/// Serves a single request account at its address only.
+struct MockProgram {
+    address: Pubkey,
+    owner: Pubkey,
+    data: Vec<u8>,
+}
+
+#[async_trait::async_trait]
+impl RpcSender for MockProgram {
+    async fn send(&self, request: RpcRequest, params: Value) -> ClientResult<Value> {
+        match request {
+            RpcRequest::GetSlot => Ok(json!(1)),
+            RpcRequest::GetMultipleAccounts => {
+                let accounts: Vec<_> = params[0]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(
+                        |address| match address.as_str() == Some(&self.address.to_string()) {
+                            true => account_json(&self.data, &self.owner),
+                            false => Value::Null,
+                        },
+                    )
+                    .collect();
+                Ok(json!({ "context": { "slot": 1 }, "value": accounts }))
+            }
+            _ => unimplemented!("{request}"),
+        }
+    }
+
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        RpcTransportStats::default()
+    }
+
+    fn url(&self) -> String {
+        "mock".into()
+    }
+}
+
+#[tokio::test]
+async fn fulfilled_at_first_attempt() {
+    let client = Pubkey::new_unique();
+    let request = RequestAccount::new(
+        255,
+        1,
+        client,
+        [1; 32],
+        RequestState::Fulfilled(Fulfilled::new([3; 64], None)),
+    );
+    let mut data = vec![];
+    request.try_serialize(&mut data).unwrap();
+    let accounts = json!({
+        "context": { "slot": 1 },
+        "value": [account_json(&data, &orao_solana_vrf_cb::id()), null],
+    });
+    let rpc = RpcClient::new_mock_with_mocks(
+        "succeeds".to_string(),
+        HashMap::from([(RpcRequest::GetMultipleAccounts, accounts)]),
+    );
+
+    let mut submitted = vec![];
+    let result = submit_with_retry(
+        &rpc,
+        &client,
+        RequestParams::builder().with_seed([1; 32]),
+        3,
+        150,
+        &orao_solana_vrf_cb::id(),
+        |prepared| {
+            submitted.push(prepared.seed());
+            async { Ok::<_, Infallible>(()) }
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(submitted, vec![Seed([1; 32])]);
+    assert_eq!(result.attempts, 1);
+    assert_eq!(result.fulfilled.seed, [1; 32]);
+    assert_eq!(result.fulfilled.randomness, [3; 64]);
+    assert!(result.superseded.is_empty());
+}
+
+#[tokio::test]
+async fn submission_error() {
+    // `usize::MAX` attempts mean "until fulfilled", so nothing is allocated upfront.
+    for max_attempts in [3, usize::MAX] {
+        let rpc = RpcClient::new_mock("succeeds".to_string());
+        let result = submit_with_retry(
+            &rpc,
+            &Pubkey::new_unique(),
+            RequestParams::builder(),
+            max_attempts,
+            150,
+            &orao_solana_vrf_cb::id(),
+            |_| async { Err("rejected") },
+        )
+        .await;
+        assert!(matches!(result, Err(SubmitRetryError::Submit("rejected"))));
+    }
+}
+
+#[tokio::test]
+async fn no_attempts() {
+    let rpc = RpcClient::new_mock("fails".to_string());
+    let result = submit_with_retry(
+        &rpc,
+        &Pubkey::new_unique(),
+        RequestParams::builder(),
+        0,
+        150,
+        &orao_solana_vrf_cb::id(),
+        |_| async { Ok::<_, Infallible>(()) },
+    )
+    .await;
+    assert!(matches!(result, Err(SubmitRetryError::Expired { seeds }) if seeds.is_empty()));
+}
+
+#[tokio::test]
+async fn custom_program_id() {
+    let (client, vrf_id) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let request = RequestAccount::new(
+        255,
+        1,
+        client,
+        [1; 32],
+        RequestState::Fulfilled(Fulfilled::new([3; 64], None)),
+    );
+    let mut data = vec![];
+    request.try_serialize(&mut data).unwrap();
+    let rpc = RpcClient::new_sender(
+        MockProgram {
+            address: RequestAccount::find_address(&client, &[1; 32], &vrf_id).0,
+            owner: vrf_id,
+            data,
+        },
+        RpcClientConfig::default(),
+    );
+
+    // the slot never advances, so polling the wrong address would never end
+    let result = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        submit_with_retry(
+            &rpc,
+            &client,
+            RequestParams::builder().with_seed([1; 32]),
+            1,
+            150,
+            &vrf_id,
+            |_| async { Ok::<_, Infallible>(()) },
+        ),
+    )
+    .await
+    .expect("the request account of the custom program is not polled")
+    .unwrap();
+    assert_eq!(result.fulfilled.randomness, [3; 64]);
+}
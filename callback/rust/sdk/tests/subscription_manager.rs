@@ -0,0 +1,157 @@
+//! Tests of the [`SubscriptionManager`] routing using notification fixtures.
+#![cfg(feature = "sdk")]
+
+use anchor_client::{
+    solana_client::rpc_response::{Response, RpcLogsResponse, RpcResponseContext},
+    solana_sdk::{
+        commitment_config::CommitmentConfig, instruction::InstructionError, signature::Signature,
+        transaction::TransactionError,
+    },
+};
+use anchor_lang::prelude::Pubkey;
+use futures::{FutureExt, StreamExt};
+use orao_solana_vrf_cb::{
+    events::{CalledBack, Fulfilled, Requested},
+    sdk::*,
+};
+
+/// Nothing listens there, so the manager only receives routed notifications.
+const UNREACHABLE_WS_URL: &str = "ws://127.0.0.1:1";
+
+fn manager() -> SubscriptionManager {
+    SubscriptionManager::new(UNREACHABLE_WS_URL, CommitmentConfig::confirmed())
+}
+
+fn notification(logs: Vec<String>, err: Option<TransactionError>) -> Response<RpcLogsResponse> {
+    Response {
+        context: RpcResponseContext::new(42),
+        value: RpcLogsResponse {
+            signature: Signature::new_unique().to_string(),
+            err,
+            logs,
+        },
+    }
+}
+
+fn vrf_logs(events: &[Event]) -> Vec<String> {
+    let vrf = orao_solana_vrf_cb::id();
+    let mut logs = vec![format!("Program {vrf} invoke [1]")];
+    logs.extend(events.iter().map(Event::to_program_data_log));
+    logs.push(format!("Program {vrf} success"));
+    logs
+}
+
+/// Returns the events buffered by the subscription.
+fn received(subscription: &mut Subscription) -> Vec<Event> {
+    let mut events = vec![];
+    while let Some(Some(resolved)) = subscription.next().now_or_never() {
+        events.push(resolved.event);
+    }
+    events
+}
+
+#[test]
+fn filter_matching() {
+    let client = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+
+    let all = EventFilter::all();
+    assert!(all.matches(None, EventKind::CalledBack));
+    assert!(all.matches(Some(&client), EventKind::Fulfilled));
+
+    let by_client = EventFilter::all().with_client(client);
+    assert!(by_client.matches(Some(&client), EventKind::Requested));
+    assert!(!by_client.matches(Some(&other), EventKind::Requested));
+    assert!(!by_client.matches(None, EventKind::CalledBack));
+
+    let by_kind = EventFilter::all()
+        .with_kind(EventKind::Fulfilled)
+        .with_kind(EventKind::Requested);
+    assert!(by_kind.matches(Some(&other), EventKind::Fulfilled));
+    assert!(by_kind.matches(None, EventKind::Requested));
+    assert!(!by_kind.matches(Some(&client), EventKind::Responded));
+
+    let both = by_client.with_kind(EventKind::Fulfilled);
+    assert!(both.matches(Some(&client), EventKind::Fulfilled));
+    assert!(!both.matches(Some(&client), EventKind::Requested));
+    assert!(!both.matches(Some(&other), EventKind::Fulfilled));
+}
+
+#[tokio::test]
+async fn routes_events_to_matching_subscriptions() {
+    let client = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let fulfilled = Event::from(Fulfilled::new(client, [1; 32], [2; 64]));
+    let called_back = Event::from(CalledBack::new(Pubkey::new_unique()));
+    let requested = Event::from(Requested::new(other, [3; 32], None, false));
+
+    let manager = manager();
+    let mut all = manager.subscribe(EventFilter::all());
+    let mut by_client = manager.subscribe(EventFilter::all().with_client(client));
+    let mut by_kind = manager.subscribe(EventFilter::all().with_kind(EventKind::Requested));
+    assert_eq!(manager.num_subscriptions(), 3);
+
+    manager.route(&notification(
+        vrf_logs(&[fulfilled.clone(), called_back.clone(), requested.clone()]),
+        None,
+    ));
+
+    assert_eq!(
+        received(&mut all),
+        vec![fulfilled.clone(), called_back.clone(), requested.clone()]
+    );
+    // `CalledBack` is routed as the preceding `Fulfilled`.
+    assert_eq!(received(&mut by_client), vec![fulfilled, called_back]);
+    assert_eq!(received(&mut by_kind), vec![requested.clone()]);
+
+    // The filter is replaced in place.
+    assert!(by_kind.set_filter(EventFilter::all().with_client(client)));
+    manager.route(&notification(vrf_logs(&[requested]), None));
+    assert!(received(&mut by_kind).is_empty());
+    assert_eq!(received(&mut all).len(), 1);
+
+    drop(by_client);
+    assert_eq!(manager.num_subscriptions(), 2);
+}
+
+#[tokio::test]
+async fn skips_failed_and_malformed_notifications() {
+    let client = Pubkey::new_unique();
+    let fulfilled = Event::from(Fulfilled::new(client, [1; 32], [2; 64]));
+
+    let manager = manager();
+    let mut all = manager.subscribe(EventFilter::all());
+
+    let err = TransactionError::InstructionError(0, InstructionError::Custom(1));
+    manager.route(&notification(
+        vrf_logs(std::slice::from_ref(&fulfilled)),
+        Some(err),
+    ));
+    assert!(received(&mut all).is_empty());
+    assert_eq!(manager.parse_errors(), 0);
+
+    let mut logs = vrf_logs(&[fulfilled]);
+    logs.insert(1, "Program data: not base64!".to_string());
+    manager.route(&notification(logs, None));
+    assert!(received(&mut all).is_empty());
+    assert_eq!(manager.parse_errors(), 1);
+}
+
+#[tokio::test]
+async fn slow_subscription_lags() {
+    let client = Pubkey::new_unique();
+    let events = (0..3_u8)
+        .map(|x| Event::from(Fulfilled::new(client, [x; 32], [2; 64])))
+        .collect::<Vec<_>>();
+
+    let manager = manager();
+    let mut slow = manager.subscribe_with_buffer(EventFilter::all(), 1);
+    let mut fast = manager.subscribe(EventFilter::all());
+
+    manager.route(&notification(vrf_logs(&events), None));
+
+    assert_eq!(received(&mut slow), vec![events[0].clone()]);
+    assert_eq!(slow.lagged(), 2);
+    assert_eq!(received(&mut fast), events);
+    assert_eq!(fast.lagged(), 0);
+}
@@ -0,0 +1,48 @@
+//! Tests of [`summarize_transaction`].
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{events, sdk::*};
+
+#[test]
+fn lifecycle_order() {
+    let key = Pubkey::new_unique();
+    let events: Vec<Event> = vec![
+        events::Fulfilled::new(key, [1; 32], [2; 64]).into(),
+        events::Requested::new(key, [1; 32], None, false).into(),
+        events::RequestedAlt::new(key, [2; 32], None, vec![]).into(),
+        events::Responded::new(key, key, [1; 32], [2; 64]).into(),
+        events::CalledBack::new(key).into(),
+        events::Registered::new(key, key, key, key).into(),
+    ];
+    assert_eq!(
+        summarize_transaction(&events),
+        "registered 1 client, requested 2 randoms, received 1 response, fulfilled 1, called back 1"
+    );
+    assert_eq!(summarize_transaction(&[]), "no VRF activity");
+}
+
+#[test]
+fn exact_withdrawn_amounts() {
+    let key = Pubkey::new_unique();
+    let withdrawn = |amount| Event::from(events::Withdrawn::new(key, key, amount));
+
+    assert_eq!(
+        summarize_transaction(&[withdrawn(1_000_000_000), withdrawn(500_000_000)]),
+        "withdrew 1.5 SOL"
+    );
+    assert_eq!(
+        summarize_transaction(&[withdrawn(1)]),
+        "withdrew 0.000000001 SOL"
+    );
+    // not representable as f64 SOL
+    assert_eq!(
+        summarize_transaction(&[withdrawn(u64::MAX)]),
+        "withdrew 18446744073.709551615 SOL"
+    );
+    // the sum saturates
+    assert_eq!(
+        summarize_transaction(&[withdrawn(u64::MAX), withdrawn(1)]),
+        "withdrew 18446744073.709551615 SOL"
+    );
+}
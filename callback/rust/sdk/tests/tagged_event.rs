@@ -0,0 +1,129 @@
+//! Pins the internally tagged JSON form of [`TaggedEvent`] for every [`EventKind`].
+#![cfg(feature = "sdk")]
+
+use std::{collections::BTreeSet, io};
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{events, sdk::*};
+use serde_json::{json, Value};
+
+const DISCRIMINATOR: [u8; 8] = *b"tagdevnt";
+
+fn parse_payload(payload: &[u8]) -> io::Result<Box<dyn std::any::Any + Send + Sync>> {
+    Ok(Box::new(payload.to_vec()))
+}
+
+fn bytes(byte: u8, len: usize) -> Value {
+    json!(vec![byte; len])
+}
+
+/// One event of every kind along with the expected JSON.
+fn cases() -> Vec<(Event, Value)> {
+    let _ = register_custom_event(DISCRIMINATOR, parse_payload);
+    let key = Pubkey::new_from_array([7; 32]);
+    let other = Pubkey::new_from_array([8; 32]);
+    let custom = Event::try_from_bytes(&[&DISCRIMINATOR[..], &[1, 2, 3]].concat()).unwrap();
+
+    vec![
+        (
+            events::CallbackUpdated::new(key, other, true).into(),
+            json!({"type": "CallbackUpdated", "owner": bytes(7, 32), "client": bytes(8, 32), "defined": true}),
+        ),
+        (
+            events::CalledBack::new(key).into(),
+            json!({"type": "CalledBack", "program": bytes(7, 32)}),
+        ),
+        (
+            events::Fulfilled::new(key, [1; 32], [2; 64]).into(),
+            json!({"type": "Fulfilled", "client": bytes(7, 32), "seed": bytes(1, 32), "randomness": bytes(2, 64)}),
+        ),
+        (
+            events::Registered::new(key, other, key, other).into(),
+            json!({
+                "type": "Registered",
+                "owner": bytes(7, 32),
+                "program": bytes(8, 32),
+                "state": bytes(7, 32),
+                "client": bytes(8, 32),
+            }),
+        ),
+        (
+            events::Requested::new(key, [1; 32], None, true).into(),
+            json!({
+                "type": "Requested",
+                "client": bytes(7, 32),
+                "seed": bytes(1, 32),
+                "callback": null,
+                "callback_override": true,
+            }),
+        ),
+        (
+            events::RequestedAlt::new(key, [1; 32], None, vec![other]).into(),
+            json!({
+                "type": "RequestedAlt",
+                "client": bytes(7, 32),
+                "seed": bytes(1, 32),
+                "callback": null,
+                "lookup_tables": [bytes(8, 32)],
+            }),
+        ),
+        (
+            events::Responded::new(key, other, [1; 32], [2; 64]).into(),
+            json!({
+                "type": "Responded",
+                "authority": bytes(7, 32),
+                "client": bytes(8, 32),
+                "seed": bytes(1, 32),
+                "randomness": bytes(2, 64),
+            }),
+        ),
+        (
+            events::Transferred::new(key, other, key).into(),
+            json!({"type": "Transferred", "owner": bytes(7, 32), "client": bytes(8, 32), "new_owner": bytes(7, 32)}),
+        ),
+        (
+            events::Withdrawn::new(key, other, 5).into(),
+            json!({"type": "Withdrawn", "owner": bytes(7, 32), "client": bytes(8, 32), "amount": 5}),
+        ),
+        (
+            custom,
+            json!({"type": "Custom", "discriminator": DISCRIMINATOR, "payload": "AQID"}),
+        ),
+    ]
+}
+
+#[test]
+fn covers_every_kind() {
+    let kinds = cases()
+        .iter()
+        .map(|(event, _)| event.kind())
+        .collect::<BTreeSet<_>>();
+    assert_eq!(kinds, EventKind::ALL.into_iter().collect());
+}
+
+#[test]
+fn json_shape() {
+    for (event, expected) in cases() {
+        let value = serde_json::to_value(TaggedEvent(event.clone())).unwrap();
+        assert_eq!(value, expected, "{}", event.kind());
+        assert_eq!(value["type"], event.kind().name());
+    }
+}
+
+#[test]
+fn round_trip() {
+    for (event, expected) in cases() {
+        let json = serde_json::to_string(&TaggedEvent(event.clone())).unwrap();
+        assert_eq!(serde_json::from_str::<TaggedEvent>(&json).unwrap().0, event);
+        assert_eq!(
+            serde_json::from_value::<TaggedEvent>(expected).unwrap().0,
+            event
+        );
+    }
+}
+
+#[test]
+fn unknown_type() {
+    assert!(serde_json::from_value::<TaggedEvent>(json!({"type": "Nope"})).is_err());
+    assert!(serde_json::from_value::<TaggedEvent>(json!({"client": bytes(7, 32)})).is_err());
+}
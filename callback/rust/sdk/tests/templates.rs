@@ -0,0 +1,38 @@
+//! Tests of the [`Event::format_with`].
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{events, sdk::*};
+
+#[test]
+fn placeholders_are_substituted() {
+    let client = Pubkey::new_unique();
+    let event = Event::from(events::Fulfilled::new(client, [1; 32], [2; 64]));
+    let templates = EventTemplates::new().with(
+        EventKind::Fulfilled,
+        "{{{kind}}} {seed} of {client}: {randomness} {unknown}",
+    );
+
+    assert_eq!(
+        event.format_with(&templates),
+        format!(
+            "{{Fulfilled}} {} of {client}: {} {{unknown}}",
+            Seed([1; 32]),
+            Randomness([2; 64])
+        )
+    );
+}
+
+#[test]
+fn falls_back_to_display() {
+    let event = Event::from(events::Withdrawn::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        1_500_000_000,
+    ));
+    let templates = EventTemplates::new().with(EventKind::Fulfilled, "{seed}");
+    assert_eq!(event.format_with(&templates), event.to_string());
+
+    let templates = EventTemplates::new().with(EventKind::Withdrawn, "{sol} SOL ({amount})");
+    assert_eq!(event.format_with(&templates), "1.5 SOL (1500000000)");
+}
@@ -0,0 +1,60 @@
+//! Tests the local VRF evaluation against the response verification.
+#![cfg(feature = "test-vrf")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Responded},
+    majority,
+    sdk::{check_misbehavior, test_vrf, verify_response, Event},
+};
+
+#[test]
+fn evaluation_is_deterministic_and_verifiable() {
+    let secret_key = [7; 32];
+    let client = Pubkey::new_unique();
+    let seed = [1; 32];
+
+    let randomness = test_vrf::evaluate(&secret_key, &client, &seed);
+    assert_eq!(randomness, test_vrf::evaluate(&secret_key, &client, &seed));
+    assert_ne!(
+        randomness,
+        test_vrf::evaluate(&secret_key, &client, &[2; 32])
+    );
+    assert!(verify_response(
+        &test_vrf::authority(&secret_key),
+        &client,
+        &seed,
+        &randomness.0
+    ));
+}
+
+#[test]
+fn quorum_matches_fulfillment() {
+    let secret_keys: Vec<[u8; 32]> = (1..=3).map(|x| [x; 32]).collect();
+    let authorities: Vec<Pubkey> = secret_keys.iter().map(test_vrf::authority).collect();
+    let quorum = &secret_keys[..majority(secret_keys.len())];
+    let client = Pubkey::new_unique();
+    let seed = [9; 32];
+
+    let randomness = test_vrf::evaluate_quorum(quorum, &client, &seed);
+    assert_eq!(
+        randomness,
+        test_vrf::evaluate_quorum(quorum.iter().rev(), &client, &seed)
+    );
+
+    let mut events: Vec<Event> = quorum
+        .iter()
+        .map(|secret_key| {
+            Responded::new(
+                test_vrf::authority(secret_key),
+                client,
+                seed,
+                test_vrf::evaluate(secret_key, &client, &seed).0,
+            )
+            .into()
+        })
+        .collect();
+    events.push(Fulfilled::new(client, seed, randomness.0).into());
+
+    assert!(check_misbehavior(&client, &seed, &events, &authorities).is_clean());
+}
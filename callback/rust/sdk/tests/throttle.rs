@@ -0,0 +1,56 @@
+//! Timing tests of [`throttle`] on the paused Tokio clock.
+#![cfg(feature = "sdk")]
+
+use std::time::Duration;
+
+use futures::StreamExt;
+use orao_solana_vrf_cb::sdk::throttle;
+use tokio::time::Instant;
+
+#[tokio::test(start_paused = true)]
+async fn paces_without_dropping() {
+    let start = Instant::now();
+    let items = throttle(futures::stream::iter(0..11), 100)
+        .map(|x| (x, start.elapsed()))
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(
+        items.iter().map(|(x, _)| *x).collect::<Vec<_>>(),
+        (0..11).collect::<Vec<_>>()
+    );
+    // the first item is not delayed, then every 10ms
+    let expected = (0..11)
+        .map(|x| Duration::from_millis(10 * x))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        items.iter().map(|(_, at)| *at).collect::<Vec<_>>(),
+        expected
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn item_after_a_pause_is_not_delayed() {
+    let start = Instant::now();
+    let source = futures::stream::iter(0..3).then(|x| async move {
+        if x == 2 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        x
+    });
+    let times = throttle(source, 100)
+        .map(|_| start.elapsed())
+        .collect::<Vec<_>>()
+        .await;
+    // the third item is polled once the token is there (at 20ms), and is yielded
+    // as soon as it arrives 50ms later
+    assert_eq!(times, [0, 10, 70].map(Duration::from_millis).to_vec());
+}
+
+#[tokio::test(start_paused = true)]
+async fn zero_rate_is_unthrottled() {
+    let start = Instant::now();
+    let count = throttle(futures::stream::iter(0..1000), 0).count().await;
+    assert_eq!(count, 1000);
+    assert_eq!(start.elapsed(), Duration::ZERO);
+}
@@ -0,0 +1,68 @@
+//! Tests of the [`throughput`] bucketing.
+#![cfg(feature = "sdk")]
+
+use std::time::Duration;
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested},
+    sdk::{throughput, Event, EventKind, ResolvedEvent},
+};
+
+fn resolved(block_time: Option<i64>, event: impl Into<Event>) -> ResolvedEvent {
+    ResolvedEvent::new(Signature::new_unique(), 1, block_time, 0, 0, event.into())
+}
+
+fn requested(block_time: Option<i64>) -> ResolvedEvent {
+    resolved(
+        block_time,
+        Requested::new(Pubkey::new_unique(), [1; 32], None, false),
+    )
+}
+
+#[test]
+fn bucketed_by_window() {
+    let minute = Duration::from_secs(60);
+    let events = [
+        requested(Some(125)),
+        requested(Some(179)),
+        // At the edge of the bucket, so it belongs to the next one.
+        requested(Some(180)),
+        requested(Some(300)),
+        requested(None),
+        resolved(
+            Some(130),
+            Fulfilled::new(Pubkey::new_unique(), [1; 32], [2; 64]),
+        ),
+    ];
+
+    assert_eq!(
+        throughput(&events, EventKind::Requested, minute),
+        vec![(120, 2), (180, 1), (240, 0), (300, 1)]
+    );
+    assert_eq!(
+        throughput(&events, EventKind::Fulfilled, minute),
+        vec![(120, 1)]
+    );
+    assert!(throughput(&events, EventKind::Withdrawn, minute).is_empty());
+}
+
+#[test]
+fn negative_times() {
+    let events = [requested(Some(-1)), requested(Some(0))];
+    assert_eq!(
+        throughput(&events, EventKind::Requested, Duration::from_secs(10)),
+        vec![(-10, 1), (0, 1)]
+    );
+}
+
+#[test]
+#[should_panic]
+fn sub_second_window() {
+    throughput(
+        &[requested(Some(0))],
+        EventKind::Requested,
+        Duration::from_millis(500),
+    );
+}
@@ -0,0 +1,74 @@
+//! Tests [`events_in_transaction`] on a transaction emitting several events.
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{CallbackUpdated, Fulfilled, Requested, Responded},
+    sdk::{events_in_transaction, Event},
+};
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+
+const SLOT: u64 = 42;
+
+fn transaction(
+    signature: Signature,
+    logs: Vec<String>,
+) -> EncodedConfirmedTransactionWithStatusMeta {
+    // The transaction is flattened into the top-level object.
+    serde_json::from_value(serde_json::json!({
+        "slot": SLOT,
+        "blockTime": null,
+        "transaction": {
+            "signatures": [signature.to_string()],
+            "accountKeys": []
+        },
+        "meta": {
+            "err": null,
+            "status": {"Ok": null},
+            "fee": 5000,
+            "preBalances": [],
+            "postBalances": [],
+            "logMessages": logs
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn preserves_emission_order() {
+    let vrf = orao_solana_vrf_cb::id();
+    let client = Pubkey::new_unique();
+    let program = Pubkey::new_unique();
+    let seed = [1; 32];
+    let events: Vec<Event> = vec![
+        Requested::new(client, seed, None, false).into(),
+        CallbackUpdated::new(Pubkey::new_unique(), client, false).into(),
+        Responded::new(Pubkey::new_unique(), client, seed, [2; 64]).into(),
+        Fulfilled::new(client, seed, [2; 64]).into(),
+    ];
+
+    let mut logs = vec![format!("Program {program} invoke [1]")];
+    for (i, event) in events.iter().enumerate() {
+        logs.push(format!("Program {vrf} invoke [2]"));
+        logs.push(format!("Program log: Instruction: {i}"));
+        logs.push(event.to_program_data_log());
+        logs.push(format!("Program {vrf} success"));
+    }
+    logs.push(format!("Program {program} success"));
+
+    let signature = Signature::new_unique();
+    let resolved = events_in_transaction(&transaction(signature, logs.clone())).unwrap();
+
+    assert_eq!(
+        resolved.iter().map(|x| x.event.clone()).collect::<Vec<_>>(),
+        events
+    );
+    for (i, x) in resolved.iter().enumerate() {
+        assert_eq!(x.index, i);
+        assert_eq!(x.signature, signature);
+        assert_eq!(x.slot, SLOT);
+        assert_eq!(logs[x.log_index], x.event.to_program_data_log());
+    }
+    assert!(resolved.windows(2).all(|x| x[0].log_index < x[1].log_index));
+}
@@ -0,0 +1,71 @@
+//! Tests of the [`Event::try_from_bytes`] errors on truncated inputs.
+#![cfg(feature = "sdk")]
+
+use std::io;
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{events, sdk::*};
+
+fn truncated(bytes: &[u8]) -> TruncatedEvent {
+    let err = Event::try_from_bytes(bytes).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    *err.get_ref()
+        .and_then(|x| x.downcast_ref::<TruncatedEvent>())
+        .expect("not a TruncatedEvent")
+}
+
+/// Events with every variable-length field empty.
+fn minimal_events() -> Vec<Event> {
+    let key = Pubkey::new_unique();
+    vec![
+        events::CallbackUpdated::new(key, key, true).into(),
+        events::CalledBack::new(key).into(),
+        events::Fulfilled::new(key, [1; 32], [2; 64]).into(),
+        events::Registered::new(key, key, key, key).into(),
+        events::Requested::new(key, [1; 32], None, false).into(),
+        events::RequestedAlt::new(key, [1; 32], None, vec![]).into(),
+        events::Responded::new(key, key, [1; 32], [2; 64]).into(),
+        events::Transferred::new(key, key, key).into(),
+        events::Withdrawn::new(key, key, 1).into(),
+    ]
+}
+
+#[test]
+fn empty_event() {
+    assert_eq!(truncated(&[]), TruncatedEvent::Empty);
+}
+
+#[test]
+fn truncated_discriminator() {
+    assert_eq!(
+        truncated(&FULFILLED_DISCRIMINATOR[..5]),
+        TruncatedEvent::Discriminator { len: 5 }
+    );
+}
+
+#[test]
+fn truncated_payload() {
+    for event in minimal_events() {
+        let bytes = event.to_bytes();
+        let expected = bytes.len() - 8;
+
+        assert_eq!(Event::try_from_bytes(&bytes).unwrap(), event);
+        for len in [8, bytes.len() - 1] {
+            assert_eq!(
+                truncated(&bytes[..len]),
+                TruncatedEvent::Payload {
+                    kind: event.kind(),
+                    expected,
+                    actual: len - 8,
+                }
+            );
+        }
+    }
+}
+
+#[test]
+fn unknown_event() {
+    let err = Event::try_from_bytes(&[0xff; 16]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert!(err.get_ref().unwrap().is::<UnknownEvent>());
+}
@@ -0,0 +1,29 @@
+//! Tests of the [`EventKind::style`] conventions.
+#![cfg(feature = "tui")]
+
+use std::collections::HashSet;
+
+use orao_solana_vrf_cb::sdk::{Color, EventKind};
+
+#[test]
+fn styles() {
+    assert_eq!(EventKind::Fulfilled.style(), (Color::Green, "✅"));
+    assert_eq!(EventKind::Requested.style(), (Color::Yellow, "⏳"));
+    assert_eq!(
+        EventKind::RequestedAlt.style(),
+        EventKind::Requested.style()
+    );
+
+    // every other kind is distinguishable by its glyph
+    let glyphs: HashSet<_> = EventKind::ALL
+        .iter()
+        .filter(|x| **x != EventKind::RequestedAlt)
+        .map(|x| x.style().1)
+        .collect();
+    assert_eq!(glyphs.len(), EventKind::ALL.len() - 1);
+}
+
+#[test]
+fn paint() {
+    assert_eq!(Color::Green.paint("ok"), "\x1b[32mok\x1b[0m");
+}
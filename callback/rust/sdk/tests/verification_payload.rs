@@ -0,0 +1,102 @@
+//! Tests of [`VerificationPayload`] on a fixture produced by two test authorities.
+#![cfg(feature = "sdk")]
+
+use anchor_client::solana_sdk::bs58;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Responded},
+    sdk::VerificationPayload,
+};
+
+const FIXTURE: &str = include_str!("fixtures/verification_payload.json");
+
+fn fixture() -> VerificationPayload {
+    serde_json::from_str(FIXTURE).unwrap()
+}
+
+fn authorities(payload: &VerificationPayload) -> Vec<Pubkey> {
+    payload
+        .responses
+        .iter()
+        .map(|x| x.authority.parse().unwrap())
+        .collect()
+}
+
+fn bytes<const N: usize>(s: &str) -> [u8; N] {
+    bs58::decode(s).into_vec().unwrap().try_into().unwrap()
+}
+
+#[test]
+fn fixture_verifies() {
+    let payload = fixture();
+    assert!(payload.verify());
+
+    let authorities = authorities(&payload);
+    assert!(payload.verify_quorum(&authorities));
+    // 2 of 3 is not the majority (see `majority`)
+    let mut configured = authorities.clone();
+    configured.push(Pubkey::new_unique());
+    assert!(!payload.verify_quorum(&configured));
+    // unknown authorities
+    assert!(!payload.verify_quorum(&[authorities[0]]));
+}
+
+#[test]
+fn tampered_payloads_fail() {
+    let mut payload = fixture();
+    payload.responses.pop();
+    assert!(!payload.verify());
+
+    let mut payload = fixture();
+    payload.randomness = fixture().responses[0].signature.clone();
+    assert!(!payload.verify());
+
+    let mut payload = fixture();
+    payload.seed = bs58::encode([6; 32]).into_string();
+    assert!(!payload.verify());
+
+    let mut payload = fixture();
+    payload.client = "not base58!".to_string();
+    assert!(!payload.verify());
+
+    let mut payload = fixture();
+    payload.responses.clear();
+    payload.randomness = bs58::encode([0; 64]).into_string();
+    assert!(!payload.verify());
+}
+
+#[test]
+fn payload_of_fulfillment() {
+    let payload = fixture();
+    let client = payload.client.parse().unwrap();
+    let seed = bytes(&payload.seed);
+    let fulfilled = Fulfilled::new(client, seed, bytes(&payload.randomness));
+
+    let mut responses = payload
+        .responses
+        .iter()
+        .map(|x| {
+            Responded::new(
+                x.authority.parse().unwrap(),
+                client,
+                seed,
+                bytes(&x.signature),
+            )
+        })
+        .collect::<Vec<_>>();
+    // a repeated response and a response to another request are skipped
+    responses.push(responses[0].clone());
+    responses.push(Responded::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        seed,
+        [0; 64],
+    ));
+
+    let actual = fulfilled.verification_payload(&responses);
+    assert_eq!(actual, payload);
+    assert_eq!(
+        serde_json::to_string_pretty(&actual).unwrap() + "\n",
+        FIXTURE
+    );
+}
@@ -0,0 +1,86 @@
+//! Tests of [`verify_against_account`] using the mock RPC.
+#![cfg(feature = "sdk")]
+
+use std::collections::HashMap;
+
+use anchor_client::solana_client::{nonblocking::rpc_client::RpcClient, rpc_request::RpcRequest};
+use anchor_lang::{prelude::Pubkey, AccountSerialize};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use orao_solana_vrf_cb::{
+    events,
+    sdk::{verify_against_account, AccountMismatch, Randomness},
+    state::request::{Fulfilled, Pending, RequestAccount, RequestState},
+};
+use serde_json::{json, Value};
+
+fn rpc_with(account: Option<&RequestAccount>) -> RpcClient {
+    let account = account.map_or(Value::Null, |account| {
+        let mut data = vec![];
+        account.try_serialize(&mut data).unwrap();
+        json!({
+            "lamports": 1_000_000,
+            "data": [STANDARD.encode(&data), "base64"],
+            "owner": orao_solana_vrf_cb::id().to_string(),
+            "executable": false,
+            "rentEpoch": 0,
+            "space": data.len(),
+        })
+    });
+    RpcClient::new_mock_with_mocks(
+        "succeeds".to_string(),
+        HashMap::from([(
+            RpcRequest::GetMultipleAccounts,
+            json!({ "context": { "slot": 1 }, "value": [account, null] }),
+        )]),
+    )
+}
+
+#[tokio::test]
+async fn consistent_and_mismatching() {
+    let client = Pubkey::new_unique();
+    let vrf_id = orao_solana_vrf_cb::id();
+    let event = events::Fulfilled::new(client, [1; 32], [2; 64]);
+    let fulfilled = |randomness| {
+        RequestAccount::new(
+            255,
+            1,
+            client,
+            [1; 32],
+            RequestState::Fulfilled(Fulfilled::new(randomness, None)),
+        )
+    };
+
+    let rpc = rpc_with(Some(&fulfilled([2; 64])));
+    assert_eq!(
+        verify_against_account(&rpc, &event, &vrf_id).await.unwrap(),
+        None
+    );
+
+    let rpc = rpc_with(Some(&fulfilled([3; 64])));
+    assert_eq!(
+        verify_against_account(&rpc, &event, &vrf_id).await.unwrap(),
+        Some(AccountMismatch::RandomnessMismatch {
+            expected: Randomness([3; 64]),
+            actual: Randomness([2; 64]),
+        })
+    );
+
+    let pending = RequestAccount::new(
+        255,
+        1,
+        client,
+        [1; 32],
+        RequestState::Pending(Pending::new(vec![], None, false)),
+    );
+    let rpc = rpc_with(Some(&pending));
+    assert_eq!(
+        verify_against_account(&rpc, &event, &vrf_id).await.unwrap(),
+        Some(AccountMismatch::NotFulfilled { responses: 0 })
+    );
+
+    let rpc = rpc_with(None);
+    assert_eq!(
+        verify_against_account(&rpc, &event, &vrf_id).await.unwrap(),
+        Some(AccountMismatch::NotFound)
+    );
+}
@@ -0,0 +1,35 @@
+//! Tests [`Registered::verify_state_pda`].
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{error::ErrorCode, events::Registered, state::client::Client};
+
+#[test]
+fn returns_the_canonical_bump() {
+    let vrf = orao_solana_vrf_cb::id();
+    let (program, state) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (client, bump) = Client::find_address(&program, &state, &vrf);
+
+    let event = Registered::new(Pubkey::new_unique(), program, state, client);
+    assert_eq!(event.verify_state_pda(&vrf), Ok(bump));
+}
+
+#[test]
+fn rejects_mismatched_addresses() {
+    let vrf = orao_solana_vrf_cb::id();
+    let (program, state) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (client, _) = Client::find_address(&program, &state, &vrf);
+    let owner = Pubkey::new_unique();
+
+    let other_state = Registered::new(owner, program, Pubkey::new_unique(), client);
+    assert_eq!(
+        other_state.verify_state_pda(&vrf),
+        Err(ErrorCode::UnexpectedClientState)
+    );
+
+    let other_vrf = Registered::new(owner, program, state, client);
+    assert_eq!(
+        other_vrf.verify_state_pda(&Pubkey::new_unique()),
+        Err(ErrorCode::UnexpectedClientState)
+    );
+}
@@ -0,0 +1,58 @@
+//! Tests of the versioned event representation (see [`Event::to_versioned_bytes`]).
+#![cfg(feature = "sdk")]
+
+use std::io;
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{events, sdk::*};
+
+fn events() -> Vec<Event> {
+    let key = Pubkey::new_unique();
+    vec![
+        events::CallbackUpdated::new(key, key, true).into(),
+        events::CalledBack::new(key).into(),
+        events::Fulfilled::new(key, [1; 32], [2; 64]).into(),
+        events::Registered::new(key, key, key, key).into(),
+        events::Requested::new(key, [1; 32], None, false).into(),
+        events::RequestedAlt::new(key, [1; 32], None, vec![key]).into(),
+        events::Responded::new(key, key, [1; 32], [2; 64]).into(),
+        events::Transferred::new(key, key, key).into(),
+        events::Withdrawn::new(key, key, 1).into(),
+    ]
+}
+
+#[test]
+fn round_trip() {
+    for event in events() {
+        let bytes = event.to_versioned_bytes();
+        assert_eq!(bytes[0], EVENT_FORMAT_VERSION);
+        assert_eq!(&bytes[1..], event.to_bytes());
+        assert_eq!(Event::try_from_versioned_bytes(&bytes).unwrap(), event);
+    }
+}
+
+#[test]
+fn version_mismatch() {
+    for event in events() {
+        let mut bytes = event.to_versioned_bytes();
+        bytes[0] = EVENT_FORMAT_VERSION + 1;
+
+        let err = Event::try_from_versioned_bytes(&bytes).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let mismatch = err
+            .get_ref()
+            .and_then(|x| x.downcast_ref::<VersionMismatch>())
+            .expect("not a VersionMismatch");
+        assert_eq!(mismatch.found, EVENT_FORMAT_VERSION + 1);
+    }
+
+    // the unversioned representation is not accepted either
+    let bytes = events()[2].to_bytes();
+    assert!(Event::try_from_versioned_bytes(&bytes).is_err());
+}
+
+#[test]
+fn empty() {
+    let err = Event::try_from_versioned_bytes(&[]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
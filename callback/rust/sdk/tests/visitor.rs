@@ -0,0 +1,51 @@
+//! Tests of [`EventVisitor`].
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Fulfilled, Requested, Transferred, Withdrawn},
+    sdk::{Event, EventVisitor},
+};
+
+#[derive(Default)]
+struct Counter {
+    requested: usize,
+    fulfilled: usize,
+    withdrawn: u64,
+}
+
+impl EventVisitor for Counter {
+    fn visit_requested(&mut self, _: &Requested) {
+        self.requested += 1;
+    }
+
+    fn visit_fulfilled(&mut self, _: &Fulfilled) {
+        self.fulfilled += 1;
+    }
+
+    fn visit_withdrawn(&mut self, event: &Withdrawn) {
+        self.withdrawn += event.amount;
+    }
+}
+
+#[test]
+fn dispatches_to_handlers() {
+    let client = Pubkey::new_unique();
+    let events: Vec<Event> = vec![
+        Requested::new(client, [1; 32], None, false).into(),
+        Requested::new(client, [2; 32], None, false).into(),
+        Fulfilled::new(client, [1; 32], [0; 64]).into(),
+        Withdrawn::new(Pubkey::new_unique(), client, 10).into(),
+        Withdrawn::new(Pubkey::new_unique(), client, 5).into(),
+        // not handled
+        Transferred::new(Pubkey::new_unique(), client, Pubkey::new_unique()).into(),
+    ];
+
+    let mut counter = Counter::default();
+    for event in &events {
+        event.accept(&mut counter);
+    }
+    assert_eq!(counter.requested, 2);
+    assert_eq!(counter.fulfilled, 1);
+    assert_eq!(counter.withdrawn, 15);
+}
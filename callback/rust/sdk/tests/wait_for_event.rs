@@ -0,0 +1,88 @@
+//! Tests [`wait_for_event`] using the mock RPC.
+#![cfg(feature = "sdk")]
+
+use std::{collections::HashMap, time::Duration};
+
+use anchor_client::solana_client::{nonblocking::rpc_client::RpcClient, rpc_request::RpcRequest};
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{CalledBack, Fulfilled},
+    sdk::{wait_for_event, Event},
+};
+
+/// The mock RPC returns a single signature, and this is its transaction.
+fn rpc_with_events(events: &[Event]) -> RpcClient {
+    let vrf = orao_solana_vrf_cb::id();
+    let mut logs = vec![format!("Program {vrf} invoke [1]")];
+    logs.extend(events.iter().map(Event::to_program_data_log));
+    logs.push(format!("Program {vrf} success"));
+
+    let tx = serde_json::json!({
+        "slot": 123,
+        "blockTime": null,
+        "transaction": {
+            "signatures": [Signature::new_unique().to_string()],
+            "message": {
+                "header": {
+                    "numRequiredSignatures": 1,
+                    "numReadonlySignedAccounts": 0,
+                    "numReadonlyUnsignedAccounts": 0
+                },
+                "accountKeys": [Pubkey::new_unique().to_string()],
+                "recentBlockhash": "11111111111111111111111111111111",
+                "instructions": []
+            }
+        },
+        "meta": {
+            "err": null,
+            "status": {"Ok": null},
+            "fee": 5000,
+            "preBalances": [],
+            "postBalances": [],
+            "logMessages": logs
+        }
+    });
+    RpcClient::new_mock_with_mocks(
+        "succeeds".to_string(),
+        HashMap::from([(RpcRequest::GetTransaction, tx)]),
+    )
+}
+
+#[tokio::test]
+async fn returns_first_matching_event() {
+    let client = Pubkey::new_unique();
+    let program = Pubkey::new_unique();
+    let events: Vec<Event> = vec![
+        Fulfilled::new(client, [1; 32], [2; 64]).into(),
+        CalledBack::new(program).into(),
+    ];
+
+    let event = wait_for_event(
+        &rpc_with_events(&events),
+        &client,
+        |ev| matches!(ev, Event::CalledBack(_)),
+        Duration::from_secs(10),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(event.event, events[1]);
+    assert_eq!(event.slot, 123);
+}
+
+#[tokio::test]
+async fn times_out_without_match() {
+    let client = Pubkey::new_unique();
+    let events: Vec<Event> = vec![Fulfilled::new(client, [1; 32], [2; 64]).into()];
+
+    let event = wait_for_event(
+        &rpc_with_events(&events),
+        &client,
+        |ev| matches!(ev, Event::CalledBack(_)),
+        Duration::ZERO,
+    )
+    .await
+    .unwrap();
+    assert_eq!(event, None);
+}
@@ -0,0 +1,49 @@
+//! Tests [`WebhookPayload`] signing and verification.
+#![cfg(feature = "webhook")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::Fulfilled,
+    sdk::{verify_webhook_signature, Event, TaggedEvent, WebhookPayload},
+};
+
+#[test]
+fn signs_and_verifies() {
+    let event = Event::from(Fulfilled::new(Pubkey::new_unique(), [1; 32], [2; 64]));
+    let payload = WebhookPayload::new(&event, b"secret").unwrap();
+
+    assert!(payload.signature.starts_with("sha256="));
+    assert!(verify_webhook_signature(
+        payload.body.as_bytes(),
+        &payload.signature,
+        b"secret"
+    ));
+    assert!(!verify_webhook_signature(
+        payload.body.as_bytes(),
+        &payload.signature,
+        b"another secret"
+    ));
+    assert!(!verify_webhook_signature(
+        payload.body.replace('1', "3").as_bytes(),
+        &payload.signature,
+        b"secret"
+    ));
+    assert!(!verify_webhook_signature(
+        payload.body.as_bytes(),
+        "sha256=zz",
+        b"secret"
+    ));
+
+    let parsed: TaggedEvent = serde_json::from_str(&payload.body).unwrap();
+    assert_eq!(parsed.0, event);
+}
+
+#[test]
+fn known_signature() {
+    // HMAC-SHA256 test vector (RFC 4231, test case 2)
+    assert!(verify_webhook_signature(
+        b"what do ya want for nothing?",
+        "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+        b"Jefe"
+    ));
+}
@@ -0,0 +1,50 @@
+//! Tests of the [`Fulfilled::weighted_choice`].
+
+use anchor_lang::{prelude::Pubkey, solana_program::hash::hashv};
+use orao_solana_vrf_cb::events::Fulfilled;
+
+fn fulfilled(i: u32) -> Fulfilled {
+    let mut randomness = [0; 64];
+    randomness[..32].copy_from_slice(hashv(&[b"first", &i.to_le_bytes()]).as_ref());
+    randomness[32..].copy_from_slice(hashv(&[b"second", &i.to_le_bytes()]).as_ref());
+    Fulfilled::new(Pubkey::default(), [0; 32], randomness)
+}
+
+#[test]
+fn distribution_matches_weights() {
+    const SAMPLES: u32 = 100_000;
+    let weights = [1, 0, 2, 7];
+
+    let mut counts = [0_u32; 4];
+    for i in 0..SAMPLES {
+        counts[fulfilled(i).weighted_choice(&weights)] += 1;
+    }
+
+    assert_eq!(counts[1], 0);
+    let total = weights.iter().sum::<u64>() as f64;
+    for (count, weight) in counts.iter().zip(weights) {
+        let expected = SAMPLES as f64 * weight as f64 / total;
+        assert!(
+            (*count as f64 - expected).abs() <= SAMPLES as f64 * 0.01,
+            "{counts:?} does not match {weights:?}"
+        );
+    }
+}
+
+#[test]
+fn deterministic() {
+    let weights = [u64::MAX, u64::MAX, 1];
+    for i in 0..100 {
+        assert_eq!(
+            fulfilled(i).weighted_choice(&weights),
+            fulfilled(i).weighted_choice(&weights)
+        );
+    }
+    assert_eq!(fulfilled(0).weighted_choice(&[0, 0, 5]), 2);
+}
+
+#[test]
+#[should_panic]
+fn zero_weights() {
+    fulfilled(0).weighted_choice(&[0, 0]);
+}
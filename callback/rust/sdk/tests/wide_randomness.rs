@@ -0,0 +1,47 @@
+//! Tests of the [`Fulfilled::as_u128_pair`] and [`Fulfilled::as_u256`] byte order.
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::events::Fulfilled;
+
+#[test]
+fn little_endian_halves() {
+    let mut randomness = [0xff; 64];
+    for (i, byte) in randomness[..32].iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    let fulfilled = Fulfilled::new(Pubkey::default(), [0; 32], randomness);
+
+    let (low, high) = fulfilled.as_u128_pair();
+    assert_eq!(low, 0x0f0e0d0c_0b0a0908_07060504_03020100);
+    assert_eq!(high, 0x1f1e1d1c_1b1a1918_17161514_13121110);
+}
+
+#[test]
+fn least_significant_byte_first() {
+    let mut randomness = [0; 64];
+    randomness[0] = 1;
+    randomness[16] = 2;
+    randomness[32] = 3;
+    let fulfilled = Fulfilled::new(Pubkey::default(), [0; 32], randomness);
+
+    assert_eq!(fulfilled.as_u128_pair(), (1, 2));
+}
+
+#[cfg(feature = "u256")]
+#[test]
+fn u256_matches_u128_pair() {
+    use primitive_types::U256;
+
+    let mut randomness = [0; 64];
+    for (i, byte) in randomness.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    let fulfilled = Fulfilled::new(Pubkey::default(), [0; 32], randomness);
+
+    let [first, second] = fulfilled.as_u256();
+    let (low, high) = fulfilled.as_u128_pair();
+    assert_eq!(first, (U256::from(high) << 128) | U256::from(low));
+    assert_eq!(first.low_u128(), 0x0f0e0d0c_0b0a0908_07060504_03020100);
+    assert_eq!(second.byte(0), 32);
+    assert_eq!(second.byte(31), 63);
+}
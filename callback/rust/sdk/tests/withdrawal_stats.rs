@@ -0,0 +1,63 @@
+//! Tests [`WithdrawalStats`].
+#![cfg(feature = "sdk")]
+
+use anchor_lang::prelude::Pubkey;
+use orao_solana_vrf_cb::{
+    events::{Requested, Withdrawn},
+    sdk::{Event, WithdrawalStats},
+};
+
+#[test]
+fn per_client_distribution() {
+    let owner = Pubkey::new_unique();
+    let first = Pubkey::new_unique();
+    let second = Pubkey::new_unique();
+    let events: Vec<Event> = vec![
+        Withdrawn::new(owner, first, 10).into(),
+        Requested::new(first, [1; 32], None, false).into(),
+        Withdrawn::new(owner, first, 1).into(),
+        Withdrawn::new(owner, second, 7).into(),
+        Withdrawn::new(owner, first, 4).into(),
+        Withdrawn::new(owner, second, 2).into(),
+    ];
+
+    let stats = WithdrawalStats::from_events(&events);
+    let mut clients = vec![first, second];
+    clients.sort();
+    assert_eq!(stats.clients().copied().collect::<Vec<_>>(), clients);
+
+    assert_eq!(stats.count(&first), 3);
+    assert_eq!(stats.total(&first), 15);
+    assert_eq!(stats.average(&first), Some(5));
+    assert_eq!(stats.median(&first), Some(4));
+
+    // even number of withdrawals — both are rounded down
+    assert_eq!(stats.count(&second), 2);
+    assert_eq!(stats.average(&second), Some(4));
+    assert_eq!(stats.median(&second), Some(4));
+}
+
+#[test]
+fn no_withdrawals() {
+    let stats = WithdrawalStats::from_events(&[]);
+    let client = Pubkey::new_unique();
+    assert_eq!(stats.clients().count(), 0);
+    assert_eq!(stats.count(&client), 0);
+    assert_eq!(stats.total(&client), 0);
+    assert_eq!(stats.average(&client), None);
+    assert_eq!(stats.median(&client), None);
+}
+
+#[test]
+fn exact_for_large_amounts() {
+    let client = Pubkey::new_unique();
+    let events: Vec<Event> = vec![
+        Withdrawn::new(client, client, u64::MAX).into(),
+        Withdrawn::new(client, client, u64::MAX - 1).into(),
+    ];
+
+    let stats = WithdrawalStats::from_events(&events);
+    assert_eq!(stats.total(&client), 2 * u64::MAX as u128 - 1);
+    assert_eq!(stats.average(&client), Some(u64::MAX - 1));
+    assert_eq!(stats.median(&client), Some(u64::MAX - 1));
+}